@@ -0,0 +1,55 @@
+//! Benchmarks for finding and parsing escape codes in large texts.
+//!
+//! `legacy_iter_modifiers` reproduces the scanning path before [`ANSIEscapeCodes`] was
+//! introduced, which searched with [`ESCAPE_CODE_PATTERN`] and allocated a copy of each
+//! match; it is kept here as a baseline for comparison.
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+
+use conch_ansi::*;
+use conch_base_models::*;
+
+/// Build a log of roughly 4 MiB, with a few escape codes per line.
+fn log() -> String {
+    let lines = [
+        "\x1b[2m2023-03-03T12:34:56Z\x1b[22m \x1b[38;5;10mINFO\x1b[39m Request handled in 42ms\n",
+        "\x1b[2m2023-03-03T12:34:57Z\x1b[22m \x1b[1;38;5;9mERROR\x1b[39;22m Connection reset\n",
+        "\x1b[2m2023-03-03T12:34:58Z\x1b[22m \x1b[48;5;236mDEBUG\x1b[49m Plain text without codes\n",
+        "A line with an incomplete \x1b[38;5 code and a \x1b[2A cursor movement\n",
+    ];
+
+    lines.iter().cycle().take(64 * 1024).copied().collect()
+}
+
+fn legacy_iter_modifiers(text: &str) -> impl Iterator<Item = Modifier> + '_ {
+    ESCAPE_CODE_PATTERN.find_iter(text).filter_map(|matched| {
+        let code = ANSIEscapeCode::try_from(matched.as_str().to_string().as_str()).ok()?;
+        Modifier::try_from(code).ok()
+    })
+}
+
+fn bench_scan(c: &mut Criterion) {
+    let text = log();
+    let mut group = c.benchmark_group("scan log");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+
+    group.bench_function("legacy_iter_modifiers", |b| {
+        b.iter(|| legacy_iter_modifiers(black_box(&text)).count())
+    });
+
+    group.bench_function("iter_escape_codes", |b| {
+        b.iter(|| black_box(&text).iter_escape_codes().count())
+    });
+
+    group.bench_function("iter_modifiers", |b| {
+        b.iter(|| black_box(&text).iter_modifiers().count())
+    });
+
+    group.bench_function("len_without_modifiers", |b| {
+        b.iter(|| black_box(&text).len_without_modifiers())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_scan);
+criterion_main!(benches);
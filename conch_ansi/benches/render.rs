@@ -0,0 +1,102 @@
+//! Benchmarks for rendering [`Modifier`]s.
+//!
+//! `legacy_wraps` reproduces the rendering path before [`StringWrapper::write_wrapped()`]
+//! was introduced, which built a new [`String`] for every escape code and every layer
+//! of a [`Modifier::Combo`]; it is kept here as a baseline for comparison.
+use std::fmt::Write as FmtWrite;
+use std::io::Write as IoWrite;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use conch_ansi::*;
+use conch_base_models::*;
+
+const TEXT: &str = "2023-03-03T12:34:56Z INFO Request handled in 42ms";
+
+fn combo() -> Modifier {
+    Modifier::colour("BrightRed").unwrap()
+        + Modifier::background("Grayscale04").unwrap()
+        + Modifier::intensity("Bold").unwrap()
+}
+
+fn legacy_wraps(modifier: &Modifier, text: &str) -> String {
+    match modifier {
+        Modifier::Combo(modifiers) => modifiers
+            .iter()
+            .rev()
+            .fold(text.to_string(), |text, modifier| {
+                legacy_wraps(modifier, &text)
+            }),
+        Modifier::Nothing => text.to_string(),
+        modifier => {
+            let opening = modifier.to_string();
+            let closing = modifier.resetter(Some(text)).to_string();
+
+            format!("{}{}{}", opening, text, closing)
+        }
+    }
+}
+
+fn bench_wrap(c: &mut Criterion) {
+    let modifier = combo();
+    let mut group = c.benchmark_group("wrap combo");
+
+    group.bench_function("legacy_wraps", |b| {
+        b.iter(|| legacy_wraps(black_box(&modifier), black_box(TEXT)))
+    });
+
+    group.bench_function("wraps", |b| {
+        b.iter(|| black_box(&modifier).wraps(black_box(TEXT)))
+    });
+
+    group.bench_function("write_wrapped (reused buffer)", |b| {
+        let mut buffer = String::with_capacity(256);
+        b.iter(|| {
+            buffer.clear();
+            black_box(&modifier)
+                .write_wrapped(&mut buffer, black_box(TEXT))
+                .unwrap();
+            buffer.len()
+        })
+    });
+
+    group.bench_function("paint (io::Write, reused buffer)", |b| {
+        let mut buffer: Vec<u8> = Vec::with_capacity(256);
+        b.iter(|| {
+            buffer.clear();
+            write!(buffer, "{}", black_box(&modifier).paint(black_box(TEXT))).unwrap();
+            buffer.len()
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_len(c: &mut Criterion) {
+    let modifier = combo();
+    let mut group = c.benchmark_group("len combo");
+
+    group.bench_function("legacy to_string().len()", |b| {
+        b.iter(|| black_box(&modifier).to_string().len())
+    });
+
+    group.bench_function("len", |b| b.iter(|| black_box(&modifier).len()));
+
+    group.finish();
+}
+
+fn bench_format(c: &mut Criterion) {
+    let modifier = combo();
+
+    c.bench_function("format combo into reused buffer", |b| {
+        let mut buffer = String::with_capacity(256);
+        b.iter(|| {
+            buffer.clear();
+            write!(buffer, "{}", black_box(&modifier)).unwrap();
+            buffer.len()
+        })
+    });
+}
+
+criterion_group!(benches, bench_wrap, bench_len, bench_format);
+criterion_main!(benches);
@@ -2,12 +2,16 @@ use std::fmt;
 use strum_macros::EnumIter;
 
 use crate::traits::*;
-use conch_base_models::{ANSIEscapeCode, IntoANSIEscapeCode, ModifierError, Resetter};
+use conch_base_models::{
+    ANSIEscapeCode, EscapeCodePart, IntoANSIEscapeCode, ModifierCodes, ModifierError,
+    ModifierErrorKind, Resetter,
+};
 use conch_macros::ansi_enum_builder as builder;
 
 /// Move the position of the current cursor in the terminal.
 #[allow(dead_code)]
 #[derive(Clone, Debug, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoveCursor {
     Up(i32),
     Down(i32),
@@ -65,16 +69,16 @@ impl IntoANSIEscapeCode for MoveCursor {
     /// ```
     fn into_ansi_escape_code(&self) -> ANSIEscapeCode {
         match self {
-            Self::Up(n) if *n < 0 => ANSIEscapeCode::new(None, Some(vec![n.abs()]), 'B'),
-            Self::Up(n) => ANSIEscapeCode::new(None, Some(vec![*n]), 'A'),
-            Self::Down(n) if *n < 0 => ANSIEscapeCode::new(None, Some(vec![n.abs()]), 'A'),
-            Self::Down(n) => ANSIEscapeCode::new(None, Some(vec![*n]), 'B'),
-            Self::Right(n) if *n < 0 => ANSIEscapeCode::new(None, Some(vec![n.abs()]), 'D'),
-            Self::Right(n) => ANSIEscapeCode::new(None, Some(vec![*n]), 'C'),
-            Self::Left(n) if *n < 0 => ANSIEscapeCode::new(None, Some(vec![n.abs()]), 'C'),
-            Self::Left(n) => ANSIEscapeCode::new(None, Some(vec![*n]), 'D'),
+            Self::Up(n) if *n < 0 => ANSIEscapeCode::with_modifiers(None, &[n.abs()], 'B'),
+            Self::Up(n) => ANSIEscapeCode::with_modifiers(None, &[*n], 'A'),
+            Self::Down(n) if *n < 0 => ANSIEscapeCode::with_modifiers(None, &[n.abs()], 'A'),
+            Self::Down(n) => ANSIEscapeCode::with_modifiers(None, &[*n], 'B'),
+            Self::Right(n) if *n < 0 => ANSIEscapeCode::with_modifiers(None, &[n.abs()], 'D'),
+            Self::Right(n) => ANSIEscapeCode::with_modifiers(None, &[*n], 'C'),
+            Self::Left(n) if *n < 0 => ANSIEscapeCode::with_modifiers(None, &[n.abs()], 'C'),
+            Self::Left(n) => ANSIEscapeCode::with_modifiers(None, &[*n], 'D'),
             Self::Origin => ANSIEscapeCode::new(None, None, 'H'),
-            Self::Absolute(x, y) => ANSIEscapeCode::new(None, Some(vec![*y, *x]), 'H'),
+            Self::Absolute(x, y) => ANSIEscapeCode::with_modifiers(None, &[*y, *x], 'H'),
         }
     }
 }
@@ -114,11 +118,13 @@ impl TryFrom<&ANSIEscapeCode> for MoveCursor {
     type Error = ModifierError;
 
     fn try_from(value: &ANSIEscapeCode) -> Result<Self, Self::Error> {
+        let error = |kind| ModifierError::new(kind, "MoveCursor");
+
         if !"ABCDH".contains(value.end_char) {
-            return Err(ModifierError::UnexpectedEndCharacter(
-                stringify!($enum_name).to_string(),
-                value.end_char.to_string(),
-            ));
+            return Err(error(ModifierErrorKind::UnexpectedEndChar)
+                .with_expected("one of `A`, `B`, `C`, `D` or `H`")
+                .with_found(value.end_char)
+                .with_part(EscapeCodePart::EndChar));
         }
 
         macro_rules! expand_variants {
@@ -138,38 +144,32 @@ impl TryFrom<&ANSIEscapeCode> for MoveCursor {
                             if modifiers.len() == $modifier_count {
                                 Ok($variant_builder(modifiers))
                             } else {
-                                Err(ModifierError::ValueNotRecognised(
-                                    stringify!($enum_name).to_string(),
-                                    format!("{:?}:{:?}", value.code, value.modifiers),
-                                    format!(
-                                        "{} command accepts {} argument(s), but {:?} found.",
-                                        stringify!($name),
+                                Err(error(ModifierErrorKind::WrongArgumentCount)
+                                    .with_expected(format!(
+                                        "{} argument(s) for {}",
                                         $modifier_count,
-                                        modifiers
-                                    ),
-                                ))
+                                        stringify!($name),
+                                    ))
+                                    .with_found(format!("{} argument(s)", modifiers.len())))
                             }
                         }
                     ),*
-                    _ => Err(ModifierError::ValueNotRecognised(
-                        stringify!($enum_name).to_string(),
-                        format!("{:?}:{:?}", value.code, value.modifiers),
-                        String::from("Unrecognised pattern for MoveCursor."),
-                    ))
+                    _ => Err(error(ModifierErrorKind::UnrecognisedCodes)
+                        .with_found(format!("{:?}:{:?}", value.code, value.modifiers))),
                 }
             };
         }
 
         expand_variants!(
-            (Up, 1, 'A', |mods: &Vec<i32>| Self::Up(mods[0])),
-            (Down, 1, 'B', |mods: &Vec<i32>| Self::Down(mods[0])),
-            (Right, 1, 'C', |mods: &Vec<i32>| Self::Right(mods[0])),
-            (Left, 1, 'D', |mods: &Vec<i32>| Self::Left(mods[0])),
+            (Up, 1, 'A', |mods: &ModifierCodes| Self::Up(mods[0])),
+            (Down, 1, 'B', |mods: &ModifierCodes| Self::Down(mods[0])),
+            (Right, 1, 'C', |mods: &ModifierCodes| Self::Right(mods[0])),
+            (Left, 1, 'D', |mods: &ModifierCodes| Self::Left(mods[0])),
             // Because we put the modifiers.len() check at case level instead of match,
             // Origin here will unfortunately absorb anything that should've gone to
             // Absolute!
             // (Origin, 0, 'H', | _ | Self::Origin),
-            (Absolute, 2, 'H', |mods: &Vec<i32>| {
+            (Absolute, 2, 'H', |mods: &ModifierCodes| {
                 if mods[0] == 0 && mods[1] == 0 {
                     Self::Origin
                 } else {
@@ -3,7 +3,9 @@ use strum_macros::EnumIter;
 
 use enum_index::*;
 
-use conch_base_models::{ANSIEscapeCode, IntoANSIEscapeCode, ModifierError};
+use conch_base_models::{
+    ANSIEscapeCode, EscapeCodePart, IntoANSIEscapeCode, ModifierError, ModifierErrorKind,
+};
 use conch_macros::ansi_enum_builder as builder;
 
 type OptionU8 = Option<u8>;
@@ -15,7 +17,10 @@ macro_rules! color_builder {
         $reset_idx:literal
     ) => {
         /// An Enum for ANSI 256-colour codes.
+        ///
+        /// With the `serde` feature, this is serialized as its variant name.
         #[derive(Clone, Debug, EnumIter, EnumIndex, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         #[index_type(OptionU8)]
         pub enum $enum_name {
             #[index(Some(0))]
@@ -828,7 +833,11 @@ macro_rules! color_builder {
                 self.index()
                     .map(|colour_idx| {
                         // Colour index
-                        ANSIEscapeCode::new(Some($apply_idx), Some(vec![5, colour_idx as i32]), 'm')
+                        ANSIEscapeCode::with_modifiers(
+                            Some($apply_idx),
+                            &[5, colour_idx as i32],
+                            'm',
+                        )
                     })
                     .unwrap_or(
                         // Reset
@@ -857,55 +866,54 @@ macro_rules! color_builder {
             type Error = ModifierError;
 
             fn try_from(value: &ANSIEscapeCode) -> Result<Self, Self::Error> {
-                // Refactor TryFrom<&Captures<'t>> to use this instead
+                let error = |kind| ModifierError::new(kind, stringify!($enum_name));
+
                 if value.end_char != 'm' {
-                    return Err(ModifierError::UnexpectedEndCharacter(
-                        stringify!($enum_name).to_string(),
-                        value.end_char.to_string(),
-                    ));
+                    return Err(error(ModifierErrorKind::UnexpectedEndChar)
+                        .with_expected("`m`")
+                        .with_found(value.end_char)
+                        .with_part(EscapeCodePart::EndChar));
                 }
 
                 match (value.code, value.modifiers.len()) {
                     (Some($reset_idx), 0) => Ok(Self::Reset),
                     (Some($apply_idx), 2) => match value.modifiers[0] {
                         5 => u8::try_from(value.modifiers[1])
-                            .or(Err(ModifierError::VariantNotFound(
-                                stringify!($enum_name).to_string(),
-                                value.modifiers.clone(),
-                            )))
-                            .and_then(|colour_code| {
-                                Self::try_from(&Some(colour_code)).or(Err(
-                                    ModifierError::VariantNotFound(
-                                        stringify!($enum_name).to_string(),
-                                        value.modifiers.clone(),
-                                    ),
-                                ))
+                            .ok()
+                            .and_then(|colour_code| Self::try_from(&Some(colour_code)).ok())
+                            .ok_or_else(|| {
+                                error(ModifierErrorKind::VariantNotFound)
+                                    .with_expected("a colour between 0 and 255")
+                                    .with_found(value.modifiers[1])
+                                    .with_part(EscapeCodePart::Param(2))
                             }),
-                        _ => Err(ModifierError::ValueNotRecognised(
-                            stringify!($enum_name).to_string(),
-                            format!("{:?}", value.modifiers),
-                            String::from("Non 5 codes are not allowed."),
-                        )),
+                        mode => Err(error(ModifierErrorKind::UnrecognisedCodes)
+                            .with_expected("`5` for 256 colours")
+                            .with_found(mode)
+                            .with_part(EscapeCodePart::Param(1))),
                     },
                     (Some(idx), 2) if idx != $apply_idx && idx != $reset_idx => {
-                        Err(ModifierError::MismatchedANSICode(
-                            stringify!($enum_name).to_string(),
-                            idx,
-                            $apply_idx,
-                        ))
+                        Err(error(ModifierErrorKind::MismatchedCode)
+                            .with_expected(format!("`{}`", $apply_idx))
+                            .with_found(idx)
+                            .with_part(EscapeCodePart::Param(0)))
                     }
                     (Some(idx), 0) if idx != $apply_idx && idx != $reset_idx => {
-                        Err(ModifierError::MismatchedANSICode(
-                            stringify!($enum_name).to_string(),
-                            idx,
-                            $reset_idx,
-                        ))
+                        Err(error(ModifierErrorKind::MismatchedCode)
+                            .with_expected(format!("`{}`", $reset_idx))
+                            .with_found(idx)
+                            .with_part(EscapeCodePart::Param(0)))
                     }
-                    _ => Err(ModifierError::ValueNotRecognised(
-                        stringify!($enum_name).to_string(),
-                        format!("{:?}:{:?}", value.code, value.modifiers),
-                        String::from("Wrong combination of codes."),
-                    )),
+                    (code, count) => Err(error(ModifierErrorKind::WrongArgumentCount)
+                        .with_expected(format!(
+                            "`{}` alone, or `{};5` with a colour",
+                            $reset_idx, $apply_idx
+                        ))
+                        .with_found(format!(
+                            "{} with {} argument(s)",
+                            code.map_or(String::from("no code"), |code| format!("`{}`", code)),
+                            count
+                        ))),
                 }
             }
         }
@@ -0,0 +1,92 @@
+use std::fmt;
+use strum_macros::EnumIter;
+
+use enum_index::*;
+
+use conch_base_models::{
+    ANSIEscapeCode, EscapeCodePart, IntoANSIEscapeCode, ModifierError, ModifierErrorKind, Resetter,
+};
+use conch_macros::ansi_enum_builder as builder;
+
+/// Decoration modifier, such as italics or underlines.
+///
+/// Each decoration is reset independently of the others, and of [`crate::Intensity`].
+///
+/// With the `serde` feature, this is serialized as its variant name.
+#[derive(Clone, Debug, EnumIter, EnumIndex, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[index_type(u16)]
+pub enum Decoration {
+    #[index(3)]
+    Italic,
+
+    #[index(4)]
+    Underline,
+
+    #[index(9)]
+    Strikethrough,
+
+    #[index(23)]
+    NotItalic,
+
+    #[index(24)]
+    NotUnderline,
+
+    #[index(29)]
+    NotStrikethrough,
+}
+
+impl Resetter for Decoration {
+    /// Attempt to reset any settings to before this modifier was applied.
+    ///
+    /// The resultant modifier turns off this decoration only for all subsequent text.
+    #[allow(unused_variables)]
+    fn resetter(&self, input: Option<&str>) -> Self {
+        match self {
+            Self::Italic | Self::NotItalic => Self::NotItalic,
+            Self::Underline | Self::NotUnderline => Self::NotUnderline,
+            Self::Strikethrough | Self::NotStrikethrough => Self::NotStrikethrough,
+        }
+    }
+}
+
+impl IntoANSIEscapeCode for Decoration {
+    fn into_ansi_escape_code(&self) -> ANSIEscapeCode {
+        ANSIEscapeCode::new(Some(self.index()), None, 'm')
+    }
+}
+
+impl TryFrom<&ANSIEscapeCode> for Decoration {
+    type Error = ModifierError;
+
+    fn try_from(value: &ANSIEscapeCode) -> Result<Self, Self::Error> {
+        let error = |kind| ModifierError::new(kind, "Decoration");
+
+        if value.end_char != 'm' {
+            return Err(error(ModifierErrorKind::UnexpectedEndChar)
+                .with_expected("`m`")
+                .with_found(value.end_char)
+                .with_part(EscapeCodePart::EndChar));
+        }
+
+        if !value.modifiers.is_empty() {
+            return Err(error(ModifierErrorKind::WrongArgumentCount)
+                .with_expected("no arguments")
+                .with_found(format!("{} argument(s)", value.modifiers.len()))
+                .with_part(EscapeCodePart::Param(1)));
+        }
+
+        match value.code {
+            Some(code) => Self::try_from(&code).map_err(|_| {
+                error(ModifierErrorKind::MismatchedCode)
+                    .with_expected("`3`, `4`, `9`, `23`, `24` or `29`")
+                    .with_found(code)
+                    .with_part(EscapeCodePart::Param(0))
+            }),
+            None => Err(error(ModifierErrorKind::MissingCode)
+                .with_expected("`3`, `4`, `9`, `23`, `24` or `29`")),
+        }
+    }
+}
+
+builder!(Decoration);
@@ -3,11 +3,16 @@ use strum_macros::EnumIter;
 
 use enum_index::*;
 
-use conch_base_models::{ANSIEscapeCode, IntoANSIEscapeCode, ModifierError, Resetter};
+use conch_base_models::{
+    ANSIEscapeCode, EscapeCodePart, IntoANSIEscapeCode, ModifierError, ModifierErrorKind, Resetter,
+};
 use conch_macros::ansi_enum_builder as builder;
 
 /// Intensity modifier
+///
+/// With the `serde` feature, this is serialized as its variant name.
 #[derive(Clone, Debug, EnumIter, EnumIndex, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[index_type(u16)]
 pub enum Intensity {
     #[index(22)]
@@ -45,32 +50,30 @@ impl TryFrom<&ANSIEscapeCode> for Intensity {
     type Error = ModifierError;
 
     fn try_from(value: &ANSIEscapeCode) -> Result<Self, Self::Error> {
+        let error = |kind| ModifierError::new(kind, "Intensity");
+
         if value.end_char != 'm' {
-            return Err(ModifierError::UnexpectedEndCharacter(
-                stringify!($enum_name).to_string(),
-                value.end_char.to_string(),
-            ));
+            return Err(error(ModifierErrorKind::UnexpectedEndChar)
+                .with_expected("`m`")
+                .with_found(value.end_char)
+                .with_part(EscapeCodePart::EndChar));
         }
 
-        if value.modifiers.len() > 0 {
-            return Err(ModifierError::ValueNotRecognised(
-                stringify!($enum_name).to_string(),
-                format!("{:?}:{:?}", value.code, value.modifiers),
-                String::from("This code does not accept modifiers."),
-            ));
+        if !value.modifiers.is_empty() {
+            return Err(error(ModifierErrorKind::WrongArgumentCount)
+                .with_expected("no arguments")
+                .with_found(format!("{} argument(s)", value.modifiers.len()))
+                .with_part(EscapeCodePart::Param(1)));
         }
 
-        if let Some(code) = value.code {
-            Self::try_from(&code).or(Err(ModifierError::MismatchedANSICode(
-                stringify!($enum_name).to_string(),
-                code,
-                1, // For the lack of a better code
-            )))
-        } else {
-            Err(ModifierError::MissingANSICode(
-                stringify!($enum_name).to_string(),
-                1, // For the lack of a better code
-            ))
+        match value.code {
+            Some(code) => Self::try_from(&code).map_err(|_| {
+                error(ModifierErrorKind::MismatchedCode)
+                    .with_expected("`1`, `2` or `22`")
+                    .with_found(code)
+                    .with_part(EscapeCodePart::Param(0))
+            }),
+            None => Err(error(ModifierErrorKind::MissingCode).with_expected("`1`, `2` or `22`")),
         }
     }
 }
@@ -1,5 +1,8 @@
 mod colours;
 pub use colours::*;
 
+mod decoration;
+pub use decoration::*;
+
 mod intensity;
 pub use intensity::*;
@@ -1,6 +1,8 @@
 mod unified;
 pub use unified::*;
 
+mod style;
+
 mod font;
 pub use font::*;
 
@@ -0,0 +1,245 @@
+//! Human readable style strings for [`Modifier`], such as `"Bold fg:BrightRed"`.
+//!
+//! These are meant for configuration files written by end users, where raw escape
+//! codes would be unreadable.
+
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::{Modifier, MoveCursor};
+use conch_base_models::{ModifierError, ModifierErrorKind};
+
+/// Prefixes accepted before a `:` in a style string.
+const PREFIXES: &str = "one of `fg`, `bg`, `up`, `down`, `left`, `right` or `at`";
+
+/// Split `text` by whitespace, yielding each token with its byte offset.
+fn tokens(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_whitespace()
+        .map(move |token| (token.as_ptr() as usize - text.as_ptr() as usize, token))
+}
+
+/// Build a [`ModifierError`] pointing at `span` of a style string.
+fn style_error(
+    kind: ModifierErrorKind,
+    expected: &str,
+    found: &str,
+    span: Range<usize>,
+) -> ModifierError {
+    ModifierError::new(kind, "Modifier")
+        .with_expected(expected)
+        .with_found(found)
+        .with_span(span)
+}
+
+/// Parse an integer argument found at `offset` of a style string.
+fn parse_int(value: &str, offset: usize) -> Result<i32, ModifierError> {
+    value.parse().map_err(|_| {
+        style_error(
+            ModifierErrorKind::InvalidArgument,
+            "an integer",
+            value,
+            offset..offset + value.len(),
+        )
+    })
+}
+
+/// Parse a single token found at `offset` of a style string.
+fn parse_token(token: &str, offset: usize) -> Result<Modifier, ModifierError> {
+    let Some((prefix, value)) = token.split_once(':') else {
+        return match token {
+            "none" => Ok(Modifier::Nothing),
+            "origin" => Ok(Modifier::origin()),
+            name => Modifier::intensity(name)
+                .or_else(|| Modifier::decoration(name))
+                .ok_or_else(|| {
+                    style_error(
+                        ModifierErrorKind::UnrecognisedStyle,
+                        "an intensity such as `Bold`, a decoration such as `Italic`, `origin`, \
+                    `none`, or a prefixed style such as `fg:BrightRed`",
+                        name,
+                        offset..offset + name.len(),
+                    )
+                }),
+        };
+    };
+
+    let value_offset = offset + prefix.len() + 1;
+    let unrecognised_name = |expected| {
+        style_error(
+            ModifierErrorKind::UnrecognisedStyle,
+            expected,
+            value,
+            value_offset..value_offset + value.len(),
+        )
+    };
+
+    match prefix {
+        "fg" => Modifier::colour(value)
+            .ok_or_else(|| unrecognised_name("a colour name such as `BrightRed`")),
+        "bg" => Modifier::background(value)
+            .ok_or_else(|| unrecognised_name("a colour name such as `BrightRed`")),
+        "up" => parse_int(value, value_offset).map(Modifier::up),
+        "down" => parse_int(value, value_offset).map(Modifier::down),
+        "left" => parse_int(value, value_offset).map(Modifier::left),
+        "right" => parse_int(value, value_offset).map(Modifier::right),
+        "at" => match value.split_once(',') {
+            Some((x, y)) => Ok(Modifier::absolute(
+                parse_int(x, value_offset)?,
+                parse_int(y, value_offset + x.len() + 1)?,
+            )),
+            None => Err(style_error(
+                ModifierErrorKind::InvalidArgument,
+                "a position such as `10,20`",
+                value,
+                value_offset..value_offset + value.len(),
+            )),
+        },
+        _ => Err(style_error(
+            ModifierErrorKind::UnrecognisedStyle,
+            PREFIXES,
+            prefix,
+            offset..offset + prefix.len(),
+        )),
+    }
+}
+
+/// Parse a whitespace separated style string into a [`Modifier`].
+///
+/// Each word is one of:
+///
+/// - an [`crate::Intensity`] such as `Bold`,
+/// - a [`crate::Decoration`] such as `Italic`,
+/// - `fg:` or `bg:` followed by a [`crate::Colour`] name, such as `fg:BrightRed`,
+/// - `up:`, `down:`, `left:` or `right:` followed by a number of cells,
+/// - `at:` followed by a position such as `at:10,20`, or `origin`,
+/// - `none`, for [`Modifier::Nothing`].
+///
+/// Multiple words are combined into a [`Modifier::Combo`]. Errors point at the
+/// offending word, so that [`ModifierError::render()`] can show the user where the
+/// problem is.
+///
+/// ```rust
+/// use conch::*;
+///
+/// let modifier: Modifier = "Bold fg:BrightRed bg:Blue".parse().unwrap();
+/// assert_eq!(
+///     modifier,
+///     Modifier::intensity("Bold").unwrap()
+///         + Modifier::colour("BrightRed").unwrap()
+///         + Modifier::background("Blue").unwrap()
+/// );
+///
+/// let source = "Bold fg:Bleu";
+/// let error = source.parse::<Modifier>().unwrap_err();
+/// assert_eq!(error.span, Some(8..12));
+/// ```
+impl FromStr for Modifier {
+    type Err = ModifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        tokens(s).try_fold(Self::Nothing, |modifier, (offset, token)| {
+            Ok(modifier + parse_token(token, offset)?)
+        })
+    }
+}
+
+impl Modifier {
+    /// Write the canonical style string of this [`Modifier`] into `w`.
+    ///
+    /// See [`Modifier::from_str()`] for the format.
+    pub fn write_style<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        match self {
+            Self::Intensity(intensity) => write!(w, "{:?}", intensity),
+            Self::Decoration(decoration) => write!(w, "{:?}", decoration),
+            Self::Colour(colour) => write!(w, "fg:{:?}", colour),
+            Self::Background(background) => write!(w, "bg:{:?}", background),
+            Self::MoveCursor(movement) => match movement {
+                MoveCursor::Up(n) => write!(w, "up:{}", n),
+                MoveCursor::Down(n) => write!(w, "down:{}", n),
+                MoveCursor::Left(n) => write!(w, "left:{}", n),
+                MoveCursor::Right(n) => write!(w, "right:{}", n),
+                MoveCursor::Origin => w.write_str("origin"),
+                MoveCursor::Absolute(x, y) => write!(w, "at:{},{}", x, y),
+            },
+            Self::Combo(modifiers) => {
+                let mut modifiers = modifiers
+                    .iter()
+                    .filter(|modifier| !modifier.is_nothing())
+                    .peekable();
+
+                if modifiers.peek().is_none() {
+                    return w.write_str("none");
+                }
+
+                modifiers.enumerate().try_for_each(|(idx, modifier)| {
+                    if idx > 0 {
+                        w.write_char(' ')?;
+                    }
+                    modifier.write_style(w)
+                })
+            }
+            Self::Nothing => w.write_str("none"),
+        }
+    }
+
+    /// Return the canonical style string of this [`Modifier`].
+    ///
+    /// Parsing this string with [`Modifier::from_str()`] gives back an equal
+    /// [`Modifier`].
+    ///
+    /// ```rust
+    /// use conch::*;
+    ///
+    /// let modifier = Modifier::intensity("Bold").unwrap() + Modifier::up(3);
+    ///
+    /// assert_eq!(modifier.to_style_string(), "Bold up:3");
+    /// assert_eq!(modifier.to_style_string().parse::<Modifier>().unwrap(), modifier);
+    /// ```
+    pub fn to_style_string(&self) -> String {
+        let mut output = String::new();
+
+        self.write_style(&mut output)
+            .expect("Writing to a `String` should never fail.");
+
+        output
+    }
+
+    /// Check if this [`Modifier`] does nothing at all.
+    fn is_nothing(&self) -> bool {
+        match self {
+            Self::Nothing => true,
+            Self::Combo(modifiers) => modifiers.iter().all(Self::is_nothing),
+            _ => false,
+        }
+    }
+}
+
+/// Serialize a [`Modifier`] as its style string.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Modifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_style_string())
+    }
+}
+
+/// Deserialize a [`Modifier`] from its style string.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Modifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let style = String::deserialize(deserializer)?;
+
+        style
+            .parse()
+            .map_err(|err: ModifierError| serde::de::Error::custom(err))
+    }
+}
@@ -5,14 +5,18 @@ use std::{fmt, ops};
 
 use enum_index::VariantByName;
 
-use crate::{Background, Colour, Intensity, MoveCursor};
-use conch_base_models::{ANSIEscapeCode, HasLength, ModifierError, Resetter, StringWrapper};
+use crate::{Background, Colour, Decoration, Intensity, MoveCursor};
+use conch_base_models::{
+    ANSIEscapeCode, ANSIEscapeCodeRef, EscapeCodePart, HasLength, ModifierError, ModifierErrorKind,
+    Resetter, StringWrapper,
+};
 
 /// Unified [`Modifier`] enum type.
 ///
-/// For each of the enum types of [`Background`], [`Colour`] and [`Intensity`], this
-/// enum has a corresponding variant, bringing them under the same struct. Each of
-/// these variant takes a single-element tuple value of the corresponding enum type.
+/// For each of the enum types of [`Background`], [`Colour`], [`Decoration`] and
+/// [`Intensity`], this enum has a corresponding variant, bringing them under the same
+/// struct. Each of these variant takes a single-element tuple value of the
+/// corresponding enum type.
 ///
 /// Most important methods are implemented and passed through to the underlying
 /// enum variant, such as [`Self::wraps()`] and [`Self::len()`], so in most cases they
@@ -81,10 +85,16 @@ use conch_base_models::{ANSIEscapeCode, HasLength, ModifierError, Resetter, Stri
 ///     "\u{1b}[38;5;9m\u{1b}[1mHello, world!\u{1b}[22m\u{1b}[39m"
 /// )
 /// ```
+///
+/// More kinds of [`Modifier`] may be supported in the future, such as
+/// [`Modifier::Decoration`] was; matching on it outside of this crate requires a
+/// wildcard arm.
 #[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Modifier {
     Intensity(Intensity),
+    Decoration(Decoration),
     Colour(Colour),
     Background(Background),
     MoveCursor(MoveCursor),
@@ -140,6 +150,7 @@ macro_rules! expand_variants {
 
 expand_variants!(
     (Intensity, Intensity, intensity),
+    (Decoration, Decoration, decoration),
     (Colour, Colour, colour),
     (Background, Background, background),
 );
@@ -173,7 +184,7 @@ impl HasLength for Modifier {
             };
         }
 
-        expand_variants!(Intensity, Colour, Background, MoveCursor)
+        expand_variants!(Intensity, Decoration, Colour, Background, MoveCursor)
     }
 }
 
@@ -205,7 +216,7 @@ impl Resetter for Modifier {
             };
         }
 
-        expand_variants!(Intensity, Colour, Background, MoveCursor)
+        expand_variants!(Intensity, Decoration, Colour, Background, MoveCursor)
     }
 }
 
@@ -273,24 +284,51 @@ impl fmt::Display for Modifier {
             };
         }
 
-        expand_variants!(Intensity, Colour, Background, MoveCursor)
+        expand_variants!(Intensity, Decoration, Colour, Background, MoveCursor)
+    }
+}
+
+impl Modifier {
+    /// Write the resetter of this [`Modifier`] for `text` into `w`.
+    ///
+    /// This is the same as displaying [`Resetter::resetter()`], except that no
+    /// [`Modifier::Combo`] is built for the reversed resetters.
+    fn write_resetter<W>(&self, w: &mut W, text: &str) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        match self {
+            Self::Combo(modifiers) => modifiers
+                .iter()
+                .rev()
+                .try_for_each(|modifier| modifier.write_resetter(w, text)),
+            modifier => write!(w, "{}", modifier.resetter(Some(text))),
+        }
     }
 }
 
 /// Allows a `Modifier` to wrap a `str`, decorating it and resetting itself afterwards.
 impl StringWrapper for Modifier {
     /// Enclose the text with the modifier.
-    fn wraps(&self, text: &str) -> String {
+    ///
+    /// For [`Modifier::Combo`], the modifiers are applied in sequence, then reset in
+    /// reversed sequence after the text.
+    fn write_wrapped<W>(&self, w: &mut W, text: &str) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
         match self {
-            Self::Combo(mods) => mods
-                .iter()
-                .rev()
-                .fold(String::from(text), |text, modifier| modifier.wraps(&text)),
-            Self::Intensity(modifier) => modifier.wraps(text),
-            Self::Colour(modifier) => modifier.wraps(text),
-            Self::Background(modifier) => modifier.wraps(text),
-            Self::MoveCursor(modifier) => modifier.wraps(text),
-            Self::Nothing => text.to_string(),
+            Self::Combo(_) => {
+                write!(w, "{}", self)?;
+                w.write_str(text)?;
+                self.write_resetter(w, text)
+            }
+            Self::Intensity(modifier) => modifier.write_wrapped(w, text),
+            Self::Decoration(modifier) => modifier.write_wrapped(w, text),
+            Self::Colour(modifier) => modifier.write_wrapped(w, text),
+            Self::Background(modifier) => modifier.write_wrapped(w, text),
+            Self::MoveCursor(modifier) => modifier.write_wrapped(w, text),
+            Self::Nothing => w.write_str(text),
         }
     }
 }
@@ -310,10 +348,14 @@ impl TryFrom<&ANSIEscapeCode> for Modifier {
                         },
                     )+
                     (Some(code), _) => Err(
-                        ModifierError::UnsupportedANSICode(code),
+                        ModifierError::new(ModifierErrorKind::UnsupportedCode, "Modifier")
+                            .with_found(code)
+                            .with_part(EscapeCodePart::Param(0)),
                     ),
                     (_, chr) => Err(
-                        ModifierError::UnsupportedEndChar(chr),
+                        ModifierError::new(ModifierErrorKind::UnsupportedEndChar, "Modifier")
+                            .with_found(chr)
+                            .with_part(EscapeCodePart::EndChar),
                     ),
                 }
             };
@@ -323,6 +365,7 @@ impl TryFrom<&ANSIEscapeCode> for Modifier {
             (Intensity, Intensity, Some(1), 'm'),
             (Intensity, Intensity, Some(2), 'm'),
             (Intensity, Intensity, Some(22), 'm'),
+            (Decoration, Decoration, Some(3 | 4 | 9 | 23 | 24 | 29), 'm'),
             (Colour, Colour, Some(38), 'm'),
             (Colour, Colour, Some(39), 'm'),
             (Background, Background, Some(48), 'm'),
@@ -345,16 +388,27 @@ impl TryFrom<ANSIEscapeCode> for Modifier {
     }
 }
 
+/// Also supports an [`ANSIEscapeCodeRef`] found within a text.
+impl TryFrom<&ANSIEscapeCodeRef<'_>> for Modifier {
+    type Error = ModifierError;
+
+    fn try_from(value: &ANSIEscapeCodeRef<'_>) -> Result<Self, Self::Error> {
+        value.try_convert()
+    }
+}
+
 /// Try to parse a [`str`] into a known [`Modifier`].
 ///
 /// This is simply chaining together
 ///
-/// - [`TryFrom<&str>`] of [`ANSIEscapeCode`], and
-/// - [`TryFrom<ANSIEscapeCode>`] of [`Modifier`]
+/// - [`ANSIEscapeCodeRef::parse()`], and
+/// - [`TryFrom<&ANSIEscapeCode>`] of [`Modifier`],
+///
+/// so that any error is located within `value`.
 impl TryFrom<&str> for Modifier {
     type Error = ModifierError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        ANSIEscapeCode::try_from(value).and_then(|ansi| Modifier::try_from(ansi))
+        ANSIEscapeCodeRef::parse(value).and_then(|code| code.try_convert())
     }
 }
@@ -1,5 +1,8 @@
 mod enums;
 pub use enums::*;
 
+mod structs;
+pub use structs::*;
+
 mod traits;
 pub use traits::*;
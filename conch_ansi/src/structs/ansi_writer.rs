@@ -0,0 +1,398 @@
+//! An [`io::Write`] adapter that decides what to do with the escape codes
+//! passing through it.
+
+use std::io::{self, Write};
+
+use conch_base_models::{scan_escape_sequence, EscapeSequenceScan, ESCAPE_CHAR};
+
+/// The 16 standard colours in RGB, as rendered by `xterm`.
+const PALETTE_16: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The intensity of each step of the 6x6x6 colour cube.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Colour depths that an [`ANSIWriter`] can downgrade its output to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColourDepth {
+    /// 24-bit colours; nothing is changed.
+    TrueColour,
+
+    /// The 256 indexed colours used by [`crate::Colour`] and [`crate::Background`].
+    Colours256,
+
+    /// The 8 standard colours and their bright variants.
+    Colours16,
+
+    /// The 8 standard colours only.
+    Colours8,
+
+    /// No colours at all; other attributes such as [`crate::Intensity`] are kept.
+    Monochrome,
+}
+
+/// A colour found in a Select Graphic Rendition sequence.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SGRColour {
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl SGRColour {
+    fn rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Self::Indexed(idx @ 0..=15) => PALETTE_16[idx as usize],
+            Self::Indexed(idx @ 16..=231) => {
+                let idx = idx - 16;
+                (
+                    CUBE_LEVELS[(idx / 36) as usize],
+                    CUBE_LEVELS[(idx / 6 % 6) as usize],
+                    CUBE_LEVELS[(idx % 6) as usize],
+                )
+            }
+            Self::Indexed(idx) => {
+                let level = 8 + (idx - 232) * 10;
+                (level, level, level)
+            }
+            Self::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// Find the index within `candidates` of the 256 colours closest to this colour.
+    fn nearest(&self, candidates: impl Iterator<Item = u8>) -> u8 {
+        let (r, g, b) = self.rgb();
+        let distance = |idx: &u8| {
+            let (r2, g2, b2) = Self::Indexed(*idx).rgb();
+            [(r, r2), (g, g2), (b, b2)]
+                .iter()
+                .map(|(lhs, rhs)| (*lhs as i32 - *rhs as i32).pow(2))
+                .sum::<i32>()
+        };
+
+        candidates.min_by_key(distance).unwrap_or(0)
+    }
+}
+
+impl ColourDepth {
+    /// Downgrade the parameters of a Select Graphic Rendition sequence, i.e. the
+    /// `38;5;9` in `\x1b[38;5;9m`.
+    ///
+    /// Extended colours are read both in their `;` form, `38;5;9`, and in the `:`
+    /// form of ITU T.416, `38:5:9` or `38:2::255:0:0` with its colour space left
+    /// empty. Extended colours missing some of their parameters are dropped, and
+    /// those with values out of range are left alone.
+    ///
+    /// Returns [`None`] if nothing is left of the sequence, in which case it should
+    /// be dropped entirely.
+    pub(crate) fn downgrade_sgr(&self, params: &str) -> Option<String> {
+        if params.is_empty() {
+            return Some(String::new());
+        }
+
+        // Each parameter, with its `:` separated sub-parameters.
+        let groups: Vec<&str> = params.split(';').collect();
+        let codes: Vec<Vec<u32>> = match Result::from_iter(groups.iter().map(|group| {
+            Result::from_iter(group.split(':').map(|code| {
+                if code.is_empty() {
+                    Ok(0)
+                } else {
+                    code.parse::<u32>()
+                }
+            }))
+        })) {
+            Ok(codes) => codes,
+            // Private or malformed parameters; leave them alone.
+            Err(_) => return Some(params.to_string()),
+        };
+
+        // The single code of the parameter at `idx`, unless it has sub-parameters.
+        let single = |idx: usize| match codes.get(idx).map(Vec::as_slice) {
+            Some([code]) => Some(*code),
+            _ => None,
+        };
+
+        // The colour of the values after `38` or `48`, if complete.
+        let extended = |values: &[u32]| match values {
+            [5, colour] => Some(u8::try_from(*colour).map(SGRColour::Indexed)),
+            [2, r, g, b] | [2, _, r, g, b] => Some(
+                [*r, *g, *b]
+                    .map(u8::try_from)
+                    .into_iter()
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|rgb| SGRColour::Rgb(rgb[0], rgb[1], rgb[2])),
+            ),
+            _ => None,
+        };
+
+        // A colour of the 16 standard ones, set by a basic code.
+        let standard = |idx: u32, background: bool| {
+            (Some(Ok(SGRColour::Indexed(idx as u8))), background, true, 1)
+        };
+
+        let mut output: Vec<String> = Vec::with_capacity(groups.len());
+        let mut idx = 0;
+        while idx < codes.len() {
+            let (colour, background, basic, consumed) = match codes[idx].as_slice() {
+                // The `:` form, all within one parameter.
+                [base @ (38 | 48), values @ ..] if !values.is_empty() => {
+                    (extended(values), *base == 48, false, 1)
+                }
+                // The `;` form, over the parameters that follow.
+                [base @ (38 | 48)] => {
+                    let count = match single(idx + 1) {
+                        Some(5) => 2,
+                        Some(2) => 4,
+                        _ => 0,
+                    };
+                    let values: Option<Vec<u32>> = (idx + 1..=idx + count).map(single).collect();
+
+                    match values.filter(|_| count > 0) {
+                        Some(values) => (extended(&values), *base == 48, false, count + 1),
+                        // An unknown or incomplete colour; the length of what is left
+                        // of it cannot be known, so drop the rest of the sequence.
+                        None => (None, false, false, codes.len() - idx),
+                    }
+                }
+                [code @ 30..=37] => standard(code - 30, false),
+                [code @ 90..=97] => standard(code - 82, false),
+                [code @ 40..=47] => standard(code - 40, true),
+                [code @ 100..=107] => standard(code - 92, true),
+                [39 | 49] if self == &Self::Monochrome => (None, false, false, 1),
+                _ => {
+                    output.push(groups[idx].to_string());
+                    (None, false, false, 1)
+                }
+            };
+
+            let original = &groups[idx..idx + consumed];
+            match colour {
+                Some(Ok(colour)) => {
+                    self.push_colour(&mut output, original, colour, background, basic)
+                }
+                // Values out of range of any colour; leave them as they are.
+                Some(Err(_)) => output.extend(original.iter().map(|group| group.to_string())),
+                None => {}
+            }
+            idx += consumed;
+        }
+
+        if output.is_empty() {
+            None
+        } else {
+            Some(output.join(";"))
+        }
+    }
+
+    /// Push the parameters for a colour in this depth, given the `original`
+    /// parameters it was found in, and whether they were a `basic` 16 colour code.
+    fn push_colour(
+        &self,
+        output: &mut Vec<String>,
+        original: &[&str],
+        colour: SGRColour,
+        bg: bool,
+        basic: bool,
+    ) {
+        let (base, basic_base, bright_base) = if bg { (48, 40, 100) } else { (38, 30, 90) };
+
+        let to_basic = |idx: u8| {
+            if idx < 8 {
+                (basic_base + idx as u32).to_string()
+            } else {
+                (bright_base + idx as u32 - 8).to_string()
+            }
+        };
+        let unchanged = original.iter().map(|group| group.to_string());
+
+        match (self, colour) {
+            (Self::Monochrome, _) => {}
+            (Self::TrueColour, _) => output.extend(unchanged),
+            (Self::Colours256, SGRColour::Rgb(..)) => {
+                output.push(format!("{};5;{}", base, colour.nearest(16..=255)))
+            }
+            (Self::Colours256, _) => output.extend(unchanged),
+            (Self::Colours16, SGRColour::Indexed(idx)) if idx < 16 && basic => {
+                output.extend(unchanged)
+            }
+            (Self::Colours16, _) => output.push(to_basic(colour.nearest(0..16))),
+            (Self::Colours8, _) => output.push(to_basic(colour.nearest(0..8))),
+        }
+    }
+}
+
+/// What an [`ANSIWriter`] does to the escape codes written through it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ANSIWriterMode {
+    /// Write everything unchanged.
+    #[default]
+    PassThrough,
+
+    /// Remove all escape codes, leaving plain text behind; useful for log files.
+    Strip,
+
+    /// Rewrite all colours to fit within a [`ColourDepth`].
+    Downgrade(ColourDepth),
+}
+
+/// An [`io::Write`] wrapper that parses the outgoing byte stream, and passes escape
+/// codes through, strips them or downgrades their colours according to its
+/// [`ANSIWriterMode`].
+///
+/// Escape codes split across multiple calls to [`Write::write()`] are held back
+/// until they are complete.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use std::io::Write;
+/// use conch::*;
+///
+/// let mut writer = ANSIWriter::new(Vec::new(), ANSIWriterMode::Strip);
+///
+/// write!(writer, "{}", Modifier::colour("BrightRed").unwrap().wraps("Hello, ")).unwrap();
+/// writer.write_all(b"\x1b[1").unwrap(); // An incomplete escape code...
+/// writer.write_all(b"mWorld!\x1b[22m").unwrap(); // ...completed in the next write.
+///
+/// assert_eq!(writer.into_inner(), b"Hello, World!");
+/// ```
+#[derive(Debug)]
+pub struct ANSIWriter<W>
+where
+    W: Write,
+{
+    inner: W,
+    mode: ANSIWriterMode,
+
+    /// Bytes of an escape code that is not terminated yet.
+    pending: Vec<u8>,
+}
+
+impl<W> ANSIWriter<W>
+where
+    W: Write,
+{
+    /// Create a new [`ANSIWriter`] around `inner`.
+    pub fn new(inner: W, mode: ANSIWriterMode) -> Self {
+        Self {
+            inner,
+            mode,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Chained method to change the [`ANSIWriterMode`] of this instance.
+    pub fn mode(mut self, mode: ANSIWriterMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Get a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap this [`ANSIWriter`], returning the underlying writer.
+    ///
+    /// Any incomplete escape code held back is discarded.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Write a complete escape sequence to `inner` according to `mode`.
+    fn write_sequence(&mut self, sequence: &[u8]) -> io::Result<()> {
+        match self.mode {
+            ANSIWriterMode::PassThrough => self.inner.write_all(sequence),
+            ANSIWriterMode::Strip => Ok(()),
+            ANSIWriterMode::Downgrade(depth) => match sequence {
+                [ESCAPE_CHAR, b'[', params @ .., b'm'] => {
+                    match std::str::from_utf8(params)
+                        .ok()
+                        .map(|params| depth.downgrade_sgr(params))
+                    {
+                        Some(Some(params)) => write!(self.inner, "\x1b[{}m", params),
+                        Some(None) => Ok(()),
+                        None => self.inner.write_all(sequence),
+                    }
+                }
+                _ => self.inner.write_all(sequence),
+            },
+        }
+    }
+
+    /// Write all of `data`, which may end with an incomplete escape sequence.
+    fn write_data(&mut self, data: &[u8]) -> io::Result<()> {
+        let mut idx = 0;
+
+        while idx < data.len() {
+            let escape_idx = data[idx..]
+                .iter()
+                .position(|byte| *byte == ESCAPE_CHAR)
+                .map(|offset| idx + offset)
+                .unwrap_or(data.len());
+
+            self.inner.write_all(&data[idx..escape_idx])?;
+
+            match scan_escape_sequence(&data[escape_idx..]) {
+                EscapeSequenceScan::Complete(len) => {
+                    self.write_sequence(&data[escape_idx..escape_idx + len])?;
+                    idx = escape_idx + len;
+                }
+                EscapeSequenceScan::Incomplete => {
+                    self.pending.extend_from_slice(&data[escape_idx..]);
+                    idx = data.len();
+                }
+                EscapeSequenceScan::NotEscape => idx = escape_idx,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<W> Write for ANSIWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.mode == ANSIWriterMode::PassThrough && self.pending.is_empty() {
+            return self.inner.write(buf);
+        }
+
+        if self.pending.is_empty() {
+            self.write_data(buf)?;
+        } else {
+            // Only allocate when an escape code had been split across writes.
+            let mut data = std::mem::take(&mut self.pending);
+            data.extend_from_slice(buf);
+            self.write_data(&data)?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,7 @@
+//! Structs relating to the output of ANSI Escape Codes.
+//!
+mod ansi_writer;
+pub use ansi_writer::*;
+
+mod sync_line_writer;
+pub use sync_line_writer::*;
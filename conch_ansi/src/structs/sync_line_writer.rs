@@ -0,0 +1,154 @@
+//! A line buffered [`io::Write`] that can be shared between threads.
+
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+use conch_base_models::{scan_escape_sequence, EscapeSequenceScan, ESCAPE_CHAR};
+
+/// A line buffered, cloneable handle to a shared [`io::Write`].
+///
+/// Each handle buffers its own output, and only takes the lock on the underlying
+/// writer to write out complete lines in one go. This allows multiple threads to
+/// print styled lines through their own handles without interleaving their output,
+/// or splitting escape codes halfway.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use std::io::Write;
+/// use std::sync::{Arc, Mutex};
+/// use std::thread;
+///
+/// use conch::*;
+///
+/// let output = Arc::new(Mutex::new(Vec::new()));
+/// let writer = SyncLineWriter::from_shared(Arc::clone(&output));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|id| {
+///         let mut writer = writer.clone();
+///         thread::spawn(move || {
+///             let modifier = Modifier::colour("BrightRed").unwrap();
+///             for _ in 0..100 {
+///                 writeln!(writer, "{}", modifier.wraps(&format!("thread {}", id))).unwrap();
+///             }
+///         })
+///     })
+///     .collect();
+///
+/// handles.into_iter().for_each(|handle| handle.join().unwrap());
+///
+/// let output = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+/// assert_eq!(output.lines().count(), 400);
+/// assert!(output
+///     .lines()
+///     .all(|line| line.starts_with("\x1b[38;5;9mthread ") && line.ends_with("\x1b[39m")));
+/// ```
+#[derive(Debug)]
+pub struct SyncLineWriter<W>
+where
+    W: Write,
+{
+    inner: Arc<Mutex<W>>,
+
+    /// Output of this handle not yet written to `inner`.
+    buffer: Vec<u8>,
+}
+
+impl<W> SyncLineWriter<W>
+where
+    W: Write,
+{
+    /// Create a new [`SyncLineWriter`] taking ownership of `inner`.
+    pub fn new(inner: W) -> Self {
+        Self::from_shared(Arc::new(Mutex::new(inner)))
+    }
+
+    /// Create a new [`SyncLineWriter`] around a writer that is already shared.
+    pub fn from_shared(inner: Arc<Mutex<W>>) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Get the underlying shared writer.
+    pub fn shared(&self) -> Arc<Mutex<W>> {
+        Arc::clone(&self.inner)
+    }
+
+    /// Write `self.buffer[..end]` to `inner` under a single lock.
+    fn write_out(&mut self, end: usize) -> io::Result<()> {
+        if end == 0 {
+            return Ok(());
+        }
+
+        let mut inner = self
+            .inner
+            .lock()
+            .map_err(|_| io::Error::other("writer lock poisoned"))?;
+
+        inner.write_all(&self.buffer[..end])?;
+        self.buffer.drain(..end);
+
+        Ok(())
+    }
+
+    /// Find the end of the output that can be written without splitting an escape
+    /// code.
+    fn complete_len(&self) -> usize {
+        let last_escape = self.buffer.iter().rposition(|byte| *byte == ESCAPE_CHAR);
+
+        match last_escape.map(|idx| (idx, scan_escape_sequence(&self.buffer[idx..]))) {
+            Some((idx, EscapeSequenceScan::Incomplete)) => idx,
+            _ => self.buffer.len(),
+        }
+    }
+}
+
+impl<W> Clone for SyncLineWriter<W>
+where
+    W: Write,
+{
+    /// Create another handle to the same underlying writer, with its own buffer.
+    fn clone(&self) -> Self {
+        Self::from_shared(self.shared())
+    }
+}
+
+impl<W> Write for SyncLineWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+
+        if let Some(idx) = self.buffer.iter().rposition(|byte| *byte == b'\n') {
+            self.write_out(idx + 1)?;
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Write out everything buffered so far, except for an escape code that is not
+    /// yet complete, and flush the underlying writer.
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_out(self.complete_len())?;
+
+        self.inner
+            .lock()
+            .map_err(|_| io::Error::other("writer lock poisoned"))?
+            .flush()
+    }
+}
+
+impl<W> Drop for SyncLineWriter<W>
+where
+    W: Write,
+{
+    /// Write out anything left in the buffer before this handle goes away.
+    fn drop(&mut self) {
+        let _ = self.write_out(self.buffer.len());
+    }
+}
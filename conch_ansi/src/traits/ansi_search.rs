@@ -3,39 +3,46 @@
 use std::cmp::min;
 use std::ops::Range;
 
-use regex::{Match, Matches};
+use regex::Match;
 
 use crate::Modifier;
-use conch_base_models::{ANSIEscapeCode, HasLength, ESCAPE_CODE_PATTERN};
+use conch_base_models::{ANSIEscapeCodes, ESCAPE_CODE_PATTERN};
 
-pub struct ModifiersInText<'r, 't>(Matches<'r, 't>);
-impl<'r, 't> ModifiersInText<'r, 't> {
+/// Iterator over all the valid [`Modifier`]s within a [`str`].
+///
+/// Escape codes that are syntax valid but do not represent any [`Modifier`] are
+/// skipped.
+pub struct ModifiersInText<'t>(ANSIEscapeCodes<'t>);
+impl<'t> ModifiersInText<'t> {
     pub fn new(s: &'t str) -> Self {
-        return Self(ESCAPE_CODE_PATTERN.find_iter(s));
+        Self(ANSIEscapeCodes::new(s))
+    }
+
+    /// Also yield the byte range of each [`Modifier`] within the text.
+    pub fn with_spans(self) -> impl Iterator<Item = (Modifier, Range<usize>)> + 't {
+        self.0.filter_map(|code| {
+            Modifier::try_from(&code)
+                .ok()
+                .map(|modifier| (modifier, code.span))
+        })
     }
 
-    pub fn next_match(&mut self) -> Option<Match> {
-        self.0.next()
+    /// Return the next syntax valid escape code as a [`regex::Match`], whether or not
+    /// it represents a [`Modifier`].
+    #[deprecated(note = "use `FindModifiers::iter_escape_codes()` or `Self::with_spans()` instead")]
+    pub fn next_match(&mut self) -> Option<Match<'t>> {
+        let code = self.0.next()?;
+        ESCAPE_CODE_PATTERN.find_at(code.text, code.span.start)
     }
 }
-impl<'r, 't> Iterator for ModifiersInText<'r, 't> {
+impl Iterator for ModifiersInText<'_> {
     type Item = Modifier;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if let Some(text) = self.0.next().map(|m| m.as_str()) {
-                let result = ANSIEscapeCode::try_from(text);
-
-                if let Ok(ansi) = result {
-                    if let Ok(modifier) = Modifier::try_from(ansi) {
-                        return Some(modifier);
-                    }
-                }
-                // If its not valid, continue searching.
-            } else {
-                return None;
-            }
-        }
+        // If its not valid, continue searching.
+        self.0
+            .by_ref()
+            .find_map(|code| Modifier::try_from(&code).ok())
     }
 }
 
@@ -44,21 +51,30 @@ impl<'r, 't> Iterator for ModifiersInText<'r, 't> {
 /// `len` is just wrapper around its respective `len` functions, to provide a guarantee
 /// that anything that `impl FindModifiers` will have a `len` function.
 pub trait FindModifiers {
-    fn iter_modifiers(&self) -> ModifiersInText;
+    fn iter_modifiers(&self) -> ModifiersInText<'_>;
+    fn iter_escape_codes(&self) -> ANSIEscapeCodes<'_>;
     fn len(&self) -> usize;
 }
 impl FindModifiers for &str {
-    fn iter_modifiers(&self) -> ModifiersInText {
+    fn iter_modifiers(&self) -> ModifiersInText<'_> {
         ModifiersInText::new(self)
     }
 
+    fn iter_escape_codes(&self) -> ANSIEscapeCodes<'_> {
+        ANSIEscapeCodes::new(self)
+    }
+
     fn len(&self) -> usize {
         return str::len(self);
     }
 }
 impl FindModifiers for String {
-    fn iter_modifiers(&self) -> ModifiersInText {
-        ModifiersInText::new(&self)
+    fn iter_modifiers(&self) -> ModifiersInText<'_> {
+        ModifiersInText::new(self)
+    }
+
+    fn iter_escape_codes(&self) -> ANSIEscapeCodes<'_> {
+        ANSIEscapeCodes::new(self)
     }
 
     fn len(&self) -> usize {
@@ -76,7 +92,8 @@ where
     fn len_without_modifiers(&self) -> usize {
         let modifier_count = {
             self.iter_modifiers()
-                .fold(0_usize, |count, matched| count + matched.len())
+                .with_spans()
+                .fold(0_usize, |count, (_, span)| count + span.len())
         };
 
         return self.len() - modifier_count;
@@ -92,19 +109,10 @@ impl<'t> RangeWithoutModifiers<'t> {
     pub fn new(text: &'t str) -> Self {
         Self {
             text,
-            modifier_ranges: {
-                let mut v = vec![];
-                let mut iter = text.iter_modifiers();
-
-                loop {
-                    match iter.next_match() {
-                        Some(m) => v.push((m.start(), m.end())),
-                        None => break,
-                    }
-                }
-
-                v
-            },
+            modifier_ranges: text
+                .iter_escape_codes()
+                .map(|code| (code.span.start, code.span.end))
+                .collect(),
         }
     }
 
@@ -99,4 +99,47 @@ mod test_len {
         "Hello, \x1b[38:5:69mWorld\x1b[48:542m!\x1b[49m\x1b[39m",
         22
     );
+
+    test_factory!(leading_zeros, "\x1b[01mBold\x1b[022m", 4);
+}
+
+mod test_spans {
+    use super::*;
+
+    #[test]
+    fn modifiers_with_spans() {
+        let found: Vec<_> = "Hello, \x1b[38:5:69mWorld\x1b[48:542m!\x1b[39m"
+            .iter_modifiers()
+            .with_spans()
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                (Modifier::Colour(Colour::R1G2B5), 7..17),
+                (Modifier::Colour(Colour::Reset), 32..37),
+            ]
+        );
+    }
+
+    #[test]
+    fn range_without_modifiers() {
+        let text = "Hello, \x1b[38:5:69mWorld\x1b[39m!";
+        let ranges = RangeWithoutModifiers::new(text);
+
+        assert_eq!(ranges.range_without_modifiers(17..22), 7..12);
+        assert_eq!(ranges.index_without_modifiers(text.len()), 13);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn next_match() {
+        let text = "Hello, \x1b[38:5:69mWorld\x1b[2A!\x1b[39m";
+        let mut iter = text.iter_modifiers();
+
+        assert_eq!(iter.next_match().map(|m| m.as_str()), Some("\x1b[38:5:69m"));
+        assert_eq!(iter.next_match().map(|m| m.range()), Some(22..26));
+        assert_eq!(iter.next_match().map(|m| m.as_str()), Some("\x1b[39m"));
+        assert_eq!(iter.next_match(), None);
+    }
 }
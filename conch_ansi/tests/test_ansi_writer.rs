@@ -0,0 +1,210 @@
+//! Test [`ANSIWriter`] and [`SyncLineWriter`].
+use std::io::Write;
+
+use conch_ansi::*;
+use conch_base_models::*;
+
+mod test_ansi_writer {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $mode:expr,
+            $chunks:expr,
+            $expected:literal
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let mut writer = ANSIWriter::new(Vec::new(), $mode);
+
+                for chunk in $chunks {
+                    writer.write_all(chunk.as_bytes()).unwrap();
+                }
+
+                assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        pass_through,
+        ANSIWriterMode::PassThrough,
+        ["\x1b[38;5;9m\x1b[1mHello\x1b[22m\x1b[39m"],
+        "\x1b[38;5;9m\x1b[1mHello\x1b[22m\x1b[39m",
+    );
+
+    test_factory!(
+        strip,
+        ANSIWriterMode::Strip,
+        ["\x1b[38;5;9m\x1b[1mHello\x1b[22m\x1b[39m, World!"],
+        "Hello, World!",
+    );
+
+    test_factory!(
+        strip_non_sgr_and_hyperlinks,
+        ANSIWriterMode::Strip,
+        ["\x1b[2A\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07\x1b7!"],
+        "link!",
+    );
+
+    test_factory!(
+        strip_split_across_writes,
+        ANSIWriterMode::Strip,
+        ["Hello\x1b", "[38", ";5;", "9m, World", "!\x1b[3", "9m"],
+        "Hello, World!",
+    );
+
+    test_factory!(
+        strip_one_byte_at_a_time,
+        ANSIWriterMode::Strip,
+        "\x1b[38;5;9mHello\x1b[39m"
+            .chars()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>(),
+        "Hello",
+    );
+
+    test_factory!(
+        downgrade_256_to_16,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours16),
+        ["\x1b[38;5;9mRed\x1b[39m \x1b[48;5;21mBlue\x1b[49m"],
+        "\x1b[91mRed\x1b[39m \x1b[44mBlue\x1b[49m",
+    );
+
+    test_factory!(
+        downgrade_grayscale_to_16,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours16),
+        ["\x1b[38;5;244mGray\x1b[39m"],
+        "\x1b[90mGray\x1b[39m",
+    );
+
+    test_factory!(
+        downgrade_16_to_8,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours8),
+        ["\x1b[38;5;9mRed\x1b[39m \x1b[92mGreen\x1b[39m"],
+        "\x1b[31mRed\x1b[39m \x1b[32mGreen\x1b[39m",
+    );
+
+    test_factory!(
+        downgrade_true_colour_to_256,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours256),
+        ["\x1b[38;2;255;0;0mRed\x1b[39m"],
+        "\x1b[38;5;196mRed\x1b[39m",
+    );
+
+    test_factory!(
+        downgrade_combined_parameters,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours16),
+        ["\x1b[1;38;5;10;48;5;0mText\x1b[0m"],
+        "\x1b[1;92;40mText\x1b[0m",
+    );
+
+    test_factory!(
+        downgrade_leaves_out_of_range_values,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours16),
+        ["\x1b[38;5;300mText\x1b[39m \x1b[1;48;2;0;256;0mText\x1b[0m"],
+        "\x1b[38;5;300mText\x1b[39m \x1b[1;48;2;0;256;0mText\x1b[0m",
+    );
+
+    test_factory!(
+        downgrade_colon_form,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours256),
+        ["\x1b[38:2::255:0:0mRed\x1b[39m \x1b[1;48:5:21mBlue\x1b[0m"],
+        "\x1b[38;5;196mRed\x1b[39m \x1b[1;48:5:21mBlue\x1b[0m",
+    );
+
+    test_factory!(
+        downgrade_colon_form_to_16,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours16),
+        ["\x1b[38:2:255:0:0;4mRed\x1b[0m"],
+        "\x1b[91;4mRed\x1b[0m",
+    );
+
+    test_factory!(
+        downgrade_drops_incomplete_colours,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours16),
+        ["\x1b[1;38;5mText\x1b[0m \x1b[48;2;0;0mText\x1b[0m \x1b[38:5mText\x1b[0m"],
+        "\x1b[1mText\x1b[0m Text\x1b[0m Text\x1b[0m",
+    );
+
+    test_factory!(
+        downgrade_monochrome,
+        ANSIWriterMode::Downgrade(ColourDepth::Monochrome),
+        ["\x1b[38;5;9m\x1b[1mBold\x1b[22m\x1b[39m \x1b[1;38;5;10mText\x1b[0m"],
+        "\x1b[1mBold\x1b[22m \x1b[1mText\x1b[0m",
+    );
+
+    test_factory!(
+        downgrade_leaves_cursor_movements,
+        ANSIWriterMode::Downgrade(ColourDepth::Colours8),
+        ["\x1b[2A\x1b[38;5;9", "mText\x1b[39m"],
+        "\x1b[2A\x1b[31mText\x1b[39m",
+    );
+}
+
+mod test_sync_line_writer {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn holds_back_incomplete_lines() {
+        let output = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let mut writer = SyncLineWriter::from_shared(Arc::clone(&output));
+
+        writer.write_all(b"Hello,\nWor").unwrap();
+        assert_eq!(output.lock().unwrap().as_slice(), b"Hello,\n");
+
+        writer.write_all(b"ld!\n").unwrap();
+        assert_eq!(output.lock().unwrap().as_slice(), b"Hello,\nWorld!\n");
+    }
+
+    #[test]
+    fn flush_holds_back_incomplete_escape_codes() {
+        let output = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let mut writer = SyncLineWriter::from_shared(Arc::clone(&output));
+
+        writer.write_all(b"Hello\x1b[38;5").unwrap();
+        writer.flush().unwrap();
+        assert_eq!(output.lock().unwrap().as_slice(), b"Hello");
+
+        writer.write_all(b";9m!").unwrap();
+        writer.flush().unwrap();
+        assert_eq!(output.lock().unwrap().as_slice(), b"Hello\x1b[38;5;9m!");
+    }
+
+    #[test]
+    fn drop_writes_remainder() {
+        let output = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let mut writer = SyncLineWriter::from_shared(Arc::clone(&output));
+
+        writer.write_all(b"No newline").unwrap();
+        drop(writer);
+
+        assert_eq!(output.lock().unwrap().as_slice(), b"No newline");
+    }
+
+    #[test]
+    fn combined_with_ansi_writer() {
+        let output = Arc::new(Mutex::new(ANSIWriter::new(
+            Vec::<u8>::new(),
+            ANSIWriterMode::Strip,
+        )));
+        let mut writer = SyncLineWriter::from_shared(Arc::clone(&output));
+
+        writeln!(
+            writer,
+            "{}",
+            Modifier::colour("BrightRed")
+                .unwrap()
+                .wraps("Hello, World!")
+        )
+        .unwrap();
+
+        assert_eq!(
+            output.lock().unwrap().get_ref().as_slice(),
+            b"Hello, World!\n"
+        );
+    }
+}
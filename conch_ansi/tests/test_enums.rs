@@ -23,10 +23,7 @@ mod test_try_from {
                     if $expected.is_ok() {
                         panic!("{}", parsed.unwrap_err())
                     } else {
-                        assert_eq!(
-                            $expected.unwrap_err().to_string(),
-                            parsed.unwrap_err().to_string(),
-                        )
+                        assert_eq!($expected.unwrap_err(), parsed.unwrap_err())
                     }
                 }
             }
@@ -46,54 +43,66 @@ mod test_try_from {
     test_factory!(
         mismatched_apply_command,
         "\x1b[40:5:125m",
-        Err::<Colour, _>(ModifierError::MismatchedANSICode(
-            String::from("Colour"),
-            40,
-            38,
-        ))
+        Err::<Colour, _>(
+            ModifierError::new(ModifierErrorKind::MismatchedCode, "Colour")
+                .with_expected("`38`")
+                .with_found(40)
+                .with_part(EscapeCodePart::Param(0))
+                .with_span(2..4)
+        )
     );
     test_factory!(
         mismatched_reset_command,
         "\x1b[40m",
-        Err::<Colour, _>(ModifierError::MismatchedANSICode(
-            String::from("Colour"),
-            40,
-            39,
-        ))
+        Err::<Colour, _>(
+            ModifierError::new(ModifierErrorKind::MismatchedCode, "Colour")
+                .with_expected("`39`")
+                .with_found(40)
+                .with_part(EscapeCodePart::Param(0))
+                .with_span(2..4)
+        )
     );
     test_factory!(
         simple_apply_with_nondigit,
         "\x1b[38:a:125m",
-        Err::<Colour, _>(ModifierError::ValueIsNotAModifier(
-            String::from("\x1b[38:a:125m"),
-            String::from("Unmatchable pattern."),
-        ))
+        Err::<Colour, _>(
+            ModifierError::new(ModifierErrorKind::Unmatchable, "ANSIEscapeCode")
+                .with_expected("a digit")
+                .with_found("\x1b[38:a")
+                .with_span(5..6)
+        )
     );
     test_factory!(
         simple_apply_with_wrong_end_digit,
         "\x1b[38:5:125n",
-        Err::<Colour, _>(ModifierError::UnexpectedEndCharacter(
-            String::from("Colour"),
-            String::from("n"),
-        ))
+        Err::<Colour, _>(
+            ModifierError::new(ModifierErrorKind::UnexpectedEndChar, "Colour")
+                .with_expected("`m`")
+                .with_found('n')
+                .with_part(EscapeCodePart::EndChar)
+                .with_span(10..11)
+        )
     );
     test_factory!(
         reset_with_extra_codes,
         "\x1b[39:5:125m",
-        Err::<Colour, _>(ModifierError::ValueNotRecognised(
-            String::from("Colour"),
-            format!("{:?}:{:?}", Some(39), vec![5, 125]),
-            String::from("Wrong combination of codes.")
-        ))
+        Err::<Colour, _>(
+            ModifierError::new(ModifierErrorKind::WrongArgumentCount, "Colour")
+                .with_expected("`39` alone, or `38;5` with a colour")
+                .with_found("`39` with 2 argument(s)")
+                .with_span(0..11)
+        )
     );
     test_factory!(
         apply_with_wrong_code,
         "\x1b[38:6:125m",
-        Err::<Colour, _>(ModifierError::ValueNotRecognised(
-            String::from("Colour"),
-            format!("{:?}", vec![6, 125]),
-            String::from("Non 5 codes are not allowed.")
-        ))
+        Err::<Colour, _>(
+            ModifierError::new(ModifierErrorKind::UnrecognisedCodes, "Colour")
+                .with_expected("`5` for 256 colours")
+                .with_found(6)
+                .with_part(EscapeCodePart::Param(1))
+                .with_span(5..6)
+        )
     );
     test_factory!(
         simple_word_with_trailing_text,
@@ -103,9 +112,50 @@ mod test_try_from {
     test_factory!(
         simple_word_with_leading_text,
         "This is some extra text added\x1b[38:5:125m",
-        Err::<Colour, _>(ModifierError::ValueIsNotAModifier(
-            String::from("This is some extra text added\u{1b}[38:5:125m"),
-            String::from("Unmatchable pattern."),
-        ))
+        Err::<Colour, _>(
+            ModifierError::new(ModifierErrorKind::Unmatchable, "ANSIEscapeCode")
+                .with_expected("`\\x1b[`")
+                .with_found("T")
+                .with_span(0..1)
+        )
     );
 }
+
+mod test_errors {
+    use super::*;
+
+    #[test]
+    fn intensity_target() {
+        let error = Intensity::try_from("\x1b[1;5m").unwrap_err();
+
+        assert_eq!(error.target, "Intensity");
+        assert_eq!(error.kind, ModifierErrorKind::WrongArgumentCount);
+        assert_eq!(error.span, Some(4..5));
+    }
+
+    #[test]
+    fn move_cursor_target() {
+        let error = MoveCursor::try_from("\x1b[1;2A").unwrap_err();
+
+        assert_eq!(error.target, "MoveCursor");
+        assert_eq!(error.kind, ModifierErrorKind::WrongArgumentCount);
+        assert_eq!(error.span, Some(0..6));
+    }
+
+    #[test]
+    fn render_modifier_error() {
+        let source = "\x1b[38;5;300m";
+        let error = Modifier::try_from(source).unwrap_err();
+
+        assert_eq!(
+            error.render(source),
+            concat!(
+                "error: Colour: no matching variant; expected a colour between 0 and 255, ",
+                "found `300`\n",
+                "  |\n",
+                "1 | \\u{1b}[38;5;300m\n",
+                "  |             ^^^\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Test serialization of [`Modifier`] and its component enums.
+#![cfg(feature = "serde")]
+
+use conch_ansi::*;
+use conch_base_models::*;
+
+macro_rules! test_factory {
+    (
+        $name:ident,
+        $type:ty,
+        $value:expr,
+        $json:literal
+        $(,)?
+    ) => {
+        #[test]
+        fn $name() {
+            let value: $type = $value;
+            let json = serde_json::to_string(&value).unwrap();
+
+            assert_eq!(json, $json);
+            assert_eq!(serde_json::from_str::<$type>(&json).unwrap(), value);
+        }
+    };
+}
+
+test_factory!(colour, Colour, Colour::BrightRed, r#""BrightRed""#);
+
+test_factory!(background, Background, Background::R1G2B5, r#""R1G2B5""#);
+
+test_factory!(intensity, Intensity, Intensity::Bold, r#""Bold""#);
+
+test_factory!(move_cursor, MoveCursor, MoveCursor::Up(3), r#"{"Up":3}"#);
+
+test_factory!(
+    modifier,
+    Modifier,
+    Modifier::colour("BrightRed").unwrap(),
+    r#""fg:BrightRed""#,
+);
+
+test_factory!(
+    modifier_combo,
+    Vec<Modifier>,
+    vec![
+        Modifier::intensity("Bold").unwrap() + Modifier::background("Blue").unwrap(),
+        Modifier::Nothing,
+    ],
+    r#"["Bold bg:Blue","none"]"#,
+);
+
+test_factory!(
+    ansi_escape_code,
+    ANSIEscapeCode,
+    ANSIEscapeCode::with_modifiers(Some(38), &[5, 9], 'm'),
+    r#"{"code":38,"modifiers":[5,9],"sep":";","end_char":"m"}"#,
+);
+
+#[test]
+fn modifier_error() {
+    let error = serde_json::from_str::<Modifier>(r#""Bold fg:Bleu""#).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Modifier: unrecognised style; expected a colour name such as `BrightRed`, found `Bleu`"
+    );
+}
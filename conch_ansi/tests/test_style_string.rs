@@ -0,0 +1,187 @@
+//! Test parsing and formatting style strings of [`Modifier`].
+use conch_ansi::*;
+use conch_base_models::*;
+
+mod test_from_str {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $text:literal,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let parsed: Result<Modifier, ModifierError> = $text.parse();
+
+                assert_eq!(parsed, $expected);
+            }
+        };
+    }
+
+    test_factory!(empty, "", Ok(Modifier::Nothing));
+
+    test_factory!(none, "none", Ok(Modifier::Nothing));
+
+    test_factory!(intensity, "Bold", Ok(Modifier::Intensity(Intensity::Bold)));
+
+    test_factory!(
+        decoration,
+        "Italic",
+        Ok(Modifier::Decoration(Decoration::Italic))
+    );
+
+    test_factory!(
+        colour,
+        "fg:BrightRed",
+        Ok(Modifier::Colour(Colour::BrightRed))
+    );
+
+    test_factory!(
+        background,
+        "bg:Grayscale04",
+        Ok(Modifier::Background(Background::Grayscale04))
+    );
+
+    test_factory!(move_up, "up:3", Ok(Modifier::up(3)));
+
+    test_factory!(move_left_negative, "left:-2", Ok(Modifier::left(-2)));
+
+    test_factory!(absolute, "at:10,20", Ok(Modifier::absolute(10, 20)));
+
+    test_factory!(origin, "origin", Ok(Modifier::origin()));
+
+    test_factory!(
+        combo,
+        "  Bold\tfg:BrightRed   bg:Blue none up:3 ",
+        Ok(Modifier::Combo(vec![
+            Modifier::Intensity(Intensity::Bold),
+            Modifier::Colour(Colour::BrightRed),
+            Modifier::Background(Background::Blue),
+            Modifier::up(3),
+        ]))
+    );
+
+    test_factory!(
+        unknown_colour,
+        "Bold fg:Bleu",
+        Err(
+            ModifierError::new(ModifierErrorKind::UnrecognisedStyle, "Modifier")
+                .with_expected("a colour name such as `BrightRed`")
+                .with_found("Bleu")
+                .with_span(8..12)
+        )
+    );
+
+    test_factory!(
+        unknown_prefix,
+        "Bold colour:Blue",
+        Err(
+            ModifierError::new(ModifierErrorKind::UnrecognisedStyle, "Modifier")
+                .with_expected("one of `fg`, `bg`, `up`, `down`, `left`, `right` or `at`")
+                .with_found("colour")
+                .with_span(5..11)
+        )
+    );
+
+    test_factory!(
+        unknown_word,
+        "bold",
+        Err(
+            ModifierError::new(ModifierErrorKind::UnrecognisedStyle, "Modifier")
+                .with_expected(
+                    "an intensity such as `Bold`, a decoration such as `Italic`, `origin`, `none`, \
+                    or a prefixed style such as `fg:BrightRed`"
+                )
+                .with_found("bold")
+                .with_span(0..4)
+        )
+    );
+
+    test_factory!(
+        invalid_integer,
+        "up:three",
+        Err(
+            ModifierError::new(ModifierErrorKind::InvalidArgument, "Modifier")
+                .with_expected("an integer")
+                .with_found("three")
+                .with_span(3..8)
+        )
+    );
+
+    test_factory!(
+        invalid_position,
+        "at:10,y",
+        Err(
+            ModifierError::new(ModifierErrorKind::InvalidArgument, "Modifier")
+                .with_expected("an integer")
+                .with_found("y")
+                .with_span(6..7)
+        )
+    );
+}
+
+mod test_to_style_string {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $modifier:expr,
+            $expected:literal
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let modifier: Modifier = $modifier;
+                let style = modifier.to_style_string();
+
+                assert_eq!(style, $expected);
+                assert_eq!(style.parse::<Modifier>().unwrap(), modifier);
+            }
+        };
+    }
+
+    test_factory!(nothing, Modifier::Nothing, "none");
+
+    test_factory!(intensity, Modifier::intensity("Faint").unwrap(), "Faint");
+
+    test_factory!(
+        decoration,
+        Modifier::decoration("Underline").unwrap() + Modifier::intensity("Bold").unwrap(),
+        "Underline Bold",
+    );
+
+    test_factory!(
+        combo,
+        Modifier::intensity("Bold").unwrap()
+            + Modifier::colour("BrightRed").unwrap()
+            + Modifier::background("R1G2B5").unwrap(),
+        "Bold fg:BrightRed bg:R1G2B5",
+    );
+
+    test_factory!(
+        cursor,
+        Modifier::absolute(3, 4) + Modifier::down(-1),
+        "at:3,4 down:-1",
+    );
+}
+
+#[test]
+fn render_error() {
+    let source = "Bold fg:Bleu";
+    let error = source.parse::<Modifier>().unwrap_err();
+
+    assert_eq!(
+        error.render(source),
+        concat!(
+            "error: Modifier: unrecognised style; expected a colour name such as `BrightRed`, ",
+            "found `Bleu`\n",
+            "  |\n",
+            "1 | Bold fg:Bleu\n",
+            "  |         ^^^^\n",
+        )
+    );
+}
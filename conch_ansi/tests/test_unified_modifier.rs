@@ -43,6 +43,13 @@ mod test_from_name {
         Some(Modifier::Intensity(Intensity::Bold))
     );
 
+    test_factory!(
+        simple_decoration,
+        decoration,
+        "Strikethrough",
+        Some(Modifier::Decoration(Decoration::Strikethrough))
+    );
+
     test_factory!(non_existent_colour, colour, "BadChoice", None);
 
     test_factory!(non_existent_background, background, "BadChoice", None);
@@ -108,6 +115,18 @@ mod test_parsing {
         "\x1b[22m",
         Ok::<_, ModifierError>(Modifier::Intensity(Intensity::Normal))
     );
+
+    test_factory!(
+        simple_decoration_3,
+        "\x1b[3m",
+        Ok::<_, ModifierError>(Modifier::Decoration(Decoration::Italic))
+    );
+
+    test_factory!(
+        simple_decoration_24,
+        "\x1b[24m",
+        Ok::<_, ModifierError>(Modifier::Decoration(Decoration::NotUnderline))
+    );
 }
 
 mod test_add {
@@ -187,3 +206,97 @@ mod test_add {
         ]
     );
 }
+
+mod test_write_wrapped {
+    use super::*;
+    use std::fmt::Write;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $modifier:expr,
+            $text:literal,
+            $expected:literal
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let modifier: Modifier = $modifier;
+
+                let mut written = String::new();
+                modifier.write_wrapped(&mut written, $text).unwrap();
+
+                let mut io_written: Vec<u8> = Vec::new();
+                modifier.write_wrapped_io(&mut io_written, $text).unwrap();
+
+                assert_eq!(written, $expected);
+                assert_eq!(String::from_utf8(io_written).unwrap(), $expected);
+                assert_eq!(modifier.paint($text).to_string(), $expected);
+                assert_eq!(modifier.wraps($text), $expected);
+                assert_eq!(modifier.len(), modifier.to_string().len());
+            }
+        };
+    }
+
+    test_factory!(nothing, Modifier::Nothing, "Hello, World!", "Hello, World!",);
+
+    test_factory!(
+        colour,
+        Modifier::Colour(Colour::BrightRed),
+        "Hello, World!",
+        "\x1b[38;5;9mHello, World!\x1b[39m",
+    );
+
+    test_factory!(
+        combo,
+        Modifier::Colour(Colour::BrightRed)
+            + Modifier::Background(Background::Grayscale04)
+            + Modifier::Intensity(Intensity::Bold),
+        "Hello, World!",
+        "\x1b[38;5;9m\x1b[48;5;236m\x1b[1mHello, World!\x1b[22m\x1b[49m\x1b[39m",
+    );
+
+    test_factory!(
+        decoration,
+        Modifier::Decoration(Decoration::Italic) + Modifier::Intensity(Intensity::Bold),
+        "Hello, World!",
+        "\x1b[3m\x1b[1mHello, World!\x1b[22m\x1b[23m",
+    );
+
+    test_factory!(
+        nested_combo,
+        {
+            let mut modifier = Modifier::Colour(Colour::BrightRed);
+            modifier += Modifier::Intensity(Intensity::Bold) + Modifier::up(2);
+            modifier
+        },
+        "Hello",
+        "\x1b[38;5;9m\x1b[1m\x1b[2AHello\x1b[2B\x1b[22m\x1b[39m",
+    );
+
+    test_factory!(
+        move_cursor_right,
+        Modifier::right(3),
+        "Hello",
+        "\x1b[3CHello\x1b[8D",
+    );
+
+    #[test]
+    fn paint_within_format() {
+        let modifier = Modifier::Intensity(Intensity::Bold);
+        let mut output = String::new();
+
+        write!(output, "[{}]", modifier.paint("OK")).unwrap();
+
+        assert_eq!(output, "[\x1b[1mOK\x1b[22m]");
+    }
+
+    #[test]
+    fn wraps_non_empty() {
+        let modifier = Modifier::Intensity(Intensity::Bold);
+
+        assert_eq!(modifier.wraps_non_empty("OK"), "\x1b[1mOK\x1b[22m");
+        assert_eq!(modifier.wraps_non_empty(""), "");
+        assert_eq!(modifier.wraps(""), "\x1b[1m\x1b[22m");
+    }
+}
@@ -1 +1,219 @@
 //! Low level functions for `conch`.
+
+use std::ops::Range;
+
+use unicode_width::UnicodeWidthChar;
+
+/// Escape character that begins every ANSI escape sequence.
+pub const ESCAPE_CHAR: u8 = 0x1b;
+
+/// The result of [`scan_escape_sequence()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscapeSequenceScan {
+    /// The bytes do not begin with `\x1b`.
+    NotEscape,
+
+    /// The bytes begin with a complete escape sequence of this many bytes.
+    Complete(usize),
+
+    /// The bytes begin with an escape sequence that is not terminated yet; more
+    /// bytes are required to tell where it ends.
+    Incomplete,
+}
+
+/// Measure the escape sequence at the start of `bytes`, if any.
+///
+/// Unlike [`crate::ESCAPE_CODE_PATTERN`], this recognises every kind of escape
+/// sequence a terminal would swallow, and not just the ones that can be parsed into
+/// an [`crate::ANSIEscapeCode`]:
+///
+/// - Control Sequences such as `\x1b[38;5;9m` or `\x1b[2K`,
+/// - Operating System Commands such as hyperlinks `\x1b]8;;https://...\x1b\\`,
+///   terminated by either `BEL` or `ESC \`,
+/// - any other two-byte sequences such as `\x1b7`.
+///
+/// A Control Sequence containing a byte that is not allowed within it is considered
+/// to end just before that byte.
+///
+/// ```rust
+/// use conch_base_models::{scan_escape_sequence, EscapeSequenceScan};
+///
+/// assert_eq!(
+///     scan_escape_sequence(b"\x1b[38;5;9mHello"),
+///     EscapeSequenceScan::Complete(9),
+/// );
+/// assert_eq!(
+///     scan_escape_sequence(b"\x1b[38;5"),
+///     EscapeSequenceScan::Incomplete,
+/// );
+/// assert_eq!(
+///     scan_escape_sequence(b"Hello"),
+///     EscapeSequenceScan::NotEscape,
+/// );
+/// ```
+pub fn scan_escape_sequence(bytes: &[u8]) -> EscapeSequenceScan {
+    match bytes {
+        [ESCAPE_CHAR, b'[', rest @ ..] => {
+            for (idx, byte) in rest.iter().enumerate() {
+                match byte {
+                    // Parameter and intermediate bytes.
+                    0x20..=0x3f => continue,
+                    // Final byte.
+                    0x40..=0x7e => return EscapeSequenceScan::Complete(idx + 3),
+                    // Anything else aborts the sequence.
+                    _ => return EscapeSequenceScan::Complete(idx + 2),
+                }
+            }
+            EscapeSequenceScan::Incomplete
+        }
+        [ESCAPE_CHAR, b']', rest @ ..] => {
+            for (idx, byte) in rest.iter().enumerate() {
+                match (byte, rest.get(idx + 1)) {
+                    (0x07, _) => return EscapeSequenceScan::Complete(idx + 3),
+                    (&ESCAPE_CHAR, Some(b'\\')) => return EscapeSequenceScan::Complete(idx + 4),
+                    _ => continue,
+                }
+            }
+            EscapeSequenceScan::Incomplete
+        }
+        [ESCAPE_CHAR, _, ..] => EscapeSequenceScan::Complete(2),
+        [ESCAPE_CHAR] => EscapeSequenceScan::Incomplete,
+        _ => EscapeSequenceScan::NotEscape,
+    }
+}
+
+/// Measure the number of terminal columns `text` occupies when printed.
+///
+/// Escape sequences, as recognised by [`scan_escape_sequence()`], take up no space;
+/// wide characters such as CJK ideographs take up two columns, and other control
+/// characters none.
+///
+/// ```rust
+/// use conch_base_models::visible_width;
+///
+/// assert_eq!(visible_width("\x1b[1mHello\x1b[22m"), 5);
+/// assert_eq!(visible_width("\u{4f60}\u{597d}"), 4);
+/// ```
+pub fn visible_width(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut width = 0;
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        match scan_escape_sequence(&bytes[idx..]) {
+            EscapeSequenceScan::Complete(len) => {
+                idx += len;
+                // A two-byte sequence may swallow the first byte of a multibyte char.
+                while !text.is_char_boundary(idx) {
+                    idx += 1;
+                }
+            }
+            EscapeSequenceScan::Incomplete => break,
+            EscapeSequenceScan::NotEscape => {
+                let c = text[idx..].chars().next().expect("`idx` is within `text`.");
+                width += UnicodeWidthChar::width(c).unwrap_or(0);
+                idx += c.len_utf8();
+            }
+        }
+    }
+
+    width
+}
+
+/// Remove all escape sequences from `text`, as recognised by
+/// [`scan_escape_sequence()`], leaving only what would be visible when printed.
+///
+/// An incomplete escape sequence at the end of `text` is removed as well.
+///
+/// ```rust
+/// use conch_base_models::strip_escape_sequences;
+///
+/// assert_eq!(strip_escape_sequences("\x1b[1mHello\x1b[22m, world!"), "Hello, world!");
+/// ```
+pub fn strip_escape_sequences(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut output = String::with_capacity(text.len());
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        match scan_escape_sequence(&bytes[idx..]) {
+            EscapeSequenceScan::Complete(len) => {
+                idx += len;
+                while !text.is_char_boundary(idx) {
+                    idx += 1;
+                }
+            }
+            EscapeSequenceScan::Incomplete => break,
+            EscapeSequenceScan::NotEscape => {
+                let c = text[idx..].chars().next().expect("`idx` is within `text`.");
+                output.push(c);
+                idx += c.len_utf8();
+            }
+        }
+    }
+
+    output
+}
+
+/// Append `c` to `output`, escaping it if it is a control character that would
+/// otherwise do something to the terminal; return the number of columns appended.
+fn push_escaped(output: &mut String, c: char) -> usize {
+    if c.is_control() {
+        let escaped = c.escape_debug().to_string();
+        output.push_str(&escaped);
+        escaped.chars().count()
+    } else {
+        output.push(c);
+        1
+    }
+}
+
+/// Render `message` followed by the line of `source` containing `span`, with the
+/// bytes within `span` underlined by `^`.
+///
+/// Control characters within the line are escaped, so that escape codes are shown
+/// instead of being interpreted by the terminal; the underline accounts for this.
+/// If `span` extends beyond the end of its line, only the first line is underlined.
+///
+/// ```rust
+/// use conch_base_models::render_span;
+///
+/// assert_eq!(
+///     render_span("fg:Red\nbg:Bleu", 10..14, "error: unknown colour"),
+///     concat!(
+///         "error: unknown colour\n",
+///         "  |\n",
+///         "2 | bg:Bleu\n",
+///         "  |    ^^^^\n",
+///     ),
+/// );
+/// ```
+pub fn render_span(source: &str, span: Range<usize>, message: &str) -> String {
+    let start = span.start.min(source.len());
+    let line_start = source[..start].rfind('\n').map_or(0, |idx| idx + 1);
+    let line_end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |idx| start + idx);
+    let line_number = source[..start].matches('\n').count() + 1;
+    let end = span.end.clamp(start, line_end);
+
+    let mut line = String::with_capacity(line_end - line_start);
+    let (mut padding, mut underline) = (0, 0);
+    for (idx, c) in source[line_start..line_end].char_indices() {
+        let width = push_escaped(&mut line, c);
+
+        match line_start + idx {
+            pos if pos < start => padding += width,
+            pos if pos < end => underline += width,
+            _ => {}
+        }
+    }
+
+    let gutter = " ".repeat(line_number.to_string().len());
+
+    format!(
+        "{message}\n{gutter} |\n{line_number} | {line}\n{gutter} | {}{}\n",
+        " ".repeat(padding),
+        "^".repeat(underline.max(1)),
+    )
+}
@@ -1,12 +1,21 @@
 /// Parsing of [`str`] into a dataclass, [`ANSIEscapeCode`].
 ///
-use std::fmt;
+use std::fmt::{self, Write};
 
 use regex::Captures;
+use smallvec::SmallVec;
 
-pub use crate::{HasLength, IntoANSIEscapeCode, ModifierError};
+pub use crate::{
+    ANSIEscapeCodeRef, HasLength, IntoANSIEscapeCode, ModifierError, ModifierErrorKind,
+};
 
-pub use super::{DEFAULT_SEPARATOR, ESCAPE_CODE_PATTERN, ESCAPE_CODE_START_PATTERN, SEP_PATTERN};
+pub use super::{DEFAULT_SEPARATOR, ESCAPE_CODE_START_PATTERN};
+
+/// Inline storage for the modifier codes of an [`ANSIEscapeCode`].
+///
+/// Most escape codes carry no more than a couple of modifiers, such as the `5;255`
+/// in `\x1b[38;5;255m`; these are kept on the stack without any allocation.
+pub type ModifierCodes = SmallVec<[i32; 4]>;
 
 /// A basic dataclass of a deconstructed `\x1b[00;00;..m` structure.
 ///
@@ -18,18 +27,12 @@ pub use super::{DEFAULT_SEPARATOR, ESCAPE_CODE_PATTERN, ESCAPE_CODE_START_PATTER
 /// -------
 ///
 /// ```rust
-/// use conch_base_models::{ANSIEscapeCode, DEFAULT_SEPARATOR};
+/// use conch_base_models::{ANSIEscapeCode, ModifierCodes};
 ///
 /// let parsed: ANSIEscapeCode = "\x1b[30m".try_into().unwrap();
 /// assert_eq!(
 ///     parsed,
-///     ANSIEscapeCode {
-///         code: Some(30),
-///         modifiers: Vec::new(),
-///         sep: DEFAULT_SEPARATOR,
-///         end_char: 'm',
-///         source_str: None, // This is not used in comparison
-///     }
+///     ANSIEscapeCode::with_modifiers(Some(30), &[], 'm')
 /// );
 ///
 /// let parsed: ANSIEscapeCode = "\x1b[20;8H".try_into().unwrap();
@@ -37,23 +40,15 @@ pub use super::{DEFAULT_SEPARATOR, ESCAPE_CODE_PATTERN, ESCAPE_CODE_START_PATTER
 ///     parsed,
 ///     ANSIEscapeCode {
 ///         code: None,
-///         modifiers: vec![20,8],
-///         sep: DEFAULT_SEPARATOR,
-///         end_char: 'H',
-///         source_str: None, // This is not used in comparison
+///         modifiers: ModifierCodes::from_slice(&[20, 8]),
+///         ..ANSIEscapeCode::with_modifiers(None, &[], 'H')
 ///     }
 /// );
 ///
 /// let parsed: ANSIEscapeCode = "\x1b[38:5:255m".try_into().unwrap();
 /// assert_eq!(
 ///     parsed,
-///     ANSIEscapeCode {
-///         code: Some(38),
-///         modifiers: vec![5,255],
-///         sep: DEFAULT_SEPARATOR,
-///         end_char: 'm',
-///         source_str: None, // This is not used in comparison
-///     }
+///     ANSIEscapeCode::with_modifiers(Some(38), &[5, 255], 'm')
 /// );
 ///
 /// // Code is less than 0
@@ -74,7 +69,8 @@ pub use super::{DEFAULT_SEPARATOR, ESCAPE_CODE_PATTERN, ESCAPE_CODE_START_PATTER
 ///     parsed.is_err(),
 /// );
 /// ```
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ANSIEscapeCode {
     /// Command Code.
     ///
@@ -85,11 +81,11 @@ pub struct ANSIEscapeCode {
 
     /// Modifier codes.
     ///
-    /// a [`Vec`] of [`i32`] collecting all the modifier codes that follows
+    /// [`ModifierCodes`] collecting all the modifier codes that follows
     /// `code` above. For commands like `\x1b[2A` to move cursor up by `2` rows,
-    /// `modifiers` will be `vec![2]`; for colour commands like the above `\x1b[30m`,
-    /// this will be an empty `vec![]`.
-    pub modifiers: Vec<i32>,
+    /// `modifiers` will be `[2]`; for colour commands like the above `\x1b[30m`,
+    /// this will be empty.
+    pub modifiers: ModifierCodes,
 
     /// Separator char.
     ///
@@ -110,25 +106,52 @@ pub struct ANSIEscapeCode {
 
     /// Original `String` that generated this instance.
     ///
-    /// This field is for the sole purpose of allowing `len()` to work. Instead of
-    /// measuring the length of the rebuilt string, this field records the original
-    /// [`String`] from [`TryFrom<&str>`], so this value will always be accurate.
+    /// Parsing no longer records it, so that it does not allocate; this is [`None`]
+    /// unless set by [`Self::add_source()`], in which case `len()` measures it
+    /// instead of the rebuilt string.
+    #[deprecated(
+        note = "parsing no longer copies its source; borrow it with `ANSIEscapeCodeRef::as_str()` instead"
+    )]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub source_str: Option<String>,
 }
 #[allow(dead_code)]
 impl ANSIEscapeCode {
     /// Creates a new [`ANSIEscapeCode`] instance with the default separator.
     pub fn new(code: Option<u16>, modifiers: Option<Vec<i32>>, end_char: char) -> Self {
-        return Self {
+        Self::with_modifiers(code, modifiers.as_deref().unwrap_or(&[]), end_char)
+    }
+
+    /// Creates a new [`ANSIEscapeCode`] instance with the default separator, copying
+    /// the modifiers from a slice.
+    ///
+    /// Unlike [`Self::new()`], this does not allocate unless there are more
+    /// modifiers than [`ModifierCodes`] can hold inline.
+    #[allow(deprecated)]
+    pub fn with_modifiers(code: Option<u16>, modifiers: &[i32], end_char: char) -> Self {
+        Self {
             code,
-            modifiers: modifiers.unwrap_or(Vec::new()),
+            modifiers: ModifierCodes::from_slice(modifiers),
             sep: DEFAULT_SEPARATOR,
             end_char,
             source_str: None,
-        };
+        }
+    }
+
+    /// Iterate through all the codes of this instance, i.e. [`Self::code`] followed
+    /// by [`Self::modifiers`].
+    pub fn iter_codes(&self) -> impl Iterator<Item = i32> + '_ {
+        self.code
+            .map(i32::from)
+            .into_iter()
+            .chain(self.modifiers.iter().copied())
     }
 
     /// Chained method to add a soruce to this instance.
+    #[deprecated(
+        note = "parsing no longer copies its source; borrow it with `ANSIEscapeCodeRef::as_str()` instead"
+    )]
+    #[allow(deprecated)]
     pub fn add_source(mut self, text: &str) -> Self {
         self.source_str = Some(text.to_string());
         self
@@ -142,21 +165,30 @@ impl ANSIEscapeCode {
 
     /// Parse a [`str`] beginning with `\x1b` into a [`regex::Captures`] object.
     pub fn parse(text: &str) -> Result<Captures, ModifierError> {
-        ESCAPE_CODE_START_PATTERN
-            .captures(text)
-            .ok_or(ModifierError::ValueIsNotAModifier(
-                text.to_string(),
-                String::from("Unmatchable pattern."),
-            ))
+        ESCAPE_CODE_START_PATTERN.captures(text).ok_or_else(|| {
+            // Let the byte parser explain what is wrong with the text.
+            ANSIEscapeCodeRef::parse(text).err().unwrap_or_else(|| {
+                ModifierError::new(ModifierErrorKind::Unmatchable, "ANSIEscapeCode")
+            })
+        })
     }
 }
 impl HasLength for ANSIEscapeCode {
     /// Return the `len` of the stringified version of itself.
+    ///
+    /// This is calculated from the codes without building the [`String`].
     fn len(&self) -> usize {
-        match &self.source_str {
-            Some(s) => s.len(),
-            None => self.to_string().len(),
+        #[allow(deprecated)]
+        if let Some(source) = &self.source_str {
+            return source.len();
         }
+
+        let (count, digits) = self.iter_codes().fold((0, 0), |(count, digits), code| {
+            (count + 1, digits + decimal_len(code))
+        });
+
+        // `\x1b[`, the codes, the separators in between, then `end_char`.
+        2 + digits + count.max(1) - 1 + self.end_char.len_utf8()
     }
 }
 impl PartialEq for ANSIEscapeCode {
@@ -169,11 +201,11 @@ impl PartialEq for ANSIEscapeCode {
 impl TryFrom<&str> for ANSIEscapeCode {
     type Error = ModifierError;
 
+    /// Parse the escape code at the start of `value`.
+    ///
+    /// See [`ANSIEscapeCodeRef::parse()`]; the span into `value` is discarded.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let captures = Self::parse(value)?;
-
-        // Just use [`TryFrom<Captures<'t>>`] to do the job for us.
-        Self::try_from(captures)
+        ANSIEscapeCodeRef::parse(value).map(ANSIEscapeCodeRef::into_owned)
     }
 }
 impl<'t> TryFrom<Captures<'t>> for ANSIEscapeCode {
@@ -183,100 +215,28 @@ impl<'t> TryFrom<Captures<'t>> for ANSIEscapeCode {
     ///
     /// Mostly for internal use, due to it being restricted for a single regex pattern.
     fn try_from(value: Captures) -> Result<Self, Self::Error> {
-        let captures = value; // Rename value: change owner
-
-        let codes: Vec<i32> = {
-            captures
-                .name("codes")
-                .ok_or(ModifierError::BadRegexPattern)
-                .and_then(|codes_match| {
-                    Result::from_iter(SEP_PATTERN.split(codes_match.as_str()).map(|code| {
-                        code.parse::<i32>().or(
-                            // At least one of the code is not u8 parsable
-                            Err(ModifierError::ValueNotRecognised(
-                                stringify!($enum_name).to_string(),
-                                code.to_string(),
-                                String::from(
-                                    "At least one of the provided codes are not i16 parsable.",
-                                ),
-                            )),
-                        )
-                    }))
-                })
-        }?;
-
-        let end_char = captures
-            .name("end_char")
-            .ok_or(ModifierError::BadRegexPattern)?
-            .as_str()
-            .chars()
-            .next()
-            .unwrap();
-
-        let (code, modifiers) = match end_char {
-            'm' => {
-                let code: u16 = codes
-                    .first()
-                    .ok_or(
-                        // Codes are empty
-                        ModifierError::ValueNotRecognised(
-                            stringify!($enum_name).to_string(),
-                            format!("{:?}", codes),
-                            String::from("No codes provided."),
-                        ),
-                    )
-                    .and_then(|code| {
-                        (*code).try_into().or(
-                            // ANSI Code not within u8
-                            Err(ModifierError::ValueIsNotAModifier(
-                                captures
-                                    .get(0)
-                                    .map(|m| m.as_str())
-                                    .unwrap_or("(unparsable match)")
-                                    .to_string(),
-                                String::from("Code is not a valid u16 integer."),
-                            )),
-                        )
-                    })?;
-
-                let modifiers = Some(Vec::from(&codes[1..]));
-
-                (Some(code), modifiers)
-            }
-            _ => (None, Some(codes)),
-        };
-
-        Ok(Self::new(code, modifiers, end_char).add_source(
-            captures
-                .get(0)
-                .unwrap() // `.get(0)` must be `Some()`
-                .as_str(),
-        ))
+        value
+            .get(0)
+            .ok_or_else(|| ModifierError::new(ModifierErrorKind::BadRegexPattern, "ANSIEscapeCode"))
+            .and_then(|matched| Self::try_from(matched.as_str()))
     }
 }
 impl fmt::Display for ANSIEscapeCode {
     /// Format this pattern back into its [`str`] form.
+    ///
+    /// The codes are written straight into the formatter without any intermediate
+    /// [`String`].
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        // If there is a code, use it to start an iterator.
-        // Otherwise, get an empty [`Vec`] to start it instead.
-        let code = if let Some(code) = self.code {
-            vec![code as i32]
-        } else {
-            Vec::new()
-        };
+        f.write_str("\x1b[")?;
 
-        let modifier_string =
-            code.iter()
-                .chain(self.modifiers.iter())
-                .fold(String::new(), |mut lhs, rhs| {
-                    if lhs.len() > 0 {
-                        lhs.push(self.sep)
-                    };
-                    lhs.push_str(&rhs.to_string());
-                    lhs
-                });
+        for (idx, code) in self.iter_codes().enumerate() {
+            if idx > 0 {
+                f.write_char(self.sep)?;
+            }
+            write!(f, "{}", code)?;
+        }
 
-        write!(f, "\x1b[{}{}", modifier_string, self.end_char)
+        f.write_char(self.end_char)
     }
 }
 impl<U> From<&U> for ANSIEscapeCode
@@ -294,3 +254,14 @@ where
         value.into_ansi_escape_code()
     }
 }
+
+/// Number of characters required to print `value` in decimal.
+fn decimal_len(value: i32) -> usize {
+    let sign = usize::from(value < 0);
+
+    sign + value
+        .unsigned_abs()
+        .checked_ilog10()
+        .map(|digits| digits as usize + 1)
+        .unwrap_or(1)
+}
@@ -0,0 +1,318 @@
+//! A borrowed view of an [`ANSIEscapeCode`] found within a [`str`].
+//!
+use std::ops::Range;
+
+use crate::{
+    ANSIEscapeCode, EscapeCodePart, HasLength, ModifierCodes, ModifierError, ModifierErrorKind,
+    ESCAPE_CHAR,
+};
+
+const TARGET: &str = "ANSIEscapeCode";
+
+/// Reasons for [`ANSIEscapeCodeRef::scan()`] to fail, before they are turned into
+/// a [`ModifierError`].
+enum ScanFailure {
+    /// The byte at this index is not what the syntax expects.
+    Unmatchable { at: usize, expected: &'static str },
+
+    /// The parameter at this index and span does not fit into an [`i32`].
+    CodeOutOfRange(usize, Range<usize>),
+
+    /// The code of the escape code, at this span, is not a valid [`u16`].
+    NotU16(Range<usize>),
+}
+
+/// An [`ANSIEscapeCode`] parsed out of a text, together with where it was found.
+///
+/// Instead of keeping a copy of its source, this only records a `span` into the
+/// text it was parsed from; as the codes themselves are kept inline within
+/// [`ModifierCodes`], parsing does not allocate at all for any typical escape code.
+///
+/// Use [`Self::escape_code`] for the owned form, which outlives the text.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_base_models::{ANSIEscapeCode, ANSIEscapeCodeRef};
+///
+/// let text = "Hello, \x1b[38;5;9mWorld!";
+/// let parsed = ANSIEscapeCodeRef::parse_at(text, 7).unwrap();
+///
+/// assert_eq!(parsed.span, 7..16);
+/// assert_eq!(parsed.as_str(), "\x1b[38;5;9m");
+/// assert_eq!(
+///     parsed.escape_code,
+///     ANSIEscapeCode::with_modifiers(Some(38), &[5, 9], 'm'),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ANSIEscapeCodeRef<'t> {
+    /// The parsed escape code.
+    pub escape_code: ANSIEscapeCode,
+
+    /// The text in which the escape code was found.
+    pub text: &'t str,
+
+    /// The byte range of the escape code within [`Self::text`].
+    pub span: Range<usize>,
+}
+impl<'t> ANSIEscapeCodeRef<'t> {
+    /// Parse the escape code at the start of `text`.
+    pub fn parse(text: &'t str) -> Result<Self, ModifierError> {
+        Self::parse_at(text, 0)
+    }
+
+    /// Parse the escape code starting at the byte index `start` of `text`.
+    ///
+    /// Anything in `text` after the escape code is ignored. If `start` is beyond the
+    /// end of `text`, or not on a character boundary, a [`ModifierError`] of
+    /// [`ModifierErrorKind::InvalidArgument`] is returned.
+    pub fn parse_at(text: &'t str, start: usize) -> Result<Self, ModifierError> {
+        if !text.is_char_boundary(start) {
+            let expected = if start > text.len() {
+                "a start within the text"
+            } else {
+                "a start on a character boundary"
+            };
+
+            return Err(
+                ModifierError::new(ModifierErrorKind::InvalidArgument, TARGET)
+                    .with_expected(expected)
+                    .with_found(start),
+            );
+        }
+
+        Self::scan(text, start).map_err(|failure| match failure {
+            ScanFailure::Unmatchable { at, expected } => {
+                // Include the whole offending character, which may be multibyte.
+                let end = at + text[at..].chars().next().map_or(0, char::len_utf8);
+
+                ModifierError::new(ModifierErrorKind::Unmatchable, TARGET)
+                    .with_expected(expected)
+                    .with_found(&text[start..end])
+                    .with_span(at..end)
+            }
+            ScanFailure::CodeOutOfRange(param, span) => {
+                ModifierError::new(ModifierErrorKind::CodeOutOfRange, TARGET)
+                    .with_expected("a code within the range of i32")
+                    .with_found(&text[span.clone()])
+                    .with_part(EscapeCodePart::Param(param))
+                    .with_span(span)
+            }
+            ScanFailure::NotU16(span) => {
+                ModifierError::new(ModifierErrorKind::CodeOutOfRange, TARGET)
+                    .with_expected("an ANSI code between 0 and 65535")
+                    .with_found(&text[span.clone()])
+                    .with_part(EscapeCodePart::Param(0))
+                    .with_span(span)
+            }
+        })
+    }
+
+    /// The actual parser behind [`Self::parse_at()`].
+    ///
+    /// Failures are reported without allocating, so that [`ANSIEscapeCodes`] can
+    /// skip over invalid escape codes cheaply.
+    fn scan(text: &'t str, start: usize) -> Result<Self, ScanFailure> {
+        let bytes = text.as_bytes();
+        let unmatchable = |at: usize, expected| ScanFailure::Unmatchable { at, expected };
+
+        if bytes.get(start) != Some(&ESCAPE_CHAR) {
+            return Err(unmatchable(start, "`\\x1b[`"));
+        }
+        if bytes.get(start + 1) != Some(&b'[') {
+            return Err(unmatchable(start + 1, "`[`"));
+        }
+
+        let mut codes = ModifierCodes::new();
+        let mut idx = start + 2;
+
+        let end_char = loop {
+            let code_start = idx;
+            let negative = bytes.get(idx) == Some(&b'-');
+            if negative {
+                idx += 1;
+            }
+
+            let digits_start = idx;
+            let mut value: i64 = 0;
+            while let Some(digit @ b'0'..=b'9') = bytes.get(idx) {
+                // Saturate instead of overflowing; anything this large is rejected
+                // below anyway.
+                value = (value * 10 + i64::from(digit - b'0')).min(i64::from(u32::MAX) + 1);
+                idx += 1;
+            }
+
+            if idx == digits_start {
+                return Err(unmatchable(idx, "a digit"));
+            }
+
+            let value = if negative { -value } else { value };
+            codes.push(
+                i32::try_from(value)
+                    .map_err(|_| ScanFailure::CodeOutOfRange(codes.len(), code_start..idx))?,
+            );
+
+            match bytes.get(idx) {
+                Some(b';' | b':') => idx += 1,
+                Some(end_char) if end_char.is_ascii_alphabetic() => {
+                    idx += 1;
+                    break *end_char as char;
+                }
+                _ => return Err(unmatchable(idx, "`;`, `:` or a command character")),
+            }
+        };
+
+        let span = start..idx;
+
+        let code = match end_char {
+            'm' => Some(u16::try_from(codes.remove(0)).map_err(|_| {
+                // The code is the first parameter, right after `\x1b[`.
+                let code_end = bytes[start + 3..idx]
+                    .iter()
+                    .position(|byte| !byte.is_ascii_digit())
+                    .map_or(idx, |offset| start + 3 + offset);
+
+                ScanFailure::NotU16(start + 2..code_end)
+            })?),
+            _ => None,
+        };
+
+        Ok(Self {
+            escape_code: ANSIEscapeCode {
+                code,
+                modifiers: codes,
+                ..ANSIEscapeCode::with_modifiers(None, &[], end_char)
+            },
+            text,
+            span,
+        })
+    }
+
+    /// Return the escape code as it appears in [`Self::text`].
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.span.clone()]
+    }
+
+    /// Find the byte range of `part` within [`Self::text`].
+    pub fn span_of(&self, part: EscapeCodePart) -> Range<usize> {
+        match part {
+            EscapeCodePart::EndChar => self.span.end - 1..self.span.end,
+            EscapeCodePart::Param(param) => {
+                // Parameters sit between `\x1b[` and the end character.
+                let params_start = self.span.start + 2;
+                let params = &self.text[params_start..self.span.end - 1];
+
+                params
+                    .split([';', ':'])
+                    .scan(params_start, |position, param| {
+                        let span = *position..*position + param.len();
+                        *position = span.end + 1;
+                        Some(span)
+                    })
+                    .nth(param)
+                    .unwrap_or_else(|| self.span.clone())
+            }
+        }
+    }
+
+    /// Point `error` at where it occurred within [`Self::text`], if it is not
+    /// located already.
+    ///
+    /// The span of [`ModifierError::part`] is used if it is known, otherwise the
+    /// span of the whole escape code.
+    pub fn locate(&self, error: ModifierError) -> ModifierError {
+        if error.span.is_some() {
+            return error;
+        }
+
+        let span = error
+            .part
+            .map_or_else(|| self.span.clone(), |part| self.span_of(part));
+        error.with_span(span)
+    }
+
+    /// Convert the parsed escape code into `T`, locating any error within
+    /// [`Self::text`].
+    pub fn try_convert<T>(&self) -> Result<T, ModifierError>
+    where
+        T: for<'a> TryFrom<&'a ANSIEscapeCode, Error = ModifierError>,
+    {
+        T::try_from(&self.escape_code).map_err(|err| self.locate(err))
+    }
+
+    /// Discard the source, returning the owned [`ANSIEscapeCode`].
+    pub fn into_owned(self) -> ANSIEscapeCode {
+        self.escape_code
+    }
+}
+impl HasLength for ANSIEscapeCodeRef<'_> {
+    /// Return the `len` of the escape code as it appears in the source text.
+    ///
+    /// This can differ from the `len` of [`Self::escape_code`] for codes with
+    /// leading zeros, such as `\x1b[01m`.
+    fn len(&self) -> usize {
+        self.span.len()
+    }
+}
+impl<'t> From<ANSIEscapeCodeRef<'t>> for ANSIEscapeCode {
+    fn from(value: ANSIEscapeCodeRef<'t>) -> Self {
+        value.into_owned()
+    }
+}
+
+/// Iterator over all the parsable [`ANSIEscapeCodeRef`] within a [`str`].
+///
+/// This scans the bytes of the text directly without any [`regex::Regex`], and
+/// does not allocate while doing so.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_base_models::ANSIEscapeCodes;
+///
+/// let text = "\x1b[1mHello\x1b[22m, \x1b[38;5World!\x1b[2A";
+/// let spans: Vec<_> = ANSIEscapeCodes::new(text).map(|code| code.span).collect();
+///
+/// // `\x1b[38;5W` is valid syntax, albeit meaningless.
+/// assert_eq!(spans, vec![0..4, 9..14, 16..23, 28..32]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ANSIEscapeCodes<'t> {
+    text: &'t str,
+    position: usize,
+}
+impl<'t> ANSIEscapeCodes<'t> {
+    /// Iterate over the escape codes in `text`.
+    pub fn new(text: &'t str) -> Self {
+        Self { text, position: 0 }
+    }
+}
+impl<'t> Iterator for ANSIEscapeCodes<'t> {
+    type Item = ANSIEscapeCodeRef<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+
+        while let Some(offset) = bytes[self.position..]
+            .iter()
+            .position(|byte| *byte == ESCAPE_CHAR)
+        {
+            let start = self.position + offset;
+
+            match ANSIEscapeCodeRef::scan(self.text, start) {
+                Ok(code) => {
+                    self.position = code.span.end;
+                    return Some(code);
+                }
+                // If its not valid, continue searching.
+                Err(_) => self.position = start + 1,
+            }
+        }
+
+        self.position = bytes.len();
+        None
+    }
+}
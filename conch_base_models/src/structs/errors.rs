@@ -1,35 +1,186 @@
+use std::fmt;
+use std::ops::Range;
+
 use thiserror::Error;
 
-/// A enum of possible error types during modifier operations.
-#[derive(Error, Debug)]
-pub enum ModifierError {
-    #[error("{0:?} is not an ANSI modifier: {1}")]
-    ValueIsNotAModifier(String, String),
+use crate::render_span;
+
+/// Machine readable kinds of [`ModifierError`].
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ModifierErrorKind {
+    #[error("not a valid ANSI escape code")]
+    Unmatchable,
 
-    #[error("bad Regex passed to parser.")]
+    #[error("code out of range")]
+    CodeOutOfRange,
+
+    #[error("bad Regex passed to parser")]
     BadRegexPattern,
 
-    #[error("{0} is not a supported ANSI code.")]
-    UnsupportedANSICode(u16),
+    #[error("unsupported ANSI code")]
+    UnsupportedCode,
+
+    #[error("unsupported ANSI command character")]
+    UnsupportedEndChar,
+
+    #[error("mismatched ANSI code")]
+    MismatchedCode,
+
+    #[error("missing ANSI code")]
+    MissingCode,
+
+    #[error("unexpected ANSI command character")]
+    UnexpectedEndChar,
 
-    #[error("{0:?} is not a supported ANSI command character.")]
-    UnsupportedEndChar(char),
+    #[error("unrecognised combination of codes")]
+    UnrecognisedCodes,
 
-    #[error("{1:?} is not the correct ANSI code for {0}; {2} expected.")]
-    MismatchedANSICode(String, u16, u16),
+    #[error("wrong number of arguments")]
+    WrongArgumentCount,
 
-    #[error("No ANSI code supplied for {0}; {1} expected.")]
-    MissingANSICode(String, u16),
+    #[error("no matching variant")]
+    VariantNotFound,
+
+    #[error("unrecognised style")]
+    UnrecognisedStyle,
+
+    #[error("invalid argument")]
+    InvalidArgument,
+}
+
+/// A part of an [`crate::ANSIEscapeCode`] that a [`ModifierError`] is about.
+///
+/// This allows errors raised from an [`crate::ANSIEscapeCode`], which does not know
+/// where it came from, to be located later by [`crate::ANSIEscapeCodeRef::locate()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EscapeCodePart {
+    /// The parameter at this index, counting the `code` as `0` if it is present.
+    Param(usize),
+
+    /// The trailing command character.
+    EndChar,
+}
 
-    #[error("{1:?} is not a recognised pattern for {0}: {2}")]
-    ValueNotRecognised(String, String, String),
+/// An error during modifier operations.
+///
+/// Besides a machine readable [`ModifierErrorKind`], this records the type that was
+/// being parsed, what was expected and what was found instead; if the error came from
+/// parsing a text, `span` points at the offending bytes within it, which
+/// [`Self::render()`] can underline.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_base_models::{ANSIEscapeCode, ModifierErrorKind};
+///
+/// let err = ANSIEscapeCode::try_from("\x1b[38;5;a9m").unwrap_err();
+///
+/// assert_eq!(err.kind, ModifierErrorKind::Unmatchable);
+/// assert_eq!(err.span, Some(7..8));
+/// assert_eq!(
+///     err.render("\x1b[38;5;a9m"),
+///     concat!(
+///         "error: ANSIEscapeCode: not a valid ANSI escape code; ",
+///         "expected a digit, found `\\u{1b}[38;5;a`\n",
+///         "  |\n",
+///         "1 | \\u{1b}[38;5;a9m\n",
+///         "  |             ^\n",
+///     ),
+/// );
+/// ```
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub struct ModifierError {
+    /// What went wrong.
+    pub kind: ModifierErrorKind,
 
-    #[error("{1:?} is not the correct end character for {0}.")]
-    UnexpectedEndCharacter(String, String),
+    /// Name of the type that was being parsed or built.
+    pub target: &'static str,
 
-    #[error("codes {1:?} do not represent an existing {0} variant.")]
-    VariantNotFound(String, Vec<i32>),
+    /// Description of what was expected.
+    pub expected: Option<String>,
+
+    /// Description of what was found instead.
+    pub found: Option<String>,
+
+    /// The part of the escape code at fault, if known.
+    pub part: Option<EscapeCodePart>,
+
+    /// The byte range of the offending part within the source text, if known.
+    pub span: Option<Range<usize>>,
+}
+impl ModifierError {
+    /// Create a new [`ModifierError`] of `kind`, raised while parsing a `target`.
+    pub fn new(kind: ModifierErrorKind, target: &'static str) -> Self {
+        Self {
+            kind,
+            target,
+            expected: None,
+            found: None,
+            part: None,
+            span: None,
+        }
+    }
+
+    /// Chained method to describe what was expected.
+    pub fn with_expected(mut self, expected: impl ToString) -> Self {
+        self.expected = Some(expected.to_string());
+        self
+    }
+
+    /// Chained method to describe what was found instead.
+    pub fn with_found(mut self, found: impl ToString) -> Self {
+        self.found = Some(found.to_string());
+        self
+    }
+
+    /// Chained method to record the part of the escape code at fault.
+    pub fn with_part(mut self, part: EscapeCodePart) -> Self {
+        self.part = Some(part);
+        self
+    }
+
+    /// Chained method to record the byte range of the offending part.
+    pub fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Chained method to shift the span by `offset` bytes, for when the text that
+    /// was parsed is itself a part of a larger text.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.span = self.span.map(|span| span.start + offset..span.end + offset);
+        self
+    }
+
+    /// Pretty print this error, underlining the offending part of `source`.
+    ///
+    /// `source` should be the text in which [`Self::span`] is; if there is no span,
+    /// only the message is printed. Control characters such as `\x1b` are escaped so
+    /// that the output is safe to print to a terminal.
+    pub fn render(&self, source: &str) -> String {
+        let message = format!("error: {}", self);
+
+        match &self.span {
+            Some(span) => render_span(source, span.clone(), &message),
+            None => message + "\n",
+        }
+    }
+}
+impl fmt::Display for ModifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.target, self.kind)?;
 
-    #[error("bad Captures group passed to {0}.")]
-    BadCapturesGroup(String),
+        match (&self.expected, &self.found) {
+            (Some(expected), Some(found)) => write!(
+                f,
+                "; expected {}, found `{}`",
+                expected,
+                found.escape_debug()
+            ),
+            (Some(expected), None) => write!(f, "; expected {}", expected),
+            (None, Some(found)) => write!(f, "; found `{}`", found.escape_debug()),
+            (None, None) => Ok(()),
+        }
+    }
 }
@@ -4,5 +4,8 @@ pub use consts::*;
 mod ansi_command;
 pub use ansi_command::*;
 
+mod ansi_command_ref;
+pub use ansi_command_ref::*;
+
 mod errors;
 pub use errors::*;
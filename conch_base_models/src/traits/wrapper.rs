@@ -1,8 +1,60 @@
+use std::{fmt, io};
+
 use crate::{IntoANSIEscapeCode, Resetter};
 
 /// Auto trait for structs and enums that can wrap a string.
 pub trait StringWrapper {
-    fn wraps(&self, text: &str) -> String;
+    /// Write the text enclosed with the modifier into `w`.
+    ///
+    /// This writes straight into `w` without allocating any [`String`].
+    fn write_wrapped<W>(&self, w: &mut W, text: &str) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized;
+
+    /// Enclose the text with the modifier.
+    fn wraps(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+
+        self.write_wrapped(&mut output, text)
+            .expect("Writing to a `String` should never fail.");
+
+        output
+    }
+
+    /// Enclose the text with the modifier, unless it is empty.
+    ///
+    /// Unlike [`Self::wraps()`], this does not leave a pair of escape codes around
+    /// nothing, such as an empty part of a bar or a highlight.
+    fn wraps_non_empty(&self, text: &str) -> String {
+        if text.is_empty() {
+            String::new()
+        } else {
+            self.wraps(text)
+        }
+    }
+
+    /// Write the text enclosed with the modifier into an [`io::Write`].
+    fn write_wrapped_io<W>(&self, w: &mut W, text: &str) -> io::Result<()>
+    where
+        Self: Sized,
+        W: io::Write + ?Sized,
+    {
+        write!(w, "{}", self.paint(text))
+    }
+
+    /// Lazily enclose the text with the modifier.
+    ///
+    /// The returned [`Painted`] implements [`fmt::Display`], which writes the
+    /// wrapped text directly into the formatter when it is displayed.
+    fn paint<'a>(&'a self, text: &'a str) -> Painted<'a, Self>
+    where
+        Self: Sized,
+    {
+        Painted {
+            modifier: self,
+            text,
+        }
+    }
 }
 
 impl<U> StringWrapper for U
@@ -10,8 +62,12 @@ where
     U: IntoANSIEscapeCode + Resetter,
 {
     /// Enclose the text with the modifier.
-    fn wraps(&self, text: &str) -> String {
-        format!(
+    fn write_wrapped<W>(&self, w: &mut W, text: &str) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        write!(
+            w,
             "{}{}{}",
             self.into_ansi_escape_code(),
             text,
@@ -19,3 +75,22 @@ where
         )
     }
 }
+
+/// A text lazily wrapped by a modifier, as returned by [`StringWrapper::paint()`].
+#[derive(Clone, Copy, Debug)]
+pub struct Painted<'a, M>
+where
+    M: StringWrapper + ?Sized,
+{
+    modifier: &'a M,
+    text: &'a str,
+}
+
+impl<M> fmt::Display for Painted<'_, M>
+where
+    M: StringWrapper + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.modifier.write_wrapped(f, self.text)
+    }
+}
@@ -5,6 +5,8 @@
 //!
 //! These tests involves mostly meaningless patterns, but are nonetheless valid
 //! ANSI Escape syntax in principle.
+use std::ops::Add;
+
 use conch_base_models::*;
 
 mod test_parsing {
@@ -26,10 +28,7 @@ mod test_parsing {
                     if $expected.is_ok() {
                         panic!("{}", parsed.unwrap_err())
                     } else {
-                        assert_eq!(
-                            $expected.unwrap_err().to_string(),
-                            parsed.unwrap_err().to_string(),
-                        )
+                        assert_eq!($expected.unwrap_err(), parsed.unwrap_err())
                     }
                 }
             }
@@ -51,19 +50,24 @@ mod test_parsing {
     test_factory!(
         negative_code,
         "\x1b[-1m",
-        Err::<ANSIEscapeCode, _>(ModifierError::ValueIsNotAModifier(
-            String::from("\x1b[-1m"),
-            String::from("Code is not a valid u16 integer.")
-        ))
+        Err::<ANSIEscapeCode, _>(
+            ModifierError::new(ModifierErrorKind::CodeOutOfRange, "ANSIEscapeCode")
+                .with_expected("an ANSI code between 0 and 65535")
+                .with_found("-1")
+                .with_part(EscapeCodePart::Param(0))
+                .with_span(2..4)
+        )
     );
 
     test_factory!(
         invalid_code,
         "\x1b[am",
-        Err::<ANSIEscapeCode, _>(ModifierError::ValueIsNotAModifier(
-            String::from("\x1b[am"),
-            String::from("Unmatchable pattern.")
-        ))
+        Err::<ANSIEscapeCode, _>(
+            ModifierError::new(ModifierErrorKind::Unmatchable, "ANSIEscapeCode")
+                .with_expected("a digit")
+                .with_found("\x1b[a")
+                .with_span(2..3)
+        )
     );
 
     test_factory!(
@@ -93,19 +97,23 @@ mod test_parsing {
     test_factory!(
         empty_modifier,
         "\x1b[1::1m",
-        Err::<ANSIEscapeCode, _>(ModifierError::ValueIsNotAModifier(
-            String::from("\x1b[1::1m"),
-            String::from("Unmatchable pattern.")
-        ))
+        Err::<ANSIEscapeCode, _>(
+            ModifierError::new(ModifierErrorKind::Unmatchable, "ANSIEscapeCode")
+                .with_expected("a digit")
+                .with_found("\x1b[1::")
+                .with_span(4..5)
+        )
     );
 
     test_factory!(
         incomplete_modifier,
         "\x1b[30;60",
-        Err::<ANSIEscapeCode, _>(ModifierError::ValueIsNotAModifier(
-            String::from("\x1b[30;60"),
-            String::from("Unmatchable pattern.")
-        ))
+        Err::<ANSIEscapeCode, _>(
+            ModifierError::new(ModifierErrorKind::Unmatchable, "ANSIEscapeCode")
+                .with_expected("`;`, `:` or a command character")
+                .with_found("\x1b[30;60")
+                .with_span(7..7)
+        )
     );
 
     test_factory!(
@@ -127,10 +135,12 @@ mod test_parsing {
     test_factory!(
         invalid_modifier,
         "\x1b[1:am",
-        Err::<ANSIEscapeCode, _>(ModifierError::ValueIsNotAModifier(
-            String::from("\x1b[1:am"),
-            String::from("Unmatchable pattern.")
-        ))
+        Err::<ANSIEscapeCode, _>(
+            ModifierError::new(ModifierErrorKind::Unmatchable, "ANSIEscapeCode")
+                .with_expected("a digit")
+                .with_found("\x1b[1:a")
+                .with_span(4..5)
+        )
     );
 
     test_factory!(
@@ -166,10 +176,12 @@ mod test_parsing {
     test_factory!(
         extra_leading_text,
         "This is some extra text\x1b[30;60H",
-        Err::<ANSIEscapeCode, _>(ModifierError::ValueIsNotAModifier(
-            String::from("This is some extra text\x1b[30;60H"),
-            String::from("Unmatchable pattern.")
-        ))
+        Err::<ANSIEscapeCode, _>(
+            ModifierError::new(ModifierErrorKind::Unmatchable, "ANSIEscapeCode")
+                .with_expected("`\\x1b[`")
+                .with_found("T")
+                .with_span(0..1)
+        )
     );
 
     test_factory!(
@@ -180,4 +192,213 @@ mod test_parsing {
             ANSIEscapeCode::new(None, Some(vec![30, 60]), 'H')
         )
     );
+
+    #[test]
+    #[allow(deprecated)]
+    fn len_of_source() {
+        let parsed = ANSIEscapeCode::try_from("\x1b[01m").unwrap();
+        assert_eq!(parsed.source_str, None);
+        assert_eq!(parsed.len(), 4);
+
+        let parsed = parsed.add_source("\x1b[01m");
+        assert_eq!(parsed.source_str.as_deref(), Some("\x1b[01m"));
+        assert_eq!(parsed.len(), 5);
+    }
+}
+
+mod test_parsing_ref {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $text:literal,
+            $start:literal,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let parsed = ANSIEscapeCodeRef::parse_at($text, $start)
+                    .map(|code| (code.span.clone(), code.escape_code))
+                    .map_err(|err| err.to_string());
+
+                assert_eq!(parsed, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        at_start,
+        "\x1b[1mHello",
+        0,
+        Ok((0..4, ANSIEscapeCode::new(Some(1), None, 'm'))),
+    );
+
+    test_factory!(
+        within_text,
+        "Hello, \x1b[38;5;9mWorld!",
+        7,
+        Ok((7..16, ANSIEscapeCode::new(Some(38), Some(vec![5, 9]), 'm'))),
+    );
+
+    test_factory!(
+        leading_zeros,
+        "\x1b[01m",
+        0,
+        Ok((0..5, ANSIEscapeCode::new(Some(1), None, 'm'))),
+    );
+
+    test_factory!(
+        not_at_escape,
+        "Hello, \x1b[1m",
+        0,
+        Err(String::from(
+            "ANSIEscapeCode: not a valid ANSI escape code; expected `\\x1b[`, found `H`"
+        )),
+    );
+
+    test_factory!(
+        code_overflow,
+        "\x1b[1;99999999999m",
+        0,
+        Err(String::from(
+            "ANSIEscapeCode: code out of range; expected a code within the range of i32, \
+            found `99999999999`"
+        )),
+    );
+
+    test_factory!(
+        start_beyond_text,
+        "ab",
+        5,
+        Err(String::from(
+            "ANSIEscapeCode: invalid argument; expected a start within the text, found `5`"
+        )),
+    );
+
+    test_factory!(
+        start_within_char,
+        "é\x1b[1m",
+        1,
+        Err(String::from(
+            "ANSIEscapeCode: invalid argument; expected a start on a character boundary, \
+            found `1`"
+        )),
+    );
+
+    test_factory!(
+        start_at_end,
+        "ab",
+        2,
+        Err(String::from(
+            "ANSIEscapeCode: not a valid ANSI escape code; expected `\\x1b[`, found ``"
+        )),
+    );
+}
+
+mod test_iter_escape_codes {
+    use super::*;
+
+    #[test]
+    fn skips_invalid_codes() {
+        let text = "\x1b[1m\x1b[;m\x1b[38;5;9Hello\x1b[1;-1m\x1b\x1b[2A";
+        let found: Vec<_> = ANSIEscapeCodes::new(text)
+            .map(|code| (code.as_str(), code.span))
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                ("\x1b[1m", 0..4),
+                ("\x1b[38;5;9H", 8..17),
+                ("\x1b[1;-1m", 21..28),
+                ("\x1b[2A", 29..33),
+            ]
+        );
+    }
+
+    #[test]
+    fn large_input() {
+        let line = "\x1b[38;5;9mERROR\x1b[39m Something went wrong\n";
+        let text = line.repeat(10_000);
+
+        assert_eq!(ANSIEscapeCodes::new(&text).count(), 20_000);
+    }
+}
+
+mod test_render {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $source:expr,
+            $span:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                assert_eq!(render_span($source, $span, "error: oops"), $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        single_line,
+        "fg:Bleu",
+        3..7,
+        "error: oops\n  |\n1 | fg:Bleu\n  |    ^^^^\n",
+    );
+
+    test_factory!(
+        escaped_control_characters,
+        "\x1b[38;5;a9m",
+        7..8,
+        "error: oops\n  |\n1 | \\u{1b}[38;5;a9m\n  |             ^\n",
+    );
+
+    test_factory!(
+        empty_span_at_end,
+        "\x1b[30;60",
+        7..7,
+        "error: oops\n  |\n1 | \\u{1b}[30;60\n  |             ^\n",
+    );
+
+    test_factory!(
+        span_across_lines,
+        "line 1\nline 2\nline 3",
+        12..16,
+        "error: oops\n  |\n2 | line 2\n  |      ^\n",
+    );
+
+    test_factory!(
+        double_digit_line_number,
+        &"\n".repeat(10).add("fg:Bleu"),
+        13..17,
+        "error: oops\n   |\n11 | fg:Bleu\n   |    ^^^^\n",
+    );
+
+    #[test]
+    fn error_without_span() {
+        let error = ModifierError::new(ModifierErrorKind::MissingCode, "Intensity")
+            .with_expected("`1`, `2` or `22`");
+
+        assert_eq!(
+            error.render("whatever"),
+            "error: Intensity: missing ANSI code; expected `1`, `2` or `22`\n"
+        );
+    }
+
+    #[test]
+    fn locate_parameter() {
+        let parsed = ANSIEscapeCodeRef::parse_at("Hi \x1b[38;-5;9m", 3).unwrap();
+        let error = ModifierError::new(ModifierErrorKind::UnrecognisedCodes, "Colour")
+            .with_part(EscapeCodePart::Param(1));
+
+        assert_eq!(parsed.span_of(EscapeCodePart::Param(0)), 5..7);
+        assert_eq!(parsed.span_of(EscapeCodePart::EndChar), 12..13);
+        assert_eq!(parsed.locate(error).span, Some(8..10));
+    }
 }
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{CalendarMonth, RegionMarker};
+use conch_ansi::Modifier;
+
+/// A description of all the chained options of a [`CalendarMonth`], such that a
+/// calendar can be fully described by a configuration file.
+///
+/// Every option is optional; anything left as [`None`] keeps the default of the
+/// [`CalendarMonth`] it is applied to. With the `serde` feature, this can be
+/// (de)serialized, with [`Modifier`]s written as style strings such as
+/// `"Bold fg:BrightRed"`.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use chrono::{NaiveDate, Weekday};
+///
+/// use conch::{regions, CalendarMonth, CalendarMonthConfig, Modifier};
+///
+/// let config = CalendarMonthConfig {
+///     starts_week_with: Some(Weekday::Sun),
+///     show_other_months: Some(true),
+///     holiday_modifier: Some("fg:BrightBlue".parse().unwrap()),
+///     ..Default::default()
+/// };
+///
+/// let calendar: CalendarMonth<regions::England> =
+///     CalendarMonth::from_config(NaiveDate::from_ymd_opt(2023, 3, 3).unwrap(), &config);
+///
+/// assert_eq!(calendar.week_starts_with, Weekday::Sun);
+/// assert!(calendar.show_other_months);
+/// assert_eq!(calendar.holiday_modifier, Modifier::colour("BrightBlue").unwrap());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct CalendarMonthConfig {
+    /// See [`CalendarMonth::starts_week_with()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub starts_week_with: Option<Weekday>,
+
+    /// See [`CalendarMonth::show_title()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub show_title: Option<bool>,
+
+    /// See [`CalendarMonth::show_other_months()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub show_other_months: Option<bool>,
+
+    /// See [`CalendarMonth::capitalize_title()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub capitalize_title: Option<bool>,
+
+    /// See [`CalendarMonth::modify_title()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub title_modifier: Option<Modifier>,
+
+    /// See [`CalendarMonth::modify_weekdays()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub weekday_modifier: Option<Modifier>,
+
+    /// See [`CalendarMonth::modify_holidays()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub holiday_modifier: Option<Modifier>,
+
+    /// See [`CalendarMonth::modify_other_months()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub other_month_modifier: Option<Modifier>,
+
+    /// See [`CalendarMonth::modify_today()`].
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub today_modifier: Option<Modifier>,
+
+    /// See [`CalendarMonth::decorate_day()`].
+    ///
+    /// Days outside of the month of the calendar are ignored.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    pub decorated_days: BTreeMap<NaiveDate, Modifier>,
+}
+
+impl<Region> CalendarMonth<Region>
+where
+    Region: RegionMarker,
+{
+    /// Create a new [`CalendarMonth`] from a [`NaiveDate`] provided, with all the
+    /// options from `config` applied.
+    pub fn from_config(month: NaiveDate, config: &CalendarMonthConfig) -> Self {
+        Self::new(month).apply_config(config)
+    }
+
+    /// Chained method to apply all the options set in `config`.
+    pub fn apply_config(mut self, config: &CalendarMonthConfig) -> Self {
+        macro_rules! expand_options {
+            ($(($option:ident, $method:ident)),+$(,)?) => {
+                $(
+                    if let Some(value) = &config.$option {
+                        self = self.$method(value.clone());
+                    }
+                )*
+            };
+        }
+
+        expand_options!(
+            (starts_week_with, starts_week_with),
+            (show_title, show_title),
+            (show_other_months, show_other_months),
+            (capitalize_title, capitalize_title),
+            (title_modifier, modify_title),
+            (weekday_modifier, modify_weekdays),
+            (holiday_modifier, modify_holidays),
+            (other_month_modifier, modify_other_months),
+        );
+
+        if let Some(modifier) = &config.today_modifier {
+            self = self.modify_today(Some(modifier.clone()));
+        }
+
+        config
+            .decorated_days
+            .iter()
+            .fold(self, |calendar, (date, modifier)| {
+                calendar.decorate_day(*date, modifier.clone())
+            })
+    }
+
+    /// Describe all the options of this [`CalendarMonth`] in a
+    /// [`CalendarMonthConfig`].
+    pub fn to_config(&self) -> CalendarMonthConfig {
+        CalendarMonthConfig {
+            starts_week_with: Some(self.week_starts_with),
+            show_title: Some(self.show_title),
+            show_other_months: Some(self.show_other_months),
+            capitalize_title: Some(self.capitalize_title),
+            title_modifier: Some(self.title_modifier.clone()),
+            weekday_modifier: Some(self.weekday_modifier.clone()),
+            holiday_modifier: Some(self.holiday_modifier.clone()),
+            other_month_modifier: Some(self.other_month_modifier.clone()),
+            today_modifier: self.today_modifier.clone(),
+            decorated_days: self
+                .decorated_days
+                .iter()
+                .map(|(date, modifier)| (*date, modifier.clone()))
+                .collect(),
+        }
+    }
+}
@@ -1,6 +1,9 @@
 mod calendar;
 pub use calendar::*;
 
+mod calendar_config;
+pub use calendar_config::*;
+
 mod holidays;
 pub use holidays::*;
 
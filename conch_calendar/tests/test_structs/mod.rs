@@ -1,2 +1,3 @@
 mod test_calendar;
+mod test_calendar_config;
 mod test_holidays;
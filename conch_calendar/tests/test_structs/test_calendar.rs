@@ -201,3 +201,57 @@ mod test_to_vec_strings {
         ]
     );
 }
+
+#[cfg(test)]
+mod test_panel {
+    use super::*;
+    use conch_base_models::visible_width;
+    use conch_split::{BorderSet, Panel};
+
+    #[test]
+    fn framed_calendar_is_aligned() {
+        let calendar =
+            CalendarMonth::<regions::England>::new(NaiveDate::from_ymd_opt(2023, 2, 1).unwrap());
+
+        let lines = Lines::from(
+            Panel::new(calendar)
+                .title("February")
+                .border(BorderSet::ASCII),
+        )
+        .lines;
+
+        println!("{}", lines.join("\n"));
+
+        assert_eq!(lines.first().unwrap(), "+- February -----------+");
+        assert_eq!(lines.last().unwrap(), "+----------------------+");
+        assert!(lines.iter().all(|line| visible_width(line) == 24));
+    }
+}
+
+#[cfg(test)]
+mod test_columns {
+    use super::*;
+    use conch_base_models::visible_width;
+    use conch_split::Columns;
+
+    #[test]
+    fn three_months_side_by_side() {
+        let columns = (1..=3)
+            .map(|month| {
+                CalendarMonth::<regions::England>::new(
+                    NaiveDate::from_ymd_opt(2023, month, 1).unwrap(),
+                )
+            })
+            .fold(Columns::new().gutter("   "), Columns::push);
+
+        let lines = Lines::from(columns).lines;
+
+        println!("{}", lines.join("\n"));
+
+        // The weekday header, and 6 weeks in January 2023 which is the tallest.
+        assert_eq!(lines.len(), 7);
+        assert!(lines
+            .iter()
+            .all(|line| visible_width(line) == 20 * 3 + 3 * 2));
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::{NaiveDate, Weekday};
+use conch_ansi::Modifier;
+use conch_calendar::{regions, CalendarMonth, CalendarMonthConfig};
+
+fn march() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2023, 3, 3).unwrap()
+}
+
+#[cfg(test)]
+mod test_apply_config {
+    use super::*;
+
+    #[test]
+    fn empty_config_keeps_defaults() {
+        let calendar = CalendarMonth::<regions::England>::from_config(
+            march(),
+            &CalendarMonthConfig::default(),
+        );
+
+        assert_eq!(
+            Vec::<String>::from(calendar),
+            Vec::<String>::from(CalendarMonth::<regions::England>::new(march()))
+        );
+    }
+
+    #[test]
+    fn config_matches_chained_methods() {
+        let today = NaiveDate::from_ymd_opt(2023, 3, 8).unwrap();
+        let config = CalendarMonthConfig {
+            starts_week_with: Some(Weekday::Sun),
+            show_title: Some(false),
+            show_other_months: Some(true),
+            capitalize_title: Some(false),
+            title_modifier: Some(Modifier::Nothing),
+            weekday_modifier: Some(Modifier::colour("White").unwrap()),
+            holiday_modifier: Some(Modifier::colour("BrightBlue").unwrap()),
+            other_month_modifier: Some(Modifier::colour("Grayscale04").unwrap()),
+            today_modifier: Some(Modifier::intensity("Bold").unwrap()),
+            decorated_days: [(today, Modifier::background("Red").unwrap())]
+                .into_iter()
+                .collect(),
+        };
+
+        let chained = CalendarMonth::<regions::England>::new(march())
+            .starts_week_with(Weekday::Sun)
+            .show_title(false)
+            .show_other_months(true)
+            .capitalize_title(false)
+            .modify_title(Modifier::Nothing)
+            .modify_weekdays(Modifier::colour("White").unwrap())
+            .modify_holidays(Modifier::colour("BrightBlue").unwrap())
+            .modify_other_months(Modifier::colour("Grayscale04").unwrap())
+            .modify_today(Some(Modifier::intensity("Bold").unwrap()))
+            .decorate_day(today, Modifier::background("Red").unwrap());
+
+        let configured = CalendarMonth::<regions::England>::from_config(march(), &config);
+
+        assert_eq!(configured.to_config(), config);
+        assert_eq!(chained.to_config(), config);
+        assert_eq!(
+            Vec::<String>::from(configured),
+            Vec::<String>::from(chained)
+        );
+    }
+
+    #[test]
+    fn days_outside_of_month_are_ignored() {
+        let config = CalendarMonthConfig {
+            decorated_days: [(
+                NaiveDate::from_ymd_opt(2023, 4, 1).unwrap(),
+                Modifier::intensity("Bold").unwrap(),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let calendar = CalendarMonth::<regions::England>::from_config(march(), &config);
+
+        assert!(calendar.decorated_days.is_empty());
+    }
+}
+
+#[cfg(feature = "serde")]
+mod test_serde {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let config: CalendarMonthConfig = serde_json::from_str(
+            r#"{
+                "starts_week_with": "Sun",
+                "show_other_months": true,
+                "holiday_modifier": "Bold fg:BrightBlue",
+                "decorated_days": {"2023-03-08": "bg:Red"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            CalendarMonthConfig {
+                starts_week_with: Some(Weekday::Sun),
+                show_other_months: Some(true),
+                holiday_modifier: Some(
+                    Modifier::intensity("Bold").unwrap() + Modifier::colour("BrightBlue").unwrap()
+                ),
+                decorated_days: [(
+                    NaiveDate::from_ymd_opt(2023, 3, 8).unwrap(),
+                    Modifier::background("Red").unwrap()
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let config = CalendarMonth::<regions::England>::new(march()).to_config();
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"starts_week_with":"Mon","show_title":true,"show_other_months":false,"#,
+                r#""capitalize_title":true,"title_modifier":"Bold","weekday_modifier":"none","#,
+                r#""holiday_modifier":"fg:BrightRed Bold","other_month_modifier":"fg:Grayscale13"}"#,
+            )
+        );
+        assert_eq!(
+            serde_json::from_str::<CalendarMonthConfig>(&json).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn unknown_field() {
+        let error =
+            serde_json::from_str::<CalendarMonthConfig>(r#"{"show_weekends": true}"#).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("unknown field `show_weekends`"));
+    }
+}
@@ -6,20 +6,21 @@ macro_rules! ansi_enum_builder {
         use conch_base_models::*;
 
         impl fmt::Display for $enum_name {
-            /// Transform the object into ANSIEscapeCode, then use that to generate
-            /// a String.
+            /// Transform the object into ANSIEscapeCode, then write that into the
+            /// formatter.
             ///
             /// This also implements Display.
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 let ansi: ANSIEscapeCode = self.into();
 
-                write!(f, "{}", ansi.to_string())
+                fmt::Display::fmt(&ansi, f)
             }
         }
 
         impl HasLength for $enum_name {
+            /// Length of the escape code, calculated without building the [`String`].
             fn len(&self) -> usize {
-                self.to_string().len()
+                ANSIEscapeCode::from(self).len()
             }
         }
 
@@ -36,9 +37,10 @@ macro_rules! ansi_enum_builder {
         impl TryFrom<&str> for $enum_name {
             type Error = ModifierError;
 
-            /// Use ANSIEscapeCode to parse the str first, then select variant of itself if successful.
+            /// Use ANSIEscapeCodeRef to parse the str first, then select variant of itself if
+            /// successful; any error is located within `value`.
             fn try_from(value: &str) -> Result<Self, Self::Error> {
-                ANSIEscapeCode::try_from(value).and_then(|ansi| $enum_name::try_from(&ansi))
+                ANSIEscapeCodeRef::parse(value).and_then(|code| code.try_convert())
             }
         }
     };
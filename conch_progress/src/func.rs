@@ -0,0 +1,73 @@
+//! Helper functions for formatting progress for humans.
+
+use std::time::Duration;
+
+/// Format a duration with its two largest units, such as `42s`, `3m07s`, `2h05m`
+/// or `1d04h`.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use conch_progress::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_millis(9_500)), "9s");
+/// assert_eq!(format_duration(Duration::from_secs(187)), "3m07s");
+/// assert_eq!(format_duration(Duration::from_secs(93_600)), "1d02h");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    match duration.as_secs() {
+        secs if secs < 60 => format!("{}s", secs),
+        secs if secs < 3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+        secs if secs < 86400 => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+        secs => format!("{}d{:02}h", secs / 86400, secs % 86400 / 3600),
+    }
+}
+
+/// Format a count with its thousands separated by commas, such as `1,234,567`.
+///
+/// ```rust
+/// use conch_progress::format_count;
+///
+/// assert_eq!(format_count(999), "999");
+/// assert_eq!(format_count(1_234_567), "1,234,567");
+/// ```
+pub fn format_count(count: u64) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+
+    digits.chars().enumerate().for_each(|(idx, digit)| {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    });
+
+    formatted
+}
+
+/// Format a number of bytes in binary units, such as `512 B`, `1.5 KiB` or
+/// `3.0 MiB`.
+///
+/// ```rust
+/// use conch_progress::format_bytes;
+///
+/// assert_eq!(format_bytes(512), "512 B");
+/// assert_eq!(format_bytes(1536), "1.5 KiB");
+/// assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+/// ```
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", size, UNITS[unit])
+}
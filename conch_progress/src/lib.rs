@@ -1 +1,9 @@
 //! Progress Bar implementation as an [`Iterator`].
+mod structs;
+pub use structs::*;
+
+mod traits;
+pub use traits::*;
+
+mod func;
+pub use func::*;
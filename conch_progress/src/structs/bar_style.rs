@@ -0,0 +1,170 @@
+use conch_ansi::Modifier;
+use conch_base_models::StringWrapper;
+
+/// The characters and [`Modifier`]s of the bar of a [`crate::ProgressBar`].
+///
+/// The bar is made of a filled part, an optional head at the end of it, and an empty
+/// part for the rest; each of the characters is expected to be one column wide.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_progress::BarStyle;
+///
+/// let style = BarStyle::new().width(10).filled("=").head(">").empty(" ");
+///
+/// assert_eq!(style.render(0.0), ">         ");
+/// assert_eq!(style.render(0.45), "====>     ");
+/// assert_eq!(style.render(1.0), "==========");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarStyle {
+    width: usize,
+    filled: String,
+    head: Option<String>,
+    empty: String,
+    pulse: Option<usize>,
+    filled_modifier: Modifier,
+    head_modifier: Modifier,
+    empty_modifier: Modifier,
+}
+impl BarStyle {
+    /// Create a new [`BarStyle`] 40 columns wide, filled by `█` and emptied by `░`.
+    pub fn new() -> Self {
+        Self {
+            width: 40,
+            filled: String::from("█"),
+            head: None,
+            empty: String::from("░"),
+            pulse: None,
+            filled_modifier: Modifier::Nothing,
+            head_modifier: Modifier::Nothing,
+            empty_modifier: Modifier::Nothing,
+        }
+    }
+
+    /// Create a new [`BarStyle`] that only uses ASCII characters, such as
+    /// `[#####-----]`.
+    pub fn ascii() -> Self {
+        Self::new().filled("#").empty("-")
+    }
+
+    /// A chained function to set the width of the bar in columns
+    /// on an instance.
+    pub fn width(mut self, value: usize) -> Self {
+        self.width = value;
+        self
+    }
+
+    /// A chained function to set the character of the filled part
+    /// on an instance.
+    pub fn filled<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.filled = value.to_string();
+        self
+    }
+
+    /// A chained function to set the character at the end of the filled part,
+    /// which is left out once the bar is full, on an instance.
+    pub fn head<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.head = Some(value.to_string());
+        self
+    }
+
+    /// A chained function to set the character of the empty part
+    /// on an instance.
+    pub fn empty<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.empty = value.to_string();
+        self
+    }
+
+    /// A chained function to set the width of the filled segment bouncing across
+    /// the bar while its length is not known on an instance.
+    ///
+    /// Without it, such a bar is drawn empty.
+    pub fn pulse(mut self, value: usize) -> Self {
+        self.pulse = Some(value);
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the filled part
+    /// on an instance.
+    pub fn filled_modifier(mut self, value: Modifier) -> Self {
+        self.filled_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the head
+    /// on an instance.
+    pub fn head_modifier(mut self, value: Modifier) -> Self {
+        self.head_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the empty part
+    /// on an instance.
+    pub fn empty_modifier(mut self, value: Modifier) -> Self {
+        self.empty_modifier = value;
+        self
+    }
+
+    /// Render a bar filled up to `fraction` of its width, which is clamped between
+    /// `0.0` and `1.0`.
+    pub fn render(&self, fraction: f64) -> String {
+        let fraction = if fraction.is_nan() {
+            0.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        };
+        let filled = ((fraction * self.width as f64).floor() as usize).min(self.width);
+        let head = self.head.as_ref().filter(|_| filled < self.width);
+        let empty = self.width - filled - usize::from(head.is_some());
+
+        self.filled_modifier
+            .wraps_non_empty(&self.filled.repeat(filled))
+            + &head.map_or(String::new(), |head| {
+                self.head_modifier.wraps_non_empty(head)
+            })
+            + &self
+                .empty_modifier
+                .wraps_non_empty(&self.empty.repeat(empty))
+    }
+
+    /// Render an indeterminate bar after `ticks` ticks, with its pulse segment
+    /// bouncing back and forth, or an empty bar if it has no pulse.
+    pub fn render_pulse(&self, ticks: u64) -> String {
+        let Some(pulse) = self.pulse else {
+            return self.render(0.0);
+        };
+
+        let pulse = pulse.clamp(1, self.width.max(1)).min(self.width);
+        let span = (self.width - pulse) as u64;
+        let start = match ticks % (2 * span).max(1) {
+            tick if tick <= span => tick,
+            tick => 2 * span - tick,
+        } as usize;
+
+        self.empty_modifier
+            .wraps_non_empty(&self.empty.repeat(start))
+            + &self
+                .filled_modifier
+                .wraps_non_empty(&self.filled.repeat(pulse))
+            + &self
+                .empty_modifier
+                .wraps_non_empty(&self.empty.repeat(self.width - start - pulse))
+    }
+}
+impl Default for BarStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
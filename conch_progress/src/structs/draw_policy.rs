@@ -0,0 +1,92 @@
+use std::env;
+use std::ffi::OsStr;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+
+/// How progress indicators are drawn.
+///
+/// Bars drawing to `stderr` choose their policy with [`Self::detect()`], while bars
+/// drawing to another writer redraw in place at every change; either can be
+/// overridden with [`crate::ProgressBar::draw_policy()`] or
+/// [`crate::MultiProgress::draw_policy()`].
+///
+/// Whatever the policy, finishing a bar always draws it one last time.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use conch_progress::{DrawPolicy, ManualClock, ProgressBar};
+///
+/// let clock = ManualClock::new();
+/// let bar = ProgressBar::new(1000)
+///     .clock(clock.clone())
+///     .draw_policy(DrawPolicy::Lines { interval: Duration::ZERO })
+///     .target(std::io::sink());
+///
+/// clock.advance(Duration::from_secs(7));
+/// bar.inc(420);
+/// assert_eq!(bar.snapshot(), "42% (420/1000) eta 9s");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DrawPolicy {
+    /// Redraw the indicators in place, at most `max_hz` times per second, or at
+    /// every change if `max_hz` is `0`.
+    InPlace { max_hz: u32 },
+
+    /// Print a snapshot of each indicator on a line of its own, such as
+    /// `42% (420/1000) eta 3s`, at most once every `interval`.
+    ///
+    /// This suits logs and terminals that cannot move the cursor.
+    Lines { interval: Duration },
+
+    /// Draw nothing.
+    Silent,
+}
+impl DrawPolicy {
+    /// Redraws per second of [`Self::InPlace`] when detected.
+    pub const DEFAULT_MAX_HZ: u32 = 20;
+
+    /// Time between snapshots of [`Self::Lines`] when detected.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// Choose a policy from the environment: [`Self::InPlace`] if both `stdout` and
+    /// `stderr` are terminals and colours are not disabled through `NO_COLOR` or
+    /// `TERM=dumb`, or [`Self::Lines`] otherwise.
+    pub fn detect() -> Self {
+        Self::detect_from(
+            io::stdout().is_terminal() && io::stderr().is_terminal(),
+            env::var_os("NO_COLOR").as_deref(),
+            env::var_os("TERM").as_deref(),
+        )
+    }
+
+    /// Choose a policy like [`Self::detect()`], from whether the output is a
+    /// terminal and the values of the `NO_COLOR` and `TERM` environment variables.
+    pub fn detect_from(is_terminal: bool, no_color: Option<&OsStr>, term: Option<&OsStr>) -> Self {
+        let no_colour = no_color.is_some_and(|value| !value.is_empty());
+        let dumb = term.is_some_and(|term| term == "dumb");
+
+        if is_terminal && !no_colour && !dumb {
+            Self::InPlace {
+                max_hz: Self::DEFAULT_MAX_HZ,
+            }
+        } else {
+            Self::Lines {
+                interval: Self::DEFAULT_INTERVAL,
+            }
+        }
+    }
+
+    /// Shortest time between two draws.
+    pub(crate) fn interval(&self) -> Duration {
+        match self {
+            Self::InPlace { max_hz: 0 } => Duration::ZERO,
+            Self::InPlace { max_hz } => Duration::from_secs(1) / *max_hz,
+            Self::Lines { interval } => *interval,
+            Self::Silent => Duration::MAX,
+        }
+    }
+}
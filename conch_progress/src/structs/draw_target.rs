@@ -0,0 +1,168 @@
+//! Drawing progress indicators in place on a terminal.
+
+use std::io::{self, Write};
+
+use conch_ansi::MoveCursor;
+
+use crate::DrawPolicy;
+
+/// Escape code that clears the line from the cursor to its end.
+pub(crate) const CLEAR_LINE: &str = "\x1b[K";
+
+/// A writer that progress indicators are drawn to, keeping track of how many lines
+/// are on screen so that they can be drawn over.
+///
+/// After drawing, the cursor is left at the end of the last line drawn; the next
+/// drawing moves it back up to the first line with [`MoveCursor`] and a carriage
+/// return.
+pub(crate) struct DrawTarget {
+    writer: Box<dyn Write + Send>,
+
+    /// The policy of indicators drawn here, unless they are given another.
+    policy: DrawPolicy,
+
+    /// Number of lines currently on screen.
+    drawn: usize,
+}
+impl DrawTarget {
+    pub(crate) fn new<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self {
+            writer: Box::new(writer),
+            policy: DrawPolicy::InPlace { max_hz: 0 },
+            drawn: 0,
+        }
+    }
+
+    /// Create a new [`DrawTarget`] writing to `stderr`, with a policy detected from
+    /// the environment.
+    pub(crate) fn stderr() -> Self {
+        Self {
+            policy: DrawPolicy::detect(),
+            ..Self::new(io::stderr())
+        }
+    }
+
+    /// The policy of indicators drawn here, unless they are given another.
+    pub(crate) fn policy(&self) -> DrawPolicy {
+        self.policy
+    }
+
+    /// Escape codes that move the cursor back to the start of the first line on
+    /// screen.
+    fn rewind(&self) -> String {
+        match self.drawn {
+            0 | 1 => String::from("\r"),
+            drawn => MoveCursor::Up((drawn - 1) as i32).to_string() + "\r",
+        }
+    }
+
+    fn write(&mut self, output: &str) -> io::Result<()> {
+        self.writer.write_all(output.as_bytes())?;
+        self.writer.flush()
+    }
+
+    /// Draw `lines` over the ones on screen.
+    pub(crate) fn draw(&mut self, lines: &[String]) -> io::Result<()> {
+        if lines.is_empty() {
+            return self.clear();
+        }
+
+        let mut output = self.rewind();
+        lines.iter().enumerate().for_each(|(idx, line)| {
+            if idx > 0 {
+                output.push('\n');
+            }
+            output.push_str(line);
+            output.push_str(CLEAR_LINE);
+        });
+
+        // Clear any lines left over from a taller drawing, then come back up.
+        let extra = self.drawn.saturating_sub(lines.len());
+        if extra > 0 {
+            (0..extra).for_each(|_| {
+                output.push('\n');
+                output.push_str(CLEAR_LINE);
+            });
+            output.push_str(&MoveCursor::Up(extra as i32).to_string());
+        }
+
+        self.drawn = lines.len();
+        self.write(&output)
+    }
+
+    /// Number of lines currently on screen.
+    pub(crate) fn drawn(&self) -> usize {
+        self.drawn
+    }
+
+    /// Draw `line` over the line at `row` among the ones on screen, leaving the
+    /// others untouched.
+    ///
+    /// The cursor moves up to the line relatively, and back down to the last line
+    /// afterwards.
+    pub(crate) fn update(&mut self, row: usize, line: &str) -> io::Result<()> {
+        debug_assert!(row < self.drawn, "row {row} is not on screen");
+
+        let up = self.drawn.saturating_sub(row + 1) as i32;
+        let mut output = String::new();
+        if up > 0 {
+            output.push_str(&MoveCursor::Up(up).to_string());
+        }
+        output.push('\r');
+        output.push_str(line);
+        output.push_str(CLEAR_LINE);
+        if up > 0 {
+            output.push_str(&MoveCursor::Down(up).to_string());
+        }
+
+        self.write(&output)
+    }
+
+    /// Print `line` below anything on screen, for good.
+    pub(crate) fn println(&mut self, line: &str) -> io::Result<()> {
+        self.leave()?;
+        self.write(&format!("{}\n", line))
+    }
+
+    /// Clear all the lines on screen, leaving the cursor at the start of the first.
+    pub(crate) fn clear(&mut self) -> io::Result<()> {
+        if self.drawn == 0 {
+            return Ok(());
+        }
+
+        let mut output = self.rewind() + CLEAR_LINE;
+        (1..self.drawn).for_each(|_| {
+            output.push('\n');
+            output.push_str(CLEAR_LINE);
+        });
+        if self.drawn > 1 {
+            output.push_str(&MoveCursor::Up((self.drawn - 1) as i32).to_string());
+            output.push('\r');
+        }
+
+        self.drawn = 0;
+        self.write(&output)
+    }
+
+    /// Leave the lines on screen as they are, moving the cursor to a new line below
+    /// them.
+    pub(crate) fn leave(&mut self) -> io::Result<()> {
+        if self.drawn == 0 {
+            return Ok(());
+        }
+
+        self.drawn = 0;
+        self.write("\n")
+    }
+}
+impl std::fmt::Debug for DrawTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DrawTarget")
+            .field("policy", &self.policy)
+            .field("drawn", &self.drawn)
+            .finish()
+    }
+}
@@ -0,0 +1,27 @@
+mod bar_style;
+pub use bar_style::*;
+mod draw_policy;
+pub use draw_policy::*;
+mod draw_target;
+mod manual_clock;
+pub use manual_clock::*;
+mod multi_progress;
+pub use multi_progress::*;
+mod progress_bar;
+pub use progress_bar::*;
+mod progress_bar_iter;
+pub use progress_bar_iter::*;
+mod progress_reader;
+pub use progress_reader::*;
+mod progress_writer;
+pub use progress_writer::*;
+mod rate_estimator;
+pub use rate_estimator::*;
+mod spinner_style;
+pub use spinner_style::*;
+mod system_clock;
+pub use system_clock::*;
+mod template;
+pub use template::*;
+mod template_error;
+pub use template_error::*;
@@ -0,0 +1,300 @@
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use super::draw_target::DrawTarget;
+use super::progress_bar::BarTarget;
+use crate::{DrawPolicy, ProgressBar, ProgressFinish};
+
+/// A line of a [`MultiProgress`], drawn by one of its bars.
+#[derive(Debug)]
+struct Slot {
+    id: usize,
+    line: String,
+    finished: bool,
+}
+
+/// The state shared by all the handles of a [`MultiProgress`], and by the bars
+/// added to it.
+///
+/// Bars lock their own state before this one, never the other way around.
+pub(crate) struct MultiState {
+    target: DrawTarget,
+    slots: Vec<Slot>,
+    next_id: usize,
+    collapse: bool,
+    policy: Option<DrawPolicy>,
+
+    /// Number of finished bars collapsed into the summary line.
+    collapsed: usize,
+    summary: Box<dyn Fn(usize) -> String + Send>,
+}
+impl MultiState {
+    /// Lock the shared state; like for bars, poisoning is ignored.
+    pub(crate) fn lock(state: &Mutex<Self>) -> MutexGuard<'_, Self> {
+        state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The policy to draw with, given or the one of the target.
+    pub(crate) fn policy(&self) -> DrawPolicy {
+        self.policy.unwrap_or(self.target.policy())
+    }
+
+    /// All the lines to be on screen: the summary line, if any bar was collapsed
+    /// into it, then one line per bar.
+    fn lines(&self) -> Vec<String> {
+        (self.collapsed > 0)
+            .then(|| (self.summary)(self.collapsed))
+            .into_iter()
+            .chain(self.slots.iter().map(|slot| slot.line.clone()))
+            .collect()
+    }
+
+    /// Draw all the lines over the ones on screen, if drawing in place.
+    ///
+    /// Once every bar is finished, the lines are left on screen for good and
+    /// forgotten, so that bars added afterwards are drawn below them.
+    fn redraw(&mut self) -> io::Result<()> {
+        let done = self.slots.iter().all(|slot| slot.finished);
+
+        if let DrawPolicy::InPlace { .. } = self.policy() {
+            let lines = self.lines();
+            self.target.draw(&lines)?;
+            if done && !lines.is_empty() {
+                self.target.leave()?;
+            }
+        }
+
+        if done {
+            self.slots.clear();
+            self.collapsed = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Add a line for a new bar, returning its id.
+    fn add(&mut self, line: String, finished: bool) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.slots.push(Slot { id, line, finished });
+
+        let _ = self.redraw();
+        id
+    }
+
+    /// Draw `line` for the bar `id`, redrawing only that line if the others are on
+    /// screen already.
+    pub(crate) fn update(&mut self, id: usize, line: &str) -> io::Result<()> {
+        let Some(idx) = self.slots.iter().position(|slot| slot.id == id) else {
+            return Ok(());
+        };
+        if self.slots[idx].line == line {
+            return Ok(());
+        }
+        self.slots[idx].line = line.to_string();
+
+        let row = idx + usize::from(self.collapsed > 0);
+        if self.target.drawn() == self.slots.len() + usize::from(self.collapsed > 0) {
+            self.target.update(row, line)
+        } else {
+            self.redraw()
+        }
+    }
+
+    /// Print `line` below the lines on screen, for good.
+    pub(crate) fn println(&mut self, line: &str) -> io::Result<()> {
+        self.target.println(line)
+    }
+
+    /// Finish the bar `id`, leaving its line, collapsing it into the summary line,
+    /// or clearing it.
+    pub(crate) fn finish(&mut self, id: usize, on_finish: ProgressFinish) -> io::Result<()> {
+        let Some(idx) = self.slots.iter().position(|slot| slot.id == id) else {
+            return Ok(());
+        };
+
+        match on_finish {
+            ProgressFinish::Leave if self.collapse => {
+                self.slots.remove(idx);
+                self.collapsed += 1;
+            }
+            ProgressFinish::Leave => self.slots[idx].finished = true,
+            ProgressFinish::Clear => {
+                self.slots.remove(idx);
+            }
+        }
+
+        self.redraw()
+    }
+
+    /// Take the line of the bar `id` off the screen.
+    pub(crate) fn remove(&mut self, id: usize) -> io::Result<()> {
+        let Some(idx) = self.slots.iter().position(|slot| slot.id == id) else {
+            return Ok(());
+        };
+        self.slots.remove(idx);
+
+        self.redraw()
+    }
+}
+impl std::fmt::Debug for MultiState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiState")
+            .field("target", &self.target)
+            .field("slots", &self.slots)
+            .field("collapse", &self.collapse)
+            .field("policy", &self.policy)
+            .field("collapsed", &self.collapsed)
+            .finish()
+    }
+}
+
+/// A container drawing several [`ProgressBar`]s at once, one per line.
+///
+/// Each bar added to a [`MultiProgress`] gets a line of its own, in the order they
+/// were added. When a bar changes, only its line is redrawn, moving the cursor up to
+/// it and back down; the whole block is only redrawn when bars are added or taken
+/// off. Bars can be updated from any thread, and added or removed at any time.
+///
+/// Finished bars are left on their lines, or cleared, according to their
+/// [`ProgressFinish`]. With [`Self::collapse_finished()`], the bars finished with
+/// [`ProgressFinish::Leave`] are instead collapsed into a single summary line above
+/// the others. Once every bar is finished, the lines are left on screen.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_progress::{BarStyle, MultiProgress, ProgressBar};
+///
+/// let multi = MultiProgress::new()
+///     .collapse_finished(true)
+///     .target(std::io::sink());
+///
+/// let first = multi.add(ProgressBar::new(2).style(BarStyle::ascii().width(4)));
+/// let second = multi.add(ProgressBar::new(4).style(BarStyle::ascii().width(4)));
+/// assert_eq!(multi.len(), 2);
+///
+/// first.inc(2);
+/// first.finish();
+/// second.inc(1);
+/// assert_eq!(multi.len(), 1);
+/// assert_eq!(multi.lines(), vec!["1 finished", "#--- 1/4  25%"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultiProgress {
+    state: Arc<Mutex<MultiState>>,
+}
+impl MultiProgress {
+    /// Create a new, empty [`MultiProgress`], drawn to `stderr`.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MultiState {
+                target: DrawTarget::stderr(),
+                slots: Vec::new(),
+                next_id: 0,
+                collapse: false,
+                policy: None,
+                collapsed: 0,
+                summary: Box::new(|count| format!("{} finished", count)),
+            })),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, MultiState> {
+        MultiState::lock(&self.state)
+    }
+
+    /// A chained function to set the writer to draw the bars to
+    /// on an instance.
+    pub fn target<W>(self, value: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        self.state().target = DrawTarget::new(value);
+        self
+    }
+
+    /// A chained function to set the [`DrawPolicy`] of all the bars, instead of the
+    /// one of the target, on an instance.
+    ///
+    /// With [`DrawPolicy::Lines`], each bar prints its own snapshots, and no summary
+    /// line is drawn.
+    pub fn draw_policy(self, value: DrawPolicy) -> Self {
+        self.state().policy = Some(value);
+        self
+    }
+
+    /// A chained function to set whether finished bars are collapsed into a summary
+    /// line on an instance.
+    pub fn collapse_finished(self, value: bool) -> Self {
+        self.state().collapse = value;
+        self
+    }
+
+    /// A chained function to set how the summary line is written from the number of
+    /// bars collapsed into it on an instance.
+    ///
+    /// By default, it reads like `3 finished`.
+    pub fn summary<F>(self, value: F) -> Self
+    where
+        F: Fn(usize) -> String + Send + 'static,
+    {
+        self.state().summary = Box::new(value);
+        self
+    }
+
+    /// Add `bar` on a new line below the others, returning it back.
+    ///
+    /// The bar is drawn on this [`MultiProgress`] from then on, instead of its own
+    /// target.
+    pub fn add(&self, bar: ProgressBar) -> ProgressBar {
+        {
+            let mut bar_state = bar.state();
+            let line = bar_state.render();
+            let id = self.state().add(line.clone(), bar_state.finished);
+
+            bar_state.detach(BarTarget::Multi {
+                multi: Arc::clone(&self.state),
+                id,
+            });
+            bar_state.drawn = Some(line);
+        }
+
+        bar
+    }
+
+    /// Take `bar` off this [`MultiProgress`], clearing its line.
+    ///
+    /// The bar is not drawn anywhere afterwards, unless given a new target. Bars that
+    /// were not added to this [`MultiProgress`] are left alone.
+    pub fn remove(&self, bar: &ProgressBar) {
+        let mut bar_state = bar.state();
+        if matches!(
+            &bar_state.target,
+            BarTarget::Multi { multi, .. } if Arc::ptr_eq(multi, &self.state)
+        ) {
+            bar_state.detach(BarTarget::Own(DrawTarget::new(io::sink())));
+        }
+    }
+
+    /// Number of bars drawn on their own line, not counting the summary line.
+    pub fn len(&self) -> usize {
+        self.state().slots.len()
+    }
+
+    /// Whether no bar is drawn.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The lines currently drawn, including the summary line.
+    pub fn lines(&self) -> Vec<String> {
+        self.state().lines()
+    }
+}
+impl Default for MultiProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,611 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use conch_split::terminal_width;
+
+use super::draw_target::DrawTarget;
+use super::multi_progress::MultiState;
+use super::spinner_style::Outcome;
+use crate::{
+    format_duration, BarStyle, Clock, DrawPolicy, RateEstimator, SpinnerStyle, SystemClock,
+    Template,
+};
+
+/// Time between two ticks when ticking steadily, for bars that are not spinners.
+const DEFAULT_TICK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// What a [`ProgressBar`] leaves on the terminal once it is finished.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ProgressFinish {
+    /// Leave the last state of the bar on screen, and move on to the next line.
+    #[default]
+    Leave,
+
+    /// Clear the bar off the screen.
+    Clear,
+}
+
+/// Where a [`ProgressBar`] is drawn.
+#[derive(Debug)]
+pub(crate) enum BarTarget {
+    /// On lines of its own.
+    Own(DrawTarget),
+
+    /// On a line of a [`crate::MultiProgress`], identified by `id`.
+    Multi {
+        multi: Arc<Mutex<MultiState>>,
+        id: usize,
+    },
+}
+
+/// The state shared by all the handles of a [`ProgressBar`].
+#[derive(Debug)]
+pub(crate) struct BarState {
+    pub(crate) position: u64,
+    pub(crate) length: Option<u64>,
+    pub(crate) prefix: String,
+    pub(crate) message: String,
+    pub(crate) style: BarStyle,
+    spinner: Option<SpinnerStyle>,
+    template: Option<Template>,
+    width: Option<usize>,
+    clock: Arc<dyn Clock>,
+    started: Instant,
+    estimator: RateEstimator,
+    on_finish: ProgressFinish,
+    pub(crate) target: BarTarget,
+    pub(crate) finished: bool,
+    outcome: Option<Outcome>,
+
+    /// Number of ticks so far, advancing spinners and pulses.
+    ticks: u64,
+    ticking: bool,
+
+    /// The policy to draw with, instead of the one of the target.
+    policy: Option<DrawPolicy>,
+    drawn_at: Option<Instant>,
+
+    /// The line last drawn, to avoid drawing the same line again.
+    pub(crate) drawn: Option<String>,
+}
+impl BarState {
+    /// Fraction of the bar that is done; `0.0` if the length is not known.
+    pub(crate) fn fraction(&self) -> f64 {
+        match self.length {
+            Some(0) => 1.0,
+            Some(length) => self.position as f64 / length as f64,
+            None => 0.0,
+        }
+    }
+
+    /// Percentage of the bar that is done, if the length is known.
+    pub(crate) fn percent(&self) -> Option<u64> {
+        self.length
+            .map(|_| (self.fraction().min(1.0) * 100.0).floor() as u64)
+    }
+
+    /// Time since the bar was created.
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.clock.now().saturating_duration_since(self.started)
+    }
+
+    /// Estimated number of steps per second.
+    pub(crate) fn rate(&self) -> f64 {
+        self.estimator.rate(self.clock.now()).unwrap_or(0.0)
+    }
+
+    /// Estimated time left at the estimated rate, if it can be estimated.
+    pub(crate) fn eta(&self) -> Option<Duration> {
+        let left = self.length?.saturating_sub(self.position);
+        self.estimator.eta(self.clock.now(), left)
+    }
+
+    /// Move to `position`, recording it for the estimated rate.
+    fn set_position(&mut self, position: u64) {
+        self.position = position;
+        self.estimator.record(self.clock.now(), position);
+    }
+
+    /// Render `style` for the current state: filled up to the position, or pulsing
+    /// if the length is not known.
+    pub(crate) fn render_bar(&self, style: &BarStyle) -> String {
+        match self.length {
+            Some(_) => style.render(self.fraction()),
+            None => style.render_pulse(self.ticks),
+        }
+    }
+
+    /// Render the current frame of the spinner, if this is one.
+    pub(crate) fn render_spinner(&self) -> String {
+        self.spinner.as_ref().map_or_else(String::new, |spinner| {
+            spinner.render(self.ticks, self.outcome)
+        })
+    }
+
+    /// Render the current state into a line.
+    pub(crate) fn render(&self) -> String {
+        if let Some(template) = &self.template {
+            let width = self.width.unwrap_or_else(terminal_width);
+            return template.render(self, width);
+        }
+
+        if self.spinner.is_some() {
+            return [self.render_spinner().as_str(), &self.prefix, &self.message]
+                .iter()
+                .filter(|part| !part.is_empty())
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+
+        let counts = match (self.length, self.percent()) {
+            (Some(length), Some(percent)) => {
+                format!("{}/{} {:>3}%", self.position, length, percent)
+            }
+            _ => self.position.to_string(),
+        };
+
+        [
+            self.prefix.as_str(),
+            &self.render_bar(&self.style),
+            &counts,
+            &self.message,
+        ]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+
+    /// Render the current state into a snapshot for [`DrawPolicy::Lines`], such as
+    /// `42% (420/1000) eta 3s`.
+    pub(crate) fn snapshot(&self) -> String {
+        let symbol = match (&self.spinner, self.outcome) {
+            (Some(spinner), Some(outcome)) => spinner.symbol(outcome),
+            _ => "",
+        };
+        let counts = match (self.length, self.percent()) {
+            (Some(length), Some(percent)) => {
+                format!("{}% ({}/{})", percent, self.position, length)
+            }
+            _ if self.spinner.is_some() => String::new(),
+            _ => self.position.to_string(),
+        };
+        let eta = self
+            .eta()
+            .filter(|eta| !eta.is_zero())
+            .map_or_else(String::new, |eta| format!("eta {}", format_duration(eta)));
+
+        [symbol, &self.prefix, &counts, &eta, &self.message]
+            .iter()
+            .filter(|part| !part.is_empty())
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// The policy to draw with: the one of the [`crate::MultiProgress`] the bar is
+    /// on, or its own, or the one of its target.
+    fn policy(&self) -> DrawPolicy {
+        match &self.target {
+            BarTarget::Own(target) => self.policy.unwrap_or(target.policy()),
+            BarTarget::Multi { multi, .. } => MultiState::lock(multi).policy(),
+        }
+    }
+
+    /// Draw the current state according to the [`DrawPolicy`], unless it is the same
+    /// as what was drawn last.
+    ///
+    /// Unless `force`d, nothing is drawn until the interval of the policy has passed
+    /// since the last draw.
+    ///
+    /// Progress bars are only decorations, so failing to draw them is not allowed to
+    /// interrupt the work they are tracking; errors are ignored.
+    fn draw(&mut self, force: bool) {
+        if self.finished {
+            return;
+        }
+
+        let policy = self.policy();
+        let now = self.clock.now();
+        let throttled = self
+            .drawn_at
+            .is_some_and(|drawn_at| now.saturating_duration_since(drawn_at) < policy.interval());
+        if policy == DrawPolicy::Silent || (throttled && !force) {
+            return;
+        }
+
+        let line = match policy {
+            DrawPolicy::Lines { .. } => self.snapshot(),
+            _ => self.render(),
+        };
+        if self.drawn.as_ref() == Some(&line) {
+            return;
+        }
+
+        let _ = match (&mut self.target, policy) {
+            (BarTarget::Own(target), DrawPolicy::Lines { .. }) => target.println(&line),
+            (BarTarget::Own(target), _) => target.draw(std::slice::from_ref(&line)),
+            (BarTarget::Multi { multi, .. }, DrawPolicy::Lines { .. }) => {
+                MultiState::lock(multi).println(&line)
+            }
+            (BarTarget::Multi { multi, id }, _) => MultiState::lock(multi).update(*id, &line),
+        };
+        self.drawn = Some(line);
+        self.drawn_at = Some(now);
+    }
+
+    /// Move the bar over to `target`, taking it off the [`crate::MultiProgress`] it
+    /// was drawn on, if any.
+    pub(crate) fn detach(&mut self, target: BarTarget) {
+        if let BarTarget::Multi { multi, id } = std::mem::replace(&mut self.target, target) {
+            let _ = MultiState::lock(&multi).remove(id);
+        }
+        self.drawn = None;
+    }
+
+    /// Time between two ticks when ticking steadily.
+    fn tick_interval(&self) -> Duration {
+        self.spinner
+            .as_ref()
+            .map_or(DEFAULT_TICK_INTERVAL, SpinnerStyle::tick_interval)
+    }
+
+    fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        self.draw(true);
+        self.finished = true;
+        let _ = match (&mut self.target, self.on_finish) {
+            (BarTarget::Own(target), ProgressFinish::Leave) => target.leave(),
+            (BarTarget::Own(target), ProgressFinish::Clear) => target.clear(),
+            (BarTarget::Multi { multi, id }, on_finish) => {
+                MultiState::lock(multi).finish(*id, on_finish)
+            }
+        };
+    }
+}
+impl Drop for BarState {
+    /// Clean up the bar once the last handle to it is dropped.
+    fn drop(&mut self) {
+        self.finish()
+    }
+}
+
+/// A progress bar drawn in place on the terminal, such as
+/// `███████░░░ 70/100  70%`.
+///
+/// A [`ProgressBar`] is a handle: clones of it share the same bar, so that it can be
+/// updated from wherever the work is done, including other threads. It is redrawn
+/// whenever its rendered line changes, as often as its [`DrawPolicy`] allows, and
+/// cleaned up according to its [`ProgressFinish`] when it is finished, or when the
+/// last handle to it is dropped.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_progress::{BarStyle, ProgressBar};
+///
+/// let bar = ProgressBar::new(4)
+///     .prefix("Copying")
+///     .style(BarStyle::ascii().width(8))
+///     .target(std::io::sink());
+///
+/// bar.inc(3);
+/// assert_eq!(bar.render(), "Copying ######-- 3/4  75%");
+///
+/// bar.set_message("almost there");
+/// assert_eq!(bar.render(), "Copying ######-- 3/4  75% almost there");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProgressBar {
+    state: Arc<Mutex<BarState>>,
+}
+impl ProgressBar {
+    /// Create a new [`ProgressBar`] of `length` steps, drawn to `stderr`.
+    pub fn new(length: u64) -> Self {
+        Self::with_length(Some(length))
+    }
+
+    /// Create a new [`ProgressBar`] whose length is not known yet, drawn to
+    /// `stderr`.
+    pub fn without_length() -> Self {
+        Self::with_length(None)
+    }
+
+    fn with_length(length: Option<u64>) -> Self {
+        let now = SystemClock.now();
+
+        Self {
+            state: Arc::new(Mutex::new(BarState {
+                position: 0,
+                length,
+                prefix: String::new(),
+                message: String::new(),
+                style: BarStyle::default(),
+                spinner: None,
+                template: None,
+                width: None,
+                clock: Arc::new(SystemClock),
+                started: now,
+                estimator: RateEstimator::new(now, 0),
+                on_finish: ProgressFinish::default(),
+                target: BarTarget::Own(DrawTarget::stderr()),
+                finished: false,
+                outcome: None,
+                ticks: 0,
+                ticking: false,
+                policy: None,
+                drawn_at: None,
+                drawn: None,
+            })),
+        }
+    }
+
+    /// Create a new spinner drawn to `stderr`: a [`ProgressBar`] without a length,
+    /// drawn as a [`SpinnerStyle`] followed by its prefix and message.
+    ///
+    /// Spinners move on at every [`Self::tick()`], or on their own with
+    /// [`Self::enable_steady_tick()`].
+    pub fn spinner() -> Self {
+        Self::without_length().spinner_style(SpinnerStyle::default())
+    }
+
+    /// Lock the shared state; a thread panicking while holding the lock leaves the
+    /// state consistent, so poisoning is ignored.
+    pub(crate) fn state(&self) -> MutexGuard<'_, BarState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// A chained function to set the [`BarStyle`]
+    /// on an instance.
+    pub fn style(self, value: BarStyle) -> Self {
+        self.state().style = value;
+        self
+    }
+
+    /// A chained function to set the [`SpinnerStyle`], drawing the bar as a spinner,
+    /// on an instance.
+    pub fn spinner_style(self, value: SpinnerStyle) -> Self {
+        self.state().spinner = Some(value);
+        self
+    }
+
+    /// A chained function to lay out the line with a [`Template`] instead of the
+    /// default layout on an instance.
+    pub fn template(self, value: Template) -> Self {
+        self.state().template = Some(value);
+        self
+    }
+
+    /// A chained function to set the width of the line that a [`Template`] lays out
+    /// on an instance.
+    ///
+    /// Without a width, the line is as wide as the terminal.
+    pub fn width(self, value: usize) -> Self {
+        self.state().width = Some(value);
+        self
+    }
+
+    /// A chained function to set the text in front of the bar
+    /// on an instance.
+    pub fn prefix<S>(self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.state().prefix = value.to_string();
+        self
+    }
+
+    /// A chained function to set the message after the bar
+    /// on an instance.
+    pub fn message<S>(self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.state().message = value.to_string();
+        self
+    }
+
+    /// A chained function to set the [`Clock`] measuring elapsed times and rates
+    /// on an instance.
+    ///
+    /// This starts the bar over from the current time of `value`, forgetting the
+    /// rate estimated so far.
+    pub fn clock<C>(self, value: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        {
+            let mut state = self.state();
+            let now = value.now();
+            state.clock = Arc::new(value);
+            state.started = now;
+            state.estimator = RateEstimator::new(now, state.position);
+        }
+        self
+    }
+
+    /// A chained function to set the [`DrawPolicy`], instead of the one of the
+    /// target, on an instance.
+    ///
+    /// Bars on a [`crate::MultiProgress`] are drawn with its policy instead.
+    pub fn draw_policy(self, value: DrawPolicy) -> Self {
+        self.state().policy = Some(value);
+        self
+    }
+
+    /// A chained function to set the [`ProgressFinish`]
+    /// on an instance.
+    pub fn on_finish(self, value: ProgressFinish) -> Self {
+        self.state().on_finish = value;
+        self
+    }
+
+    /// A chained function to set the writer to draw the bar to
+    /// on an instance.
+    ///
+    /// The bar is then redrawn in place at every change, unless given a
+    /// [`DrawPolicy`]. This takes the bar out of any [`crate::MultiProgress`] it was
+    /// added to.
+    pub fn target<W>(self, value: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        self.state().detach(BarTarget::Own(DrawTarget::new(value)));
+        self
+    }
+
+    /// Current position of the bar.
+    pub fn position(&self) -> u64 {
+        self.state().position
+    }
+
+    /// Length of the bar, if it is known.
+    pub fn length(&self) -> Option<u64> {
+        self.state().length
+    }
+
+    /// Time since the bar was created.
+    pub fn elapsed(&self) -> Duration {
+        self.state().elapsed()
+    }
+
+    /// Estimated number of steps per second, as estimated by a [`RateEstimator`].
+    pub fn rate(&self) -> f64 {
+        self.state().rate()
+    }
+
+    /// Estimated time left, if the length is known and progress is being made.
+    pub fn eta(&self) -> Option<Duration> {
+        self.state().eta()
+    }
+
+    /// Whether the bar has been finished.
+    pub fn is_finished(&self) -> bool {
+        self.state().finished
+    }
+
+    /// Advance the bar by `delta` steps.
+    pub fn inc(&self, delta: u64) {
+        let mut state = self.state();
+        let position = state.position.saturating_add(delta);
+        state.set_position(position);
+        state.draw(false);
+    }
+
+    /// Move the bar to `position`.
+    pub fn set_position(&self, position: u64) {
+        let mut state = self.state();
+        state.set_position(position);
+        state.draw(false);
+    }
+
+    /// Set the length of the bar.
+    pub fn set_length(&self, length: u64) {
+        let mut state = self.state();
+        state.length = Some(length);
+        state.draw(false);
+    }
+
+    /// Replace the message after the bar.
+    pub fn set_message<S>(&self, message: S)
+    where
+        S: ToString,
+    {
+        let mut state = self.state();
+        state.message = message.to_string();
+        state.draw(false);
+    }
+
+    /// Move the spinner or the pulse of the bar on by one frame, and draw it.
+    pub fn tick(&self) {
+        let mut state = self.state();
+        state.ticks += 1;
+        state.draw(false);
+    }
+
+    /// Tick the bar from a background thread, at the interval of its
+    /// [`SpinnerStyle`], or every 100ms if it is not a spinner, until it is
+    /// finished.
+    ///
+    /// Enabling steady ticks again has no effect.
+    pub fn enable_steady_tick(&self) {
+        let mut state = self.state();
+        if state.ticking || state.finished {
+            return;
+        }
+        state.ticking = true;
+        let mut interval = state.tick_interval();
+        drop(state);
+
+        let weak = Arc::downgrade(&self.state);
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+
+            // Stop once every handle to the bar is dropped.
+            let Some(state) = weak.upgrade() else {
+                break;
+            };
+            let bar = Self { state };
+            if bar.is_finished() {
+                break;
+            }
+            bar.tick();
+            interval = bar.state().tick_interval();
+        });
+    }
+
+    /// Render the current state of the bar into a line, without drawing it.
+    pub fn render(&self) -> String {
+        self.state().render()
+    }
+
+    /// Render the current state of the bar into a snapshot, as printed with
+    /// [`DrawPolicy::Lines`], without drawing it.
+    pub fn snapshot(&self) -> String {
+        self.state().snapshot()
+    }
+
+    /// Finish the bar at its current position, drawing it one last time.
+    ///
+    /// Finishing a bar again has no effect, and so does updating it afterwards.
+    pub fn finish(&self) {
+        self.state().finish()
+    }
+
+    /// Finish the bar with `message`, showing the success symbol of its
+    /// [`SpinnerStyle`] in place of the spinner.
+    pub fn succeed<S>(&self, message: S)
+    where
+        S: ToString,
+    {
+        self.finish_with(Outcome::Success, message.to_string())
+    }
+
+    /// Finish the bar with `message`, showing the failure symbol of its
+    /// [`SpinnerStyle`] in place of the spinner.
+    pub fn fail<S>(&self, message: S)
+    where
+        S: ToString,
+    {
+        self.finish_with(Outcome::Failure, message.to_string())
+    }
+
+    fn finish_with(&self, outcome: Outcome, message: String) {
+        let mut state = self.state();
+        if state.finished {
+            return;
+        }
+
+        state.outcome = Some(outcome);
+        state.message = message;
+        state.finish()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::ProgressBar;
+
+/// An [`Iterator`] that advances a [`ProgressBar`] by one step for every item it
+/// yields, created by [`crate::ProgressIterator`].
+///
+/// The bar is finished once the iterator is exhausted, or when it is dropped before
+/// that, such as by breaking out of a loop early.
+#[derive(Debug)]
+pub struct ProgressBarIter<I> {
+    iter: I,
+    bar: ProgressBar,
+}
+impl<I> ProgressBarIter<I>
+where
+    I: Iterator,
+{
+    /// Create a new [`ProgressBarIter`], drawing `bar` straight away.
+    pub fn new(iter: I, bar: ProgressBar) -> Self {
+        bar.tick();
+        Self { iter, bar }
+    }
+
+    /// The [`ProgressBar`] advanced by this iterator.
+    pub fn bar(&self) -> &ProgressBar {
+        &self.bar
+    }
+}
+impl<I> Iterator for ProgressBarIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+
+        match item {
+            Some(_) => self.bar.inc(1),
+            None => self.bar.finish(),
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<I> ExactSizeIterator for ProgressBarIter<I> where I: ExactSizeIterator {}
+impl<I> Drop for ProgressBarIter<I> {
+    fn drop(&mut self) {
+        self.bar.finish()
+    }
+}
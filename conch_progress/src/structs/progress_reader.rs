@@ -0,0 +1,112 @@
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+use crate::ProgressBar;
+
+/// A [`Read`]er that advances a [`ProgressBar`] by every byte read through it.
+///
+/// Reading through [`BufRead`] advances the bar by the bytes consumed. The bar is
+/// finished once the end of the reader is reached, or when it is dropped before
+/// that.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use std::io::{self, Cursor};
+///
+/// use conch_progress::{ProgressBar, ProgressReader, Template};
+///
+/// let bar = ProgressBar::without_length()
+///     .template(Template::bytes())
+///     .target(io::sink());
+/// let mut reader = ProgressReader::with_seek_length(Cursor::new(vec![0; 3072]), bar)?;
+///
+/// io::copy(&mut reader, &mut io::sink())?;
+/// assert_eq!(reader.bar().position(), 3072);
+/// assert_eq!(reader.bar().length(), Some(3072));
+/// assert!(reader.bar().is_finished());
+/// # Ok::<(), io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct ProgressReader<R> {
+    inner: R,
+    bar: ProgressBar,
+}
+impl<R> ProgressReader<R> {
+    /// Create a new [`ProgressReader`] advancing `bar`, whose length should be the
+    /// number of bytes to read, if it is known.
+    pub fn new(inner: R, bar: ProgressBar) -> Self {
+        bar.tick();
+        Self { inner, bar }
+    }
+
+    /// The [`ProgressBar`] advanced by this reader.
+    pub fn bar(&self) -> &ProgressBar {
+        &self.bar
+    }
+
+    /// A reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the underlying reader.
+    ///
+    /// Bytes read straight from it do not advance the bar.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+}
+impl<R> ProgressReader<R>
+where
+    R: Seek,
+{
+    /// Create a new [`ProgressReader`] advancing `bar`, setting its length to the
+    /// bytes left in `inner`, found by seeking to its end and back.
+    pub fn with_seek_length(mut inner: R, bar: ProgressBar) -> io::Result<Self> {
+        let position = inner.stream_position()?;
+        let end = inner.seek(SeekFrom::End(0))?;
+        inner.seek(SeekFrom::Start(position))?;
+
+        bar.set_length(end.saturating_sub(position));
+        Ok(Self::new(inner, bar))
+    }
+}
+impl<R> Read for ProgressReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+
+        match read {
+            0 if !buf.is_empty() => self.bar.finish(),
+            read => self.bar.inc(read as u64),
+        }
+
+        Ok(read)
+    }
+}
+impl<R> BufRead for ProgressReader<R>
+where
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let buf = self.inner.fill_buf()?;
+        if buf.is_empty() {
+            self.bar.finish();
+        }
+
+        Ok(buf)
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.inner.consume(amount);
+        self.bar.inc(amount as u64);
+    }
+}
+impl<R> Drop for ProgressReader<R> {
+    fn drop(&mut self) {
+        self.bar.finish()
+    }
+}
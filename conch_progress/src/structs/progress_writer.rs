@@ -0,0 +1,84 @@
+use std::io::{self, IoSlice, Write};
+
+use crate::ProgressBar;
+
+/// A [`Write`]r that advances a [`ProgressBar`] by every byte written through it.
+///
+/// The bar is finished when the writer is dropped.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use std::io::{self, Cursor};
+///
+/// use conch_progress::{ProgressBar, ProgressWriter, Template};
+///
+/// let bar = ProgressBar::new(2048)
+///     .template(Template::bytes())
+///     .target(io::sink());
+/// let mut writer = ProgressWriter::new(Vec::new(), bar.clone());
+///
+/// io::copy(&mut Cursor::new(vec![7; 1536]), &mut writer)?;
+/// assert_eq!(writer.get_ref().len(), 1536);
+/// assert_eq!(bar.position(), 1536);
+///
+/// drop(writer);
+/// assert!(bar.is_finished());
+/// # Ok::<(), io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct ProgressWriter<W> {
+    inner: W,
+    bar: ProgressBar,
+}
+impl<W> ProgressWriter<W> {
+    /// Create a new [`ProgressWriter`] advancing `bar`, whose length should be the
+    /// number of bytes to write, if it is known.
+    pub fn new(inner: W, bar: ProgressBar) -> Self {
+        bar.tick();
+        Self { inner, bar }
+    }
+
+    /// The [`ProgressBar`] advanced by this writer.
+    pub fn bar(&self) -> &ProgressBar {
+        &self.bar
+    }
+
+    /// A reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// A mutable reference to the underlying writer.
+    ///
+    /// Bytes written straight to it do not advance the bar.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+}
+impl<W> Write for ProgressWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.bar.inc(written as u64);
+        Ok(written)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let written = self.inner.write_vectored(bufs)?;
+        self.bar.inc(written as u64);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+impl<W> Drop for ProgressWriter<W> {
+    fn drop(&mut self) {
+        self.bar.finish()
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Estimates the rate of progress from the positions recorded over time, for steady
+/// rates and ETAs.
+///
+/// Two estimates are kept:
+///
+/// - a smoothed rate: an exponentially weighted moving average of the rates between
+///   records, where a rate weighs half as much as the ones after it once
+///   [`Self::half_life()`] has passed,
+/// - a windowed rate: the average rate over the last [`Self::window()`].
+///
+/// The moving average is weighted by the time between records rather than their
+/// number, so that bursts of records do not outweigh long gaps. Time passing
+/// without progress brings both estimates down, even before the next record.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use std::time::{Duration, Instant};
+///
+/// use conch_progress::RateEstimator;
+///
+/// let start = Instant::now();
+/// let mut estimator = RateEstimator::new(start, 0);
+///
+/// (1..=10).for_each(|secs| estimator.record(start + Duration::from_secs(secs), secs * 4));
+///
+/// let now = start + Duration::from_secs(10);
+/// assert_eq!(estimator.rate(now), Some(4.0));
+/// assert_eq!(estimator.eta(now, 20), Some(Duration::from_secs(5)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateEstimator {
+    half_life: Duration,
+    window: Duration,
+
+    /// Records within the window, and the last one before it, oldest first.
+    samples: VecDeque<(Instant, u64)>,
+
+    /// The smoothed rate, and the record it was last updated at; the progress made
+    /// since is folded in when estimating.
+    smoothed: Option<f64>,
+    smoothed_at: (Instant, u64),
+}
+impl RateEstimator {
+    /// Create a new [`RateEstimator`] starting at `position` at `now`, with a half
+    /// life of 2s and a window of 10s.
+    pub fn new(now: Instant, position: u64) -> Self {
+        Self {
+            half_life: Duration::from_secs(2),
+            window: Duration::from_secs(10),
+            samples: VecDeque::from([(now, position)]),
+            smoothed: None,
+            smoothed_at: (now, position),
+        }
+    }
+
+    /// A chained function to set the time after which a rate weighs half as much
+    /// in the smoothed rate on an instance.
+    pub fn half_life(mut self, value: Duration) -> Self {
+        self.half_life = value;
+        self
+    }
+
+    /// A chained function to set the time over which the windowed rate is averaged
+    /// on an instance.
+    pub fn window(mut self, value: Duration) -> Self {
+        self.window = value;
+        self
+    }
+
+    /// Start over from `position` at `now`, forgetting every record.
+    pub fn reset(&mut self, now: Instant, position: u64) {
+        *self = Self {
+            half_life: self.half_life,
+            window: self.window,
+            ..Self::new(now, position)
+        };
+    }
+
+    /// Record the `position` reached at `now`.
+    ///
+    /// Going back to an earlier position starts over from there.
+    pub fn record(&mut self, now: Instant, position: u64) {
+        let Some(&(last_at, last)) = self.samples.back() else {
+            return self.reset(now, position);
+        };
+        if position < last {
+            return self.reset(now, position);
+        }
+
+        if now <= last_at {
+            // Records at the same instant belong to the same interval, and progress
+            // made at the very start only moves the start.
+            if let Some(sample) = self.samples.back_mut() {
+                sample.1 = position;
+            }
+            if last_at == self.smoothed_at.0 {
+                self.smoothed_at.1 = position;
+            }
+            return;
+        }
+
+        // Fold the interval up to the last record into the smoothed rate; the one
+        // from there to this record is only folded in once it is over.
+        if last_at > self.smoothed_at.0 {
+            self.smoothed = self.smoothed_rate_at(last_at, last);
+            self.smoothed_at = (last_at, last);
+        }
+        self.samples.push_back((now, position));
+
+        // Keep the last record before the window, which the windowed rate starts at.
+        let start = now.checked_sub(self.window).unwrap_or(now);
+        while self.samples.len() > 1 && self.samples[1].0 <= start {
+            self.samples.pop_front();
+        }
+    }
+
+    /// The smoothed rate at `now`, in steps per second, once any time has passed.
+    pub fn smoothed_rate(&self, now: Instant) -> Option<f64> {
+        let last = self
+            .samples
+            .back()
+            .map_or(self.smoothed_at.1, |(_, last)| *last);
+
+        self.smoothed_rate_at(now, last)
+    }
+
+    /// The smoothed rate at `now` if `last` is the position reached by then.
+    fn smoothed_rate_at(&self, now: Instant, last: u64) -> Option<f64> {
+        let (since, position) = self.smoothed_at;
+        let elapsed = now.saturating_duration_since(since).as_secs_f64();
+        if elapsed <= 0.0 {
+            return self.smoothed;
+        }
+
+        let rate = last.saturating_sub(position) as f64 / elapsed;
+
+        Some(match self.smoothed {
+            Some(smoothed) => {
+                let weight = 0.5_f64.powf(elapsed / self.half_life.as_secs_f64());
+                smoothed * weight + rate * (1.0 - weight)
+            }
+            None => rate,
+        })
+    }
+
+    /// The average rate over the window before `now`, in steps per second, once any
+    /// time has passed.
+    pub fn windowed_rate(&self, now: Instant) -> Option<f64> {
+        let (first, start) = *self.samples.front()?;
+        let (_, last) = *self.samples.back()?;
+        let elapsed = now.saturating_duration_since(first).as_secs_f64();
+
+        (elapsed > 0.0).then(|| last.saturating_sub(start) as f64 / elapsed)
+    }
+
+    /// The best estimate of the rate at `now`: the smoothed rate, or the windowed
+    /// rate if there is none yet.
+    pub fn rate(&self, now: Instant) -> Option<f64> {
+        self.smoothed_rate(now).or_else(|| self.windowed_rate(now))
+    }
+
+    /// Estimated time to go through `remaining` steps at the rate at `now`, if
+    /// progress is being made.
+    pub fn eta(&self, now: Instant, remaining: u64) -> Option<Duration> {
+        self.rate(now)
+            .filter(|rate| *rate > 0.0 && rate.is_finite())
+            .and_then(|rate| Duration::try_from_secs_f64(remaining as f64 / rate).ok())
+    }
+}
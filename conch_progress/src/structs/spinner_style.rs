@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use conch_ansi::Modifier;
+use conch_base_models::StringWrapper;
+
+/// How a [`crate::ProgressBar`] was finished, for spinners to show a final symbol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Outcome {
+    Success,
+    Failure,
+}
+
+/// The frames, tick interval and final symbols of a spinner, the indicator of a
+/// [`crate::ProgressBar`] created by [`crate::ProgressBar::spinner()`].
+///
+/// Each tick of the spinner shows the next frame; once finished through
+/// [`crate::ProgressBar::succeed()`] or [`crate::ProgressBar::fail()`], the frame
+/// is replaced by the symbol of the outcome, `✔` or `✘` by default.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_progress::{ProgressBar, SpinnerStyle};
+///
+/// let spinner = ProgressBar::spinner()
+///     .spinner_style(SpinnerStyle::line())
+///     .message("Resolving")
+///     .target(std::io::sink());
+///
+/// assert_eq!(spinner.render(), "- Resolving");
+/// spinner.tick();
+/// assert_eq!(spinner.render(), "\\ Resolving");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpinnerStyle {
+    frames: Vec<String>,
+    interval: Duration,
+    modifier: Modifier,
+    success: String,
+    success_modifier: Modifier,
+    failure: String,
+    failure_modifier: Modifier,
+}
+impl SpinnerStyle {
+    /// Create a new [`SpinnerStyle`] cycling through `frames`, ticking every 100ms.
+    ///
+    /// Each frame is expected to be as wide as the others.
+    pub fn new<I, S>(frames: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        Self {
+            frames: frames.into_iter().map(|frame| frame.to_string()).collect(),
+            interval: Duration::from_millis(100),
+            modifier: Modifier::Nothing,
+            success: String::from("✔"),
+            success_modifier: Modifier::colour("BrightGreen").unwrap_or_default(),
+            failure: String::from("✘"),
+            failure_modifier: Modifier::colour("BrightRed").unwrap_or_default(),
+        }
+    }
+
+    /// Create a new [`SpinnerStyle`] of braille dots going round, such as `⠹`.
+    pub fn dots() -> Self {
+        Self::new("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏".chars()).interval(Duration::from_millis(80))
+    }
+
+    /// Create a new [`SpinnerStyle`] of a line turning round, such as `/`.
+    pub fn line() -> Self {
+        Self::new("-\\|/".chars()).interval(Duration::from_millis(130))
+    }
+
+    /// Create a new [`SpinnerStyle`] of an arc going round, such as `◝`.
+    pub fn arc() -> Self {
+        Self::new("◜◠◝◞◡◟".chars())
+    }
+
+    /// Create a new [`SpinnerStyle`] of a bar bouncing within brackets, such as
+    /// `[ ===]`.
+    pub fn bouncing_bar() -> Self {
+        Self::new([
+            "[    ]", "[=   ]", "[==  ]", "[=== ]", "[====]", "[ ===]", "[  ==]", "[   =]",
+            "[    ]", "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]",
+        ])
+        .interval(Duration::from_millis(80))
+    }
+
+    /// A chained function to set the time between two frames when ticking
+    /// steadily on an instance.
+    pub fn interval(mut self, value: Duration) -> Self {
+        self.interval = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the frames
+    /// on an instance.
+    pub fn modifier(mut self, value: Modifier) -> Self {
+        self.modifier = value;
+        self
+    }
+
+    /// A chained function to set the symbol shown once succeeded
+    /// on an instance.
+    pub fn success<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.success = value.to_string();
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the symbol shown once
+    /// succeeded on an instance.
+    pub fn success_modifier(mut self, value: Modifier) -> Self {
+        self.success_modifier = value;
+        self
+    }
+
+    /// A chained function to set the symbol shown once failed
+    /// on an instance.
+    pub fn failure<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.failure = value.to_string();
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the symbol shown once failed
+    /// on an instance.
+    pub fn failure_modifier(mut self, value: Modifier) -> Self {
+        self.failure_modifier = value;
+        self
+    }
+
+    /// Time between two frames when ticking steadily.
+    pub(crate) fn tick_interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// The symbol of `outcome`, without its [`Modifier`].
+    pub(crate) fn symbol(&self, outcome: Outcome) -> &str {
+        match outcome {
+            Outcome::Success => &self.success,
+            Outcome::Failure => &self.failure,
+        }
+    }
+
+    /// The frame shown after `ticks` ticks, or the symbol of `outcome` once
+    /// finished.
+    pub(crate) fn render(&self, ticks: u64, outcome: Option<Outcome>) -> String {
+        match outcome {
+            Some(Outcome::Success) => self.success_modifier.wraps_non_empty(&self.success),
+            Some(Outcome::Failure) => self.failure_modifier.wraps_non_empty(&self.failure),
+            None if self.frames.is_empty() => String::new(),
+            None => self
+                .modifier
+                .wraps_non_empty(&self.frames[(ticks % self.frames.len() as u64) as usize]),
+        }
+    }
+}
+impl Default for SpinnerStyle {
+    fn default() -> Self {
+        Self::dots()
+    }
+}
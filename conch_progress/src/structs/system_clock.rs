@@ -0,0 +1,12 @@
+use std::time::Instant;
+
+use crate::Clock;
+
+/// The [`Clock`] of the system, through [`Instant::now()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
@@ -0,0 +1,522 @@
+use std::str::FromStr;
+
+use conch_ansi::{Decoration, Intensity, Modifier};
+use conch_base_models::{visible_width, StringWrapper};
+use conch_split::{truncate_to_width, Alignment};
+use strum::IntoEnumIterator;
+
+use super::progress_bar::BarState;
+use crate::{format_bytes, format_count, format_duration, TemplateError, TemplateErrorKind};
+
+/// Keys accepted within the placeholders of a [`Template`].
+const KEYS: &str = "one of `prefix`, `msg`, `bar`, `spinner`, `pos`, `len`, `human_pos`, \
+    `human_len`, `percent`, `elapsed`, `eta`, `rate`, `bytes`, `total_bytes` or `bytes_rate`";
+
+/// What a placeholder of a [`Template`] is replaced with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Key {
+    Prefix,
+    Message,
+    Bar,
+    Spinner,
+    Position,
+    Length,
+    HumanPosition,
+    HumanLength,
+    Percent,
+    Elapsed,
+    Eta,
+    Rate,
+    Bytes,
+    TotalBytes,
+    BytesRate,
+}
+impl Key {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "prefix" => Some(Self::Prefix),
+            "msg" => Some(Self::Message),
+            "bar" => Some(Self::Bar),
+            "spinner" => Some(Self::Spinner),
+            "pos" => Some(Self::Position),
+            "len" => Some(Self::Length),
+            "human_pos" => Some(Self::HumanPosition),
+            "human_len" => Some(Self::HumanLength),
+            "percent" => Some(Self::Percent),
+            "elapsed" => Some(Self::Elapsed),
+            "eta" => Some(Self::Eta),
+            "rate" => Some(Self::Rate),
+            "bytes" => Some(Self::Bytes),
+            "total_bytes" => Some(Self::TotalBytes),
+            "bytes_rate" => Some(Self::BytesRate),
+            _ => None,
+        }
+    }
+}
+
+/// Width of a placeholder of a [`Template`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum FieldWidth {
+    /// At least this many columns.
+    Fixed(usize),
+
+    /// Exactly the columns left over by the rest of the line.
+    Fill,
+}
+
+/// A placeholder of a [`Template`], such as `{bar:40:fg:Green/fg:BrightBlack}`.
+#[derive(Clone, Debug, PartialEq)]
+struct Field {
+    key: Key,
+    alignment: Alignment,
+    width: Option<FieldWidth>,
+    modifier: Option<Modifier>,
+
+    /// The [`Modifier`] of the empty part of a bar.
+    empty_modifier: Option<Modifier>,
+}
+impl Field {
+    /// Parse the text between the braces of a placeholder, found at `offset` of the
+    /// template.
+    fn parse(text: &str, offset: usize) -> Result<Self, TemplateError> {
+        let mut options = text.split(':').scan(offset, |start, option| {
+            let option_offset = *start;
+            *start += option.len() + 1;
+            Some((option_offset, option))
+        });
+
+        let (_, name) = options.next().unwrap_or((offset, ""));
+        let key = Key::from_name(name).ok_or_else(|| {
+            TemplateError::new(TemplateErrorKind::UnknownKey, offset..offset + name.len())
+                .with_expected(KEYS)
+                .with_found(name)
+        })?;
+
+        let mut field = Self {
+            key,
+            alignment: Alignment::Left,
+            width: None,
+            modifier: None,
+            empty_modifier: None,
+        };
+        let (mut has_width, mut has_style) = (false, false);
+
+        // Style strings may contain colons themselves, such as `fg:Red`, so anything
+        // that does not look like a width is glued back onto the style before it.
+        let mut styles: Vec<(usize, String)> = Vec::new();
+        for (option_offset, option) in options {
+            let is_width = option
+                .chars()
+                .next()
+                .is_some_and(|c| "<^>*".contains(c) || c.is_ascii_digit());
+
+            if is_width {
+                if has_width {
+                    return Err(TemplateError::new(
+                        TemplateErrorKind::DuplicateOption,
+                        option_offset..option_offset + option.len(),
+                    )
+                    .with_expected("a single width")
+                    .with_found(option));
+                }
+                has_width = true;
+                field.parse_width(option, option_offset)?;
+            } else {
+                match styles.last_mut() {
+                    Some((style_offset, style))
+                        if *style_offset + style.len() + 1 == option_offset
+                            && !option.is_empty()
+                            && is_style_prefix(style) =>
+                    {
+                        style.push(':');
+                        style.push_str(option);
+                    }
+                    _ => styles.push((option_offset, option.to_string())),
+                }
+            }
+        }
+
+        for (style_offset, style) in styles {
+            if has_style {
+                return Err(TemplateError::new(
+                    TemplateErrorKind::DuplicateOption,
+                    style_offset..style_offset + style.len(),
+                )
+                .with_expected("a single style")
+                .with_found(style));
+            }
+            has_style = true;
+            field.parse_styles(&style, style_offset)?;
+        }
+
+        Ok(field)
+    }
+
+    /// Parse a width such as `40`, `>8` or `*`, found at `offset` of the template.
+    fn parse_width(&mut self, option: &str, offset: usize) -> Result<(), TemplateError> {
+        let (alignment, width) = match option.chars().next() {
+            Some('<') => (Alignment::Left, &option[1..]),
+            Some('^') => (Alignment::Center, &option[1..]),
+            Some('>') => (Alignment::Right, &option[1..]),
+            _ => (Alignment::Left, option),
+        };
+        let width_offset = offset + option.len() - width.len();
+
+        self.alignment = alignment;
+        self.width = match width {
+            "" => None,
+            "*" => Some(FieldWidth::Fill),
+            width => Some(FieldWidth::Fixed(width.parse().map_err(|_| {
+                TemplateError::new(
+                    TemplateErrorKind::InvalidWidth,
+                    width_offset..width_offset + width.len(),
+                )
+                .with_expected("a number of columns, or `*` to fill the line")
+                .with_found(width)
+            })?)),
+        };
+
+        Ok(())
+    }
+
+    /// Parse the style of the field, which for a bar may be split by `/` into the
+    /// styles of the filled and empty parts.
+    fn parse_styles(&mut self, option: &str, offset: usize) -> Result<(), TemplateError> {
+        let mut styles = option.split('/');
+        let filled = styles.next().unwrap_or_default();
+        self.modifier = Some(parse_style(filled, offset)?);
+
+        let Some(empty) = styles.next() else {
+            return Ok(());
+        };
+        let empty_offset = offset + filled.len() + 1;
+
+        if self.key != Key::Bar || styles.next().is_some() {
+            let (expected, slash) = match self.key {
+                Key::Bar => (
+                    "a style for the filled part and one for the empty part of the bar",
+                    empty_offset + empty.len(),
+                ),
+                _ => (
+                    "a single style, as only `bar` has two parts",
+                    offset + filled.len(),
+                ),
+            };
+
+            return Err(
+                TemplateError::new(TemplateErrorKind::InvalidStyle, slash..slash + 1)
+                    .with_expected(expected)
+                    .with_found("/"),
+            );
+        }
+
+        self.empty_modifier = Some(parse_style(empty, empty_offset)?);
+        Ok(())
+    }
+
+    /// The text of the field, before it is styled and padded.
+    fn value(&self, state: &BarState) -> String {
+        let unknown = || String::from("?");
+
+        match self.key {
+            Key::Prefix => state.prefix.clone(),
+            Key::Message => state.message.clone(),
+            Key::Bar => String::new(),
+            Key::Spinner => state.render_spinner(),
+            Key::Position => state.position.to_string(),
+            Key::Length => state
+                .length
+                .map_or_else(unknown, |length| length.to_string()),
+            Key::HumanPosition => format_count(state.position),
+            Key::HumanLength => state.length.map_or_else(unknown, format_count),
+            Key::Percent => state
+                .percent()
+                .map_or_else(unknown, |percent| percent.to_string()),
+            Key::Elapsed => format_duration(state.elapsed()),
+            Key::Eta => state.eta().map_or_else(unknown, format_duration),
+            Key::Rate => format!("{:.1}/s", state.rate()),
+            Key::Bytes => format_bytes(state.position),
+            Key::TotalBytes => state.length.map_or_else(unknown, format_bytes),
+            Key::BytesRate => format_bytes(state.rate() as u64) + "/s",
+        }
+    }
+
+    /// Render the field, `fill` columns wide if it fills the rest of the line.
+    fn render(&self, state: &BarState, fill: Option<usize>) -> String {
+        let width = match (fill, self.width) {
+            (Some(fill), _) => Some(fill),
+            (None, Some(FieldWidth::Fixed(width))) => Some(width),
+            _ => None,
+        };
+
+        if self.key == Key::Bar {
+            let mut style = state.style.clone();
+            if let Some(width) = width {
+                style = style.width(width);
+            }
+            if let Some(modifier) = &self.modifier {
+                style = style.filled_modifier(modifier.clone());
+            }
+            if let Some(modifier) = &self.empty_modifier {
+                style = style.empty_modifier(modifier.clone());
+            }
+
+            return state.render_bar(&style);
+        }
+
+        let text = self.value(state);
+        let text = match &self.modifier {
+            Some(modifier) => modifier.wraps_non_empty(&text),
+            None => text,
+        };
+
+        match (fill, width) {
+            (Some(fill), _) => self
+                .alignment
+                .pad(&truncate_to_width(&text, fill, "…"), fill),
+            (None, Some(width)) => self.alignment.pad(&text, width),
+            (None, None) => text,
+        }
+    }
+}
+
+/// Whether `style` ends with a prefix of a style string that expects a value after a
+/// `:`, such as `fg`, `Bold bg` or `fg:Green/fg`.
+fn is_style_prefix(style: &str) -> bool {
+    style
+        .rsplit([' ', '/'])
+        .next()
+        .is_some_and(|word| matches!(word, "fg" | "bg" | "up" | "down" | "left" | "right" | "at"))
+}
+
+/// The [`Intensity`] or [`Decoration`] named `word` in any case, such as `bold` or
+/// `notItalic`.
+fn attribute_ignoring_case(word: &str) -> Option<Modifier> {
+    let named = |name: String| name.eq_ignore_ascii_case(word);
+
+    Intensity::iter()
+        .find(|intensity| named(format!("{:?}", intensity)))
+        .map(Modifier::Intensity)
+        .or_else(|| {
+            Decoration::iter()
+                .find(|decoration| named(format!("{:?}", decoration)))
+                .map(Modifier::Decoration)
+        })
+}
+
+/// Parse a style string found at `offset` of the template.
+///
+/// Besides the words of [`Modifier::from_str()`], a bare colour name such as
+/// `BrightGreen` is taken as a foreground colour, and intensities and decorations
+/// are named in any case, such as `bold`.
+fn parse_style(style: &str, offset: usize) -> Result<Modifier, TemplateError> {
+    style
+        .split_whitespace()
+        .map(|word| (word.as_ptr() as usize - style.as_ptr() as usize, word))
+        .try_fold(Modifier::Nothing, |modifier, (word_offset, word)| {
+            let word_offset = offset + word_offset;
+            let parsed = match Modifier::colour(word).or_else(|| attribute_ignoring_case(word)) {
+                Some(colour) => colour,
+                None => word.parse::<Modifier>().map_err(|err| {
+                    let span = err
+                        .span
+                        .clone()
+                        .map_or(word_offset..word_offset + word.len(), |span| {
+                            span.start + word_offset..span.end + word_offset
+                        });
+                    let error = TemplateError::new(TemplateErrorKind::InvalidStyle, span);
+
+                    match (err.expected, err.found) {
+                        (Some(expected), Some(found)) => {
+                            error.with_expected(expected).with_found(found)
+                        }
+                        (Some(expected), None) => error.with_expected(expected),
+                        (None, Some(found)) => error.with_found(found),
+                        (None, None) => error,
+                    }
+                })?,
+            };
+
+            Ok(modifier + parsed)
+        })
+}
+
+/// A part of a [`Template`].
+#[derive(Clone, Debug, PartialEq)]
+enum Part {
+    Literal(String),
+    Field(Field),
+}
+
+/// A layout of the line of a [`crate::ProgressBar`], with placeholders such as
+/// `{bar}` or `{eta}` replaced by the state of the bar.
+///
+/// Each placeholder is a key followed by options, each after a `:`:
+///
+/// - a width such as `40`, optionally preceded by an alignment of `<`, `^` or `>`;
+///   a width of `*` fills the columns left over by the rest of the line, split
+///   evenly between such placeholders,
+/// - a style string as accepted by [`Modifier::from_str()`], where a bare colour
+///   name is taken as a foreground colour, and intensities and decorations such as
+///   `bold` or `italic` may be written in any case; the style of `bar` may be split
+///   by `/` into the styles of its filled and empty parts.
+///
+/// The keys are:
+///
+/// | Key           | Replaced by                                        |
+/// |---------------|----------------------------------------------------|
+/// | `prefix`      | the prefix of the bar                              |
+/// | `msg`         | the message of the bar                             |
+/// | `bar`         | the bar itself, drawn with its [`crate::BarStyle`] |
+/// | `spinner`     | the frame of the spinner, if the bar is one        |
+/// | `pos`, `len`  | the position and length                            |
+/// | `human_pos`   | the position with separated thousands, as `1,234`  |
+/// | `human_len`   | the length with separated thousands                |
+/// | `percent`     | the percentage done, without the `%`               |
+/// | `elapsed`     | the time since the bar was created                 |
+/// | `eta`         | the estimated time left, at the rate of the bar    |
+/// | `rate`        | the number of steps per second                     |
+/// | `bytes`       | the position as a size in bytes, such as `1.5 MiB` |
+/// | `total_bytes` | the length as a size in bytes                      |
+/// | `bytes_rate`  | the number of bytes per second                     |
+///
+/// Values that are not known, such as the length of a bar without one, are shown as
+/// `?`. Literal braces are written as `{{` and `}}`.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_progress::{BarStyle, ProgressBar, Template};
+///
+/// let template: Template = "{prefix:Bold} [{bar:*:BrightGreen/Grayscale08}] {pos:>3}/{len}"
+///     .parse()
+///     .unwrap();
+/// let bar = ProgressBar::new(100)
+///     .prefix("Fetching")
+///     .style(BarStyle::ascii())
+///     .template(template)
+///     .width(30)
+///     .target(std::io::sink());
+/// bar.inc(50);
+///
+/// assert_eq!(
+///     bar.render(),
+///     concat!(
+///         "\x1b[1mFetching\x1b[22m [",
+///         "\x1b[38;5;10m#####\x1b[39m\x1b[38;5;240m------\x1b[39m",
+///         "]  50/100",
+///     )
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+impl Template {
+    /// A [`Template`] for transfers of bytes, such as
+    /// `[####------] 1.5 MiB/4.0 MiB 512.0 KiB/s eta 5s`, with the bar filling the
+    /// rest of the line.
+    pub fn bytes() -> Self {
+        "[{bar:*}] {bytes}/{total_bytes} {bytes_rate} eta {eta}"
+            .parse()
+            .expect("The bytes template is valid.")
+    }
+
+    /// Render the line for `state`, filling placeholders of width `*` up to `width`
+    /// columns in total.
+    pub(crate) fn render(&self, state: &BarState, width: usize) -> String {
+        let rendered: Vec<Option<String>> = self
+            .parts
+            .iter()
+            .map(|part| match part {
+                Part::Literal(text) => Some(text.clone()),
+                Part::Field(field) if field.width == Some(FieldWidth::Fill) => None,
+                Part::Field(field) => Some(field.render(state, None)),
+            })
+            .collect();
+
+        let used: usize = rendered
+            .iter()
+            .flatten()
+            .map(|text| visible_width(text))
+            .sum();
+        let mut fills = rendered.iter().filter(|text| text.is_none()).count();
+        let mut remaining = width.saturating_sub(used);
+
+        rendered
+            .into_iter()
+            .zip(&self.parts)
+            .map(|(text, part)| match (text, part) {
+                (Some(text), _) => text,
+                (None, Part::Field(field)) => {
+                    let share = remaining / fills.max(1);
+                    remaining -= share;
+                    fills = fills.saturating_sub(1);
+                    field.render(state, Some(share))
+                }
+                (None, Part::Literal(_)) => String::new(),
+            })
+            .collect()
+    }
+}
+impl FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut pos = 0;
+
+        while let Some(c) = s[pos..].chars().next() {
+            let rest = &s[pos..];
+
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                literal.push(c);
+                pos += 2;
+                continue;
+            }
+
+            match c {
+                '}' => {
+                    return Err(
+                        TemplateError::new(TemplateErrorKind::UnmatchedBrace, pos..pos + 1)
+                            .with_expected("`}}` for a literal brace")
+                            .with_found("}"),
+                    )
+                }
+                '{' => {
+                    let inner = &rest[1..];
+
+                    match inner.find(['{', '}']) {
+                        Some(end) if inner[end..].starts_with('}') => {
+                            if !literal.is_empty() {
+                                parts.push(Part::Literal(std::mem::take(&mut literal)));
+                            }
+                            parts.push(Part::Field(Field::parse(&inner[..end], pos + 1)?));
+                            pos += end + 2;
+                        }
+                        end => {
+                            let end = pos + 1 + end.unwrap_or(inner.len());
+                            return Err(TemplateError::new(
+                                TemplateErrorKind::UnclosedPlaceholder,
+                                pos..end,
+                            )
+                            .with_expected("`}` to close the placeholder"));
+                        }
+                    }
+                }
+                c => {
+                    literal.push(c);
+                    pos += c.len_utf8();
+                }
+            }
+        }
+
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        Ok(Self { parts })
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+use std::ops::Range;
+
+use conch_base_models::render_span;
+use thiserror::Error;
+
+/// Machine readable kinds of [`TemplateError`].
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TemplateErrorKind {
+    #[error("unclosed placeholder")]
+    UnclosedPlaceholder,
+
+    #[error("unmatched `}}`")]
+    UnmatchedBrace,
+
+    #[error("unknown placeholder")]
+    UnknownKey,
+
+    #[error("invalid width")]
+    InvalidWidth,
+
+    #[error("invalid style")]
+    InvalidStyle,
+
+    #[error("option given more than once")]
+    DuplicateOption,
+}
+
+/// An error parsing a [`crate::Template`].
+///
+/// Besides a machine readable [`TemplateErrorKind`], this records what was expected
+/// and what was found instead, and `span` points at the offending bytes within the
+/// template, which [`Self::render()`] can underline.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_progress::{Template, TemplateErrorKind};
+///
+/// let source = "{bar} {pos}/{lne}";
+/// let err = source.parse::<Template>().unwrap_err();
+///
+/// assert_eq!(err.kind, TemplateErrorKind::UnknownKey);
+/// assert_eq!(err.span, 13..16);
+/// assert_eq!(
+///     err.render(source).lines().skip(2).collect::<Vec<_>>(),
+///     vec!["1 | {bar} {pos}/{lne}", "  |              ^^^"],
+/// );
+/// ```
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub struct TemplateError {
+    /// What went wrong.
+    pub kind: TemplateErrorKind,
+
+    /// Description of what was expected.
+    pub expected: Option<String>,
+
+    /// Description of what was found instead.
+    pub found: Option<String>,
+
+    /// The byte range of the offending part within the template.
+    pub span: Range<usize>,
+}
+impl TemplateError {
+    /// Create a new [`TemplateError`] of `kind`, about `span` of the template.
+    pub fn new(kind: TemplateErrorKind, span: Range<usize>) -> Self {
+        Self {
+            kind,
+            expected: None,
+            found: None,
+            span,
+        }
+    }
+
+    /// Chained method to describe what was expected.
+    pub fn with_expected(mut self, expected: impl ToString) -> Self {
+        self.expected = Some(expected.to_string());
+        self
+    }
+
+    /// Chained method to describe what was found instead.
+    pub fn with_found(mut self, found: impl ToString) -> Self {
+        self.found = Some(found.to_string());
+        self
+    }
+
+    /// Pretty print this error, underlining the offending part of `source`, which
+    /// should be the template that was parsed.
+    pub fn render(&self, source: &str) -> String {
+        render_span(source, self.span.clone(), &format!("error: {}", self))
+    }
+}
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Template: {}", self.kind)?;
+
+        match (&self.expected, &self.found) {
+            (Some(expected), Some(found)) => write!(
+                f,
+                "; expected {}, found `{}`",
+                expected,
+                found.escape_debug()
+            ),
+            (Some(expected), None) => write!(f, "; expected {}", expected),
+            (None, Some(found)) => write!(f, "; found `{}`", found.escape_debug()),
+            (None, None) => Ok(()),
+        }?;
+
+        write!(f, " at byte {}", self.span.start)
+    }
+}
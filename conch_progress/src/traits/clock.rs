@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+use std::time::Instant;
+
+/// A source of time for [`crate::ProgressBar`]s, measuring elapsed times and rates.
+///
+/// Bars use [`crate::SystemClock`] by default; a [`crate::ManualClock`] makes their
+/// timings deterministic, such as in tests.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use conch_progress::{ManualClock, ProgressBar, Template};
+///
+/// let clock = ManualClock::new();
+/// let bar = ProgressBar::new(100)
+///     .clock(clock.clone())
+///     .template("{pos}/{len} in {elapsed}".parse::<Template>().unwrap())
+///     .target(std::io::sink());
+///
+/// clock.advance(Duration::from_secs(75));
+/// bar.inc(30);
+/// assert_eq!(bar.render(), "30/100 in 1m15s");
+/// ```
+pub trait Clock: Debug + Send + Sync {
+    /// The current instant.
+    fn now(&self) -> Instant;
+}
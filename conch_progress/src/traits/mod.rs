@@ -0,0 +1,6 @@
+//! Traits adding progress bars to other types.
+//!
+mod progress_iterator;
+pub use progress_iterator::*;
+mod clock;
+pub use clock::*;
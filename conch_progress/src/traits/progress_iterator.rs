@@ -0,0 +1,63 @@
+use crate::{ProgressBar, ProgressBarIter};
+
+/// Length of an iterator going by its [`Iterator::size_hint()`]: its upper bound if
+/// it has one.
+fn length_hint<I>(iter: &I) -> Option<u64>
+where
+    I: Iterator,
+{
+    iter.size_hint()
+        .1
+        .and_then(|upper| u64::try_from(upper).ok())
+}
+
+/// Extension trait drawing a [`ProgressBar`] while any [`Iterator`] is consumed.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_progress::{ProgressBar, ProgressIterator};
+///
+/// let bar = ProgressBar::without_length().target(std::io::sink());
+/// let total: u32 = (1..=10).progress_with(bar.clone()).sum();
+///
+/// assert_eq!(total, 55);
+/// assert_eq!(bar.length(), Some(10));
+/// assert_eq!(bar.position(), 10);
+/// assert!(bar.is_finished());
+/// ```
+pub trait ProgressIterator: Iterator + Sized {
+    /// Draw a new [`ProgressBar`] while consuming this iterator, with its length
+    /// taken from [`Iterator::size_hint()`].
+    ///
+    /// If the size hint has no upper bound, the bar is drawn without a length.
+    fn progress(self) -> ProgressBarIter<Self> {
+        let bar = match length_hint(&self) {
+            Some(length) => ProgressBar::new(length),
+            None => ProgressBar::without_length(),
+        };
+
+        ProgressBarIter::new(self, bar)
+    }
+
+    /// Draw a new [`ProgressBar`] of `length` steps while consuming this iterator.
+    fn progress_count(self, length: u64) -> ProgressBarIter<Self> {
+        ProgressBarIter::new(self, ProgressBar::new(length))
+    }
+
+    /// Advance `bar` while consuming this iterator.
+    ///
+    /// If `bar` has no length, it is taken from [`Iterator::size_hint()`] where
+    /// possible.
+    fn progress_with(self, bar: ProgressBar) -> ProgressBarIter<Self> {
+        if bar.length().is_none() {
+            if let Some(length) = length_hint(&self) {
+                bar.set_length(length);
+            }
+        }
+
+        ProgressBarIter::new(self, bar)
+    }
+}
+impl<I> ProgressIterator for I where I: Iterator {}
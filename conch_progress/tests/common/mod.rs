@@ -0,0 +1,31 @@
+//! Fixtures shared by the tests of this crate.
+
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+/// A writer capturing everything drawn to it.
+#[derive(Clone, Default)]
+pub struct Capture(Arc<Mutex<Vec<u8>>>);
+
+// Each test binary only uses some of these.
+#[allow(dead_code)]
+impl Capture {
+    /// Everything drawn so far.
+    pub fn output(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+
+    /// Everything drawn since the last call.
+    pub fn take(&self) -> String {
+        String::from_utf8(std::mem::take(&mut *self.0.lock().unwrap())).unwrap()
+    }
+}
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
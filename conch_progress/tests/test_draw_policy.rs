@@ -0,0 +1,271 @@
+//! Test drawing [`ProgressBar`]s and [`MultiProgress`] with each [`DrawPolicy`].
+
+mod common;
+
+use std::ffi::OsStr;
+use std::io;
+use std::time::Duration;
+
+use conch_progress::{
+    BarStyle, DrawPolicy, ManualClock, MultiProgress, ProgressBar, ProgressFinish,
+};
+
+use common::Capture;
+
+/// A 1000 steps bar on `clock` drawn to `capture` with `policy`.
+fn bar(capture: &Capture, clock: &ManualClock, policy: DrawPolicy) -> ProgressBar {
+    ProgressBar::new(1000)
+        .style(BarStyle::ascii().width(4))
+        .clock(clock.clone())
+        .draw_policy(policy)
+        .target(capture.clone())
+}
+
+#[cfg(test)]
+mod test_in_place {
+    use super::*;
+
+    #[test]
+    fn throttled() {
+        let capture = Capture::default();
+        let clock = ManualClock::new();
+        let bar = bar(&capture, &clock, DrawPolicy::InPlace { max_hz: 10 });
+
+        bar.inc(100);
+        assert_eq!(capture.take(), "\r---- 100/1000  10%\x1b[K");
+
+        clock.advance(Duration::from_millis(50));
+        bar.inc(100);
+        assert_eq!(capture.take(), "");
+
+        clock.advance(Duration::from_millis(50));
+        bar.inc(100);
+        assert_eq!(capture.take(), "\r#--- 300/1000  30%\x1b[K");
+    }
+
+    #[test]
+    fn final_draw_forced() {
+        let capture = Capture::default();
+        let clock = ManualClock::new();
+        let bar = bar(&capture, &clock, DrawPolicy::InPlace { max_hz: 1 });
+
+        bar.inc(100);
+        bar.inc(900);
+        capture.take();
+
+        bar.finish();
+        assert_eq!(capture.take(), "\r#### 1000/1000 100%\x1b[K\n");
+    }
+
+    #[test]
+    fn unlimited() {
+        let capture = Capture::default();
+        let clock = ManualClock::new();
+        let bar = bar(&capture, &clock, DrawPolicy::InPlace { max_hz: 0 });
+
+        bar.inc(500);
+        bar.inc(500);
+        assert_eq!(
+            capture.take(),
+            "\r##-- 500/1000  50%\x1b[K\r#### 1000/1000 100%\x1b[K"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_lines {
+    use super::*;
+
+    #[test]
+    fn snapshots() {
+        let capture = Capture::default();
+        let clock = ManualClock::new();
+        let bar = bar(
+            &capture,
+            &clock,
+            DrawPolicy::Lines {
+                interval: Duration::from_secs(1),
+            },
+        );
+
+        bar.inc(420);
+        assert_eq!(capture.take(), "42% (420/1000)\n");
+
+        clock.advance(Duration::from_millis(500));
+        bar.inc(40);
+        assert_eq!(capture.take(), "");
+
+        clock.advance(Duration::from_millis(500));
+        bar.inc(40);
+        assert_eq!(capture.take(), "50% (500/1000) eta 6s\n");
+
+        bar.set_message("done");
+        bar.inc(500);
+        bar.finish();
+        assert_eq!(capture.take(), "100% (1000/1000) done\n");
+    }
+
+    #[test]
+    fn unchanged_snapshot_not_printed() {
+        let capture = Capture::default();
+        let clock = ManualClock::new();
+        let bar = bar(
+            &capture,
+            &clock,
+            DrawPolicy::Lines {
+                interval: Duration::ZERO,
+            },
+        );
+
+        bar.inc(1);
+        bar.tick();
+        bar.finish();
+        assert_eq!(capture.take(), "0% (1/1000)\n");
+    }
+
+    #[test]
+    fn cleared_bar_keeps_snapshots() {
+        let capture = Capture::default();
+        let clock = ManualClock::new();
+        let bar = bar(
+            &capture,
+            &clock,
+            DrawPolicy::Lines {
+                interval: Duration::ZERO,
+            },
+        )
+        .prefix("Copying")
+        .on_finish(ProgressFinish::Clear);
+
+        bar.inc(1000);
+        bar.finish();
+        assert_eq!(capture.take(), "Copying 100% (1000/1000)\n");
+    }
+
+    #[test]
+    fn without_length() {
+        let bar = ProgressBar::without_length()
+            .message("files")
+            .target(io::sink());
+
+        bar.inc(12);
+        assert_eq!(bar.snapshot(), "12 files");
+    }
+
+    #[test]
+    fn spinner() {
+        let capture = Capture::default();
+        let spinner = ProgressBar::spinner()
+            .message("Resolving")
+            .draw_policy(DrawPolicy::Lines {
+                interval: Duration::ZERO,
+            })
+            .target(capture.clone());
+
+        spinner.tick();
+        spinner.tick();
+        spinner.succeed("Resolved");
+        assert_eq!(capture.take(), "Resolving\n✔ Resolved\n");
+    }
+}
+
+#[test]
+fn silent() {
+    let capture = Capture::default();
+    let clock = ManualClock::new();
+    let bar = bar(&capture, &clock, DrawPolicy::Silent);
+
+    bar.inc(500);
+    bar.finish();
+    assert_eq!(capture.take(), "");
+    assert_eq!(bar.position(), 500);
+}
+
+#[cfg(test)]
+mod test_detect {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $is_terminal:expr,
+            $no_color:expr,
+            $term:expr,
+            $expected:expr
+        ) => {
+            #[test]
+            fn $name() {
+                let no_color: Option<&str> = $no_color;
+                let term: Option<&str> = $term;
+
+                assert_eq!(
+                    DrawPolicy::detect_from(
+                        $is_terminal,
+                        no_color.map(OsStr::new),
+                        term.map(OsStr::new)
+                    ),
+                    $expected
+                );
+            }
+        };
+    }
+
+    const IN_PLACE: DrawPolicy = DrawPolicy::InPlace {
+        max_hz: DrawPolicy::DEFAULT_MAX_HZ,
+    };
+    const LINES: DrawPolicy = DrawPolicy::Lines {
+        interval: DrawPolicy::DEFAULT_INTERVAL,
+    };
+
+    test_factory!(terminal, true, None, Some("xterm-256color"), IN_PLACE);
+    test_factory!(not_terminal, false, None, Some("xterm-256color"), LINES);
+    test_factory!(no_color, true, Some("1"), Some("xterm-256color"), LINES);
+    test_factory!(empty_no_color, true, Some(""), None, IN_PLACE);
+    test_factory!(dumb_terminal, true, None, Some("dumb"), LINES);
+}
+
+#[cfg(test)]
+mod test_multi_progress {
+    use super::*;
+
+    #[test]
+    fn lines() {
+        let capture = Capture::default();
+        let multi = MultiProgress::new()
+            .draw_policy(DrawPolicy::Lines {
+                interval: Duration::ZERO,
+            })
+            .target(capture.clone());
+
+        let clock = ManualClock::new();
+        let a = multi.add(ProgressBar::new(10).prefix("a").clock(clock.clone()));
+        let b = multi.add(ProgressBar::new(10).prefix("b").clock(clock));
+        assert_eq!(capture.take(), "");
+
+        a.inc(5);
+        b.inc(2);
+        a.finish();
+        assert_eq!(capture.take(), "a 50% (5/10)\nb 20% (2/10)\n");
+        assert_eq!(multi.len(), 2);
+
+        b.inc(8);
+        b.finish();
+        assert_eq!(capture.take(), "b 100% (10/10)\n");
+        assert!(multi.is_empty());
+    }
+
+    #[test]
+    fn policy_of_multi_wins() {
+        let capture = Capture::default();
+        let multi = MultiProgress::new()
+            .draw_policy(DrawPolicy::Silent)
+            .target(capture.clone());
+
+        let bar = multi.add(ProgressBar::new(10).draw_policy(DrawPolicy::Lines {
+            interval: Duration::ZERO,
+        }));
+        bar.inc(10);
+        bar.finish();
+        assert_eq!(capture.take(), "");
+    }
+}
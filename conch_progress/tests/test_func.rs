@@ -0,0 +1,87 @@
+//! Test the formatting functions.
+
+use std::time::Duration;
+
+use conch_progress::{format_bytes, format_count, format_duration};
+
+#[cfg(test)]
+mod test_format_duration {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $duration:expr,
+            $expected:expr
+        ) => {
+            #[test]
+            fn $name() {
+                assert_eq!(format_duration($duration), $expected);
+            }
+        };
+    }
+
+    test_factory!(zero, Duration::ZERO, "0s");
+    test_factory!(sub_second, Duration::from_millis(999), "0s");
+    test_factory!(seconds, Duration::from_secs(59), "59s");
+    test_factory!(minutes, Duration::from_secs(60), "1m00s");
+    test_factory!(minutes_seconds, Duration::from_secs(3599), "59m59s");
+    test_factory!(hours, Duration::from_secs(7500), "2h05m");
+    test_factory!(
+        days,
+        Duration::from_secs(86400 * 3 + 3600 * 4 + 59),
+        "3d04h"
+    );
+}
+
+#[cfg(test)]
+mod test_format_count {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $count:expr,
+            $expected:expr
+        ) => {
+            #[test]
+            fn $name() {
+                assert_eq!(format_count($count), $expected);
+            }
+        };
+    }
+
+    test_factory!(zero, 0, "0");
+    test_factory!(hundreds, 999, "999");
+    test_factory!(thousands, 1000, "1,000");
+    test_factory!(tens_of_thousands, 12345, "12,345");
+    test_factory!(hundreds_of_thousands, 123456, "123,456");
+    test_factory!(millions, 1234567, "1,234,567");
+    test_factory!(max, u64::MAX, "18,446,744,073,709,551,615");
+}
+
+#[cfg(test)]
+mod test_format_bytes {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $bytes:expr,
+            $expected:expr
+        ) => {
+            #[test]
+            fn $name() {
+                assert_eq!(format_bytes($bytes), $expected);
+            }
+        };
+    }
+
+    test_factory!(zero, 0, "0 B");
+    test_factory!(bytes, 1023, "1023 B");
+    test_factory!(kibibytes, 1024, "1.0 KiB");
+    test_factory!(fractional, 1536, "1.5 KiB");
+    test_factory!(mebibytes, 5 << 20, "5.0 MiB");
+    test_factory!(gibibytes, 3 << 30, "3.0 GiB");
+    test_factory!(largest_unit, u64::MAX, "16.0 EiB");
+}
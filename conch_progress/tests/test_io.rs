@@ -0,0 +1,171 @@
+//! Test [`ProgressReader`] and [`ProgressWriter`].
+
+use std::io::{self, BufRead, BufReader, Cursor, Read, Seek, SeekFrom, Write};
+use std::time::Duration;
+
+use conch_progress::{ManualClock, ProgressBar, ProgressReader, ProgressWriter, Template};
+
+/// A bar without a length on `clock`, showing the bytes transferred on 50 columns.
+fn bar(clock: &ManualClock) -> ProgressBar {
+    ProgressBar::without_length()
+        .clock(clock.clone())
+        .template(Template::bytes())
+        .width(50)
+        .target(io::sink())
+}
+
+/// A reader failing on every read.
+struct Failing;
+impl Read for Failing {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("failing"))
+    }
+}
+impl Write for Failing {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("failing"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_reader {
+    use super::*;
+
+    #[test]
+    fn reads_advance_bar() {
+        let clock = ManualClock::new();
+        let mut reader = ProgressReader::new(Cursor::new(vec![1; 2048]), bar(&clock));
+        reader.bar().set_length(2048);
+
+        let mut buf = [0; 1024];
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(reader.read(&mut buf).unwrap(), 1024);
+        assert_eq!(reader.bar().position(), 1024);
+        assert_eq!(
+            reader.bar().render(),
+            "[████████░░░░░░░░░] 1.0 KiB/2.0 KiB 512 B/s eta 2s"
+        );
+        assert!(!reader.bar().is_finished());
+
+        assert_eq!(reader.read(&mut buf).unwrap(), 1024);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+        assert!(reader.bar().is_finished());
+    }
+
+    #[test]
+    fn empty_buffer_does_not_finish() {
+        let mut reader = ProgressReader::new(Cursor::new(vec![1; 8]), bar(&ManualClock::new()));
+
+        assert_eq!(reader.read(&mut []).unwrap(), 0);
+        assert!(!reader.bar().is_finished());
+    }
+
+    #[test]
+    fn seek_length() {
+        let mut cursor = Cursor::new(vec![0; 3000]);
+        cursor.seek(SeekFrom::Start(1000)).unwrap();
+
+        let mut reader =
+            ProgressReader::with_seek_length(cursor, bar(&ManualClock::new())).unwrap();
+        assert_eq!(reader.bar().length(), Some(2000));
+        assert_eq!(reader.get_ref().position(), 1000);
+
+        io::copy(&mut reader, &mut io::sink()).unwrap();
+        assert_eq!(reader.bar().position(), 2000);
+        assert!(reader.bar().is_finished());
+    }
+
+    #[test]
+    fn buf_read() {
+        let text = "veni\nvidi\nvici\n";
+        let mut reader =
+            ProgressReader::new(BufReader::new(text.as_bytes()), bar(&ManualClock::new()));
+
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        assert_eq!(line, "veni\n");
+        assert_eq!(reader.bar().position(), 5);
+
+        assert_eq!(reader.by_ref().lines().count(), 2);
+        assert_eq!(reader.bar().position(), text.len() as u64);
+        assert!(reader.bar().is_finished());
+    }
+
+    #[test]
+    fn errors_do_not_advance() {
+        let mut reader = ProgressReader::new(Failing, bar(&ManualClock::new()));
+
+        assert!(reader.read(&mut [0; 4]).is_err());
+        assert_eq!(reader.bar().position(), 0);
+        assert!(!reader.bar().is_finished());
+    }
+
+    #[test]
+    fn dropped_reader_finishes() {
+        let bar = bar(&ManualClock::new());
+        let reader = ProgressReader::new(Cursor::new(vec![0; 8]), bar.clone());
+
+        drop(reader);
+        assert!(bar.is_finished());
+    }
+}
+
+#[cfg(test)]
+mod test_writer {
+    use super::*;
+
+    #[test]
+    fn writes_advance_bar() {
+        let clock = ManualClock::new();
+        let mut writer = ProgressWriter::new(Vec::new(), bar(&clock));
+
+        clock.advance(Duration::from_secs(4));
+        writer.write_all(&[3; 6 << 10]).unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(writer.get_ref().len(), 6 << 10);
+        assert_eq!(writer.bar().position(), 6 << 10);
+        assert_eq!(
+            writer.bar().render(),
+            "[░░░░░░░░░░░░░░░░░░░░░░] 6.0 KiB/? 1.5 KiB/s eta ?"
+        );
+    }
+
+    #[test]
+    fn copy() {
+        let bar = bar(&ManualClock::new());
+        bar.set_length(10_000);
+        let mut writer = ProgressWriter::new(Vec::new(), bar.clone());
+
+        let copied = io::copy(&mut Cursor::new(vec![9; 10_000]), &mut writer).unwrap();
+        assert_eq!(copied, 10_000);
+        assert_eq!(bar.position(), 10_000);
+        assert!(!bar.is_finished());
+
+        drop(writer);
+        assert!(bar.is_finished());
+    }
+
+    #[test]
+    fn copy_between_wrappers() {
+        let clock = ManualClock::new();
+        let mut reader = ProgressReader::new(Cursor::new(vec![1; 4096]), bar(&clock));
+        let mut writer = ProgressWriter::new(Vec::new(), bar(&clock));
+
+        io::copy(&mut reader, &mut writer).unwrap();
+        assert_eq!(reader.bar().position(), 4096);
+        assert_eq!(writer.bar().position(), 4096);
+    }
+
+    #[test]
+    fn errors_do_not_advance() {
+        let mut writer = ProgressWriter::new(Failing, bar(&ManualClock::new()));
+
+        assert!(writer.write(&[0; 4]).is_err());
+        assert_eq!(writer.bar().position(), 0);
+    }
+}
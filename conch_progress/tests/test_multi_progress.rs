@@ -0,0 +1,218 @@
+//! Test drawing of [`MultiProgress`].
+
+mod common;
+
+use std::io;
+use std::thread;
+
+use conch_progress::{BarStyle, MultiProgress, ProgressBar, ProgressFinish};
+
+use common::Capture;
+
+/// A 4 steps bar, 4 columns wide.
+fn bar(prefix: &str) -> ProgressBar {
+    ProgressBar::new(4)
+        .prefix(prefix)
+        .style(BarStyle::ascii().width(4))
+        .target(io::sink())
+}
+
+/// A [`MultiProgress`] drawing to a [`Capture`], with bars `a` and `b` added to it.
+fn multi(capture: &Capture) -> (MultiProgress, ProgressBar, ProgressBar) {
+    let multi = MultiProgress::new().target(capture.clone());
+    let a = multi.add(bar("a"));
+    let b = multi.add(bar("b"));
+    capture.take();
+
+    (multi, a, b)
+}
+
+#[test]
+fn add_draws_below() {
+    let capture = Capture::default();
+    let multi = MultiProgress::new().target(capture.clone());
+
+    let _a = multi.add(bar("a"));
+    assert_eq!(capture.take(), "\ra ---- 0/4   0%\x1b[K");
+
+    let _b = multi.add(bar("b"));
+    assert_eq!(
+        capture.take(),
+        "\ra ---- 0/4   0%\x1b[K\nb ---- 0/4   0%\x1b[K"
+    );
+    assert_eq!(multi.len(), 2);
+}
+
+#[test]
+fn update_redraws_only_changed_line() {
+    let capture = Capture::default();
+    let (_multi, a, b) = multi(&capture);
+
+    a.inc(1);
+    assert_eq!(capture.take(), "\x1b[1A\ra #--- 1/4  25%\x1b[K\x1b[1B");
+
+    b.inc(2);
+    assert_eq!(capture.take(), "\rb ##-- 2/4  50%\x1b[K");
+}
+
+#[test]
+fn unchanged_line_not_redrawn() {
+    let capture = Capture::default();
+    let (_multi, a, _b) = multi(&capture);
+
+    a.tick();
+    a.set_message("");
+    assert_eq!(capture.take(), "");
+}
+
+#[test]
+fn finished_bars_left() {
+    let capture = Capture::default();
+    let (multi, a, b) = multi(&capture);
+
+    a.finish();
+    assert_eq!(
+        capture.take(),
+        "\x1b[1A\ra ---- 0/4   0%\x1b[K\nb ---- 0/4   0%\x1b[K"
+    );
+    assert_eq!(multi.len(), 2);
+
+    b.inc(4);
+    b.finish();
+    assert_eq!(
+        capture.take(),
+        [
+            "\rb #### 4/4 100%\x1b[K",
+            "\x1b[1A\ra ---- 0/4   0%\x1b[K\nb #### 4/4 100%\x1b[K",
+            "\n",
+        ]
+        .concat()
+    );
+    assert!(multi.is_empty());
+}
+
+#[test]
+fn finished_bars_cleared() {
+    let capture = Capture::default();
+    let multi = MultiProgress::new().target(capture.clone());
+    let a = multi.add(bar("a").on_finish(ProgressFinish::Clear));
+    let _b = multi.add(bar("b"));
+    capture.take();
+
+    a.finish();
+    assert_eq!(
+        capture.take(),
+        "\x1b[1A\rb ---- 0/4   0%\x1b[K\n\x1b[K\x1b[1A"
+    );
+    assert_eq!(multi.lines(), vec!["b ---- 0/4   0%"]);
+}
+
+#[test]
+fn finished_bars_collapsed() {
+    let capture = Capture::default();
+    let multi = MultiProgress::new()
+        .collapse_finished(true)
+        .target(capture.clone());
+    let a = multi.add(bar("a"));
+    let b = multi.add(bar("b"));
+    let c = multi.add(bar("c"));
+
+    a.finish();
+    assert_eq!(
+        multi.lines(),
+        vec!["1 finished", "b ---- 0/4   0%", "c ---- 0/4   0%"]
+    );
+
+    c.finish();
+    assert_eq!(multi.lines(), vec!["2 finished", "b ---- 0/4   0%"]);
+
+    capture.take();
+    b.inc(1);
+    assert_eq!(capture.take(), "\rb #--- 1/4  25%\x1b[K");
+}
+
+#[test]
+fn custom_summary() {
+    let multi = MultiProgress::new()
+        .collapse_finished(true)
+        .summary(|count| format!("✔ {count} downloads"))
+        .target(io::sink());
+    let a = multi.add(bar("a"));
+    let _b = multi.add(bar("b"));
+
+    a.finish();
+    assert_eq!(multi.lines(), vec!["✔ 1 downloads", "b ---- 0/4   0%"]);
+}
+
+#[test]
+fn new_bars_below_finished_block() {
+    let capture = Capture::default();
+    let (multi, a, b) = multi(&capture);
+
+    a.finish();
+    b.finish();
+    capture.take();
+
+    let _c = multi.add(bar("c"));
+    assert_eq!(capture.take(), "\rc ---- 0/4   0%\x1b[K");
+    assert_eq!(multi.lines(), vec!["c ---- 0/4   0%"]);
+}
+
+#[test]
+fn removed_bar_not_drawn() {
+    let capture = Capture::default();
+    let (multi, a, b) = multi(&capture);
+
+    multi.remove(&a);
+    assert_eq!(
+        capture.take(),
+        "\x1b[1A\rb ---- 0/4   0%\x1b[K\n\x1b[K\x1b[1A"
+    );
+
+    a.inc(1);
+    a.finish();
+    assert_eq!(capture.take(), "");
+    assert_eq!(multi.lines(), vec!["b ---- 0/4   0%"]);
+
+    // Removing a bar twice, or one from elsewhere, has no effect.
+    multi.remove(&a);
+    MultiProgress::new().target(io::sink()).remove(&b);
+    assert_eq!(capture.take(), "");
+    assert_eq!(multi.len(), 1);
+}
+
+#[test]
+fn retargeted_bar_leaves() {
+    let capture = Capture::default();
+    let (multi, a, _b) = multi(&capture);
+
+    let a = a.target(io::sink());
+    a.inc(1);
+    assert_eq!(multi.lines(), vec!["b ---- 0/4   0%"]);
+}
+
+#[test]
+fn updated_from_threads() {
+    let multi = MultiProgress::new().target(io::sink());
+    let bars = (0..4)
+        .map(|idx| multi.add(bar(&idx.to_string())))
+        .collect::<Vec<_>>();
+
+    bars.iter()
+        .cloned()
+        .map(|bar| {
+            thread::spawn(move || {
+                (0..3).for_each(|_| bar.inc(1));
+            })
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .for_each(|handle| handle.join().unwrap());
+
+    assert_eq!(
+        multi.lines(),
+        (0..4)
+            .map(|idx| format!("{idx} ###- 3/4  75%"))
+            .collect::<Vec<_>>()
+    );
+}
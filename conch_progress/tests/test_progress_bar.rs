@@ -0,0 +1,214 @@
+//! Test drawing of [`ProgressBar`] and [`ProgressIterator`].
+
+mod common;
+
+use std::io;
+
+use conch_ansi::Modifier;
+use conch_progress::{BarStyle, ProgressBar, ProgressFinish, ProgressIterator};
+
+use common::Capture;
+
+/// A 4 steps bar, 4 columns wide, drawing to a [`Capture`].
+fn bar(capture: &Capture) -> ProgressBar {
+    ProgressBar::new(4)
+        .style(BarStyle::ascii().width(4))
+        .target(capture.clone())
+}
+
+#[cfg(test)]
+mod test_bar_style {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $style:expr,
+            $fraction:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let style: BarStyle = $style;
+
+                assert_eq!(style.render($fraction), $expected);
+            }
+        };
+    }
+
+    test_factory!(empty, BarStyle::new().width(5), 0.0, "░░░░░");
+    test_factory!(partial, BarStyle::new().width(5), 0.5, "██░░░");
+    test_factory!(full, BarStyle::new().width(5), 1.0, "█████");
+    test_factory!(clamped, BarStyle::new().width(5), 1.5, "█████");
+    test_factory!(
+        head_left_out_when_full,
+        BarStyle::ascii().width(5).head(">"),
+        1.0,
+        "#####"
+    );
+    test_factory!(
+        modifiers,
+        BarStyle::ascii()
+            .width(4)
+            .head(">")
+            .filled_modifier(Modifier::colour("Green").unwrap())
+            .head_modifier(Modifier::intensity("Bold").unwrap())
+            .empty_modifier(Modifier::colour("BrightBlack").unwrap()),
+        0.25,
+        "\x1b[38;5;2m#\x1b[39m\x1b[1m>\x1b[22m\x1b[38;5;8m--\x1b[39m"
+    );
+}
+
+#[cfg(test)]
+mod test_progress_bar {
+    use super::*;
+
+    #[test]
+    fn render_without_length() {
+        let bar = ProgressBar::without_length()
+            .style(BarStyle::ascii().width(4))
+            .target(io::sink());
+        bar.inc(7);
+
+        assert_eq!(bar.render(), "---- 7");
+    }
+
+    #[test]
+    fn render_empty_length() {
+        let bar = ProgressBar::new(0)
+            .style(BarStyle::ascii().width(4))
+            .target(io::sink());
+
+        assert_eq!(bar.render(), "#### 0/0 100%");
+    }
+
+    #[test]
+    fn redrawn_in_place() {
+        let capture = Capture::default();
+        let bar = bar(&capture);
+        bar.tick();
+        bar.inc(1);
+        bar.inc(0);
+        bar.set_message("done");
+
+        assert_eq!(
+            capture.output(),
+            [
+                "\r---- 0/4   0%\x1b[K",
+                "\r#--- 1/4  25%\x1b[K",
+                "\r#--- 1/4  25% done\x1b[K",
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn finish_leaves_bar() {
+        let capture = Capture::default();
+        let bar = bar(&capture);
+        bar.set_position(4);
+        bar.finish();
+        bar.inc(1);
+        bar.finish();
+
+        assert_eq!(capture.output(), "\r#### 4/4 100%\x1b[K\n");
+        assert_eq!(bar.position(), 5);
+    }
+
+    #[test]
+    fn finish_clears_bar() {
+        let capture = Capture::default();
+        let bar = bar(&capture).on_finish(ProgressFinish::Clear);
+        bar.inc(2);
+        bar.finish();
+
+        assert_eq!(capture.output(), "\r##-- 2/4  50%\x1b[K\r\x1b[K");
+    }
+
+    #[test]
+    fn dropped_bar_finished() {
+        let capture = Capture::default();
+        let bar = bar(&capture);
+        bar.inc(1);
+        drop(bar);
+
+        assert_eq!(capture.output(), "\r#--- 1/4  25%\x1b[K\n");
+    }
+}
+
+#[cfg(test)]
+mod test_progress_iterator {
+    use super::*;
+
+    #[test]
+    fn length_from_size_hint() {
+        let capture = Capture::default();
+        let bar = ProgressBar::without_length()
+            .style(BarStyle::ascii().width(4))
+            .target(capture.clone());
+        let items: Vec<char> = "veni".chars().progress_with(bar.clone()).collect();
+
+        assert_eq!(items, vec!['v', 'e', 'n', 'i']);
+        assert_eq!(bar.length(), Some(4));
+        assert_eq!(
+            capture.output(),
+            [
+                "\r---- 0/4   0%\x1b[K",
+                "\r#--- 1/4  25%\x1b[K",
+                "\r##-- 2/4  50%\x1b[K",
+                "\r###- 3/4  75%\x1b[K",
+                "\r#### 4/4 100%\x1b[K",
+                "\n",
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn unbounded_without_length() {
+        let bar = ProgressBar::without_length().target(io::sink());
+        let evens: Vec<u32> = (0..)
+            .progress_with(bar.clone())
+            .filter(|n| n % 2 == 0)
+            .take(3)
+            .collect();
+
+        assert_eq!(evens, vec![0, 2, 4]);
+        assert_eq!(bar.length(), None);
+        assert_eq!(bar.position(), 5);
+        assert!(bar.is_finished());
+    }
+
+    #[test]
+    fn explicit_length_kept() {
+        let bar = ProgressBar::new(10).target(io::sink());
+        (0..4).progress_with(bar.clone()).for_each(drop);
+
+        assert_eq!(bar.length(), Some(10));
+        assert_eq!(bar.position(), 4);
+    }
+
+    #[test]
+    fn progress_count() {
+        let iter = (0..4).progress_count(8);
+
+        assert_eq!(iter.bar().length(), Some(8));
+        assert_eq!(iter.len(), 4);
+    }
+
+    #[test]
+    fn cleaned_up_when_dropped_early() {
+        let capture = Capture::default();
+        let bar = bar(&capture);
+
+        for item in (0..4).progress_with(bar.clone()) {
+            if item == 1 {
+                break;
+            }
+        }
+
+        assert!(bar.is_finished());
+        assert!(capture.output().ends_with("\r##-- 2/4  50%\x1b[K\n"));
+    }
+}
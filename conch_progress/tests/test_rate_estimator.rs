@@ -0,0 +1,231 @@
+//! Test rate estimation, with [`RateEstimator`] and through [`ProgressBar`]s on a
+//! [`ManualClock`].
+
+use std::time::{Duration, Instant};
+
+use conch_progress::{Clock, ManualClock, ProgressBar, RateEstimator, Template};
+
+/// Whether `left` and `right` are equal, give or take rounding errors.
+fn approx(left: Option<f64>, right: f64) -> bool {
+    left.is_some_and(|left| (left - right).abs() < 1e-9)
+}
+
+/// Seconds after `start`.
+fn secs(start: Instant, secs: f64) -> Instant {
+    start + Duration::from_secs_f64(secs)
+}
+
+/// A [`RateEstimator`] that went at 10 steps per second for 10 seconds.
+fn steady(start: Instant) -> RateEstimator {
+    let mut estimator = RateEstimator::new(start, 0).window(Duration::from_secs(5));
+    (1..=10).for_each(|sec| estimator.record(secs(start, sec as f64), sec * 10));
+    estimator
+}
+
+#[cfg(test)]
+mod test_rate_estimator {
+    use super::*;
+
+    #[test]
+    fn no_time_passed() {
+        let start = Instant::now();
+        let mut estimator = RateEstimator::new(start, 0);
+        estimator.record(start, 10);
+
+        assert_eq!(estimator.rate(start), None);
+        assert_eq!(estimator.windowed_rate(start), None);
+        assert_eq!(estimator.eta(start, 10), None);
+    }
+
+    #[test]
+    fn progress_at_start() {
+        let start = Instant::now();
+        let mut estimator = RateEstimator::new(start, 0);
+
+        // Progress made at the very start, such as resuming from a position, is not
+        // made at any rate.
+        estimator.record(start, 50);
+        estimator.record(secs(start, 1.0), 60);
+
+        let now = secs(start, 1.0);
+        assert!(approx(estimator.smoothed_rate(now), 10.0));
+        assert!(approx(estimator.windowed_rate(now), 10.0));
+        assert_eq!(estimator.eta(now, 40), Some(Duration::from_secs(4)));
+    }
+
+    #[test]
+    fn steady_rate() {
+        let start = Instant::now();
+        let estimator = steady(start);
+        let now = secs(start, 10.0);
+
+        assert!(approx(estimator.smoothed_rate(now), 10.0));
+        assert!(approx(estimator.windowed_rate(now), 10.0));
+        assert_eq!(estimator.eta(now, 50), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn smoothed_burst() {
+        let start = Instant::now();
+        let mut estimator = steady(start);
+        estimator.record(secs(start, 11.0), 200);
+
+        // A second is half of the half life: the burst weighs 1 - 2^-0.5.
+        let weight = 0.5_f64.sqrt();
+        assert!(approx(
+            estimator.smoothed_rate(secs(start, 11.0)),
+            10.0 * weight + 100.0 * (1.0 - weight),
+        ));
+    }
+
+    #[test]
+    fn records_in_bursts_weigh_by_time() {
+        let start = Instant::now();
+        let mut estimator = steady(start);
+
+        // Many records within a single instant count as one.
+        (101..=110).for_each(|position| estimator.record(secs(start, 12.0), position));
+        let now = secs(start, 12.0);
+
+        // 10 steps in 2 seconds, which is the half life.
+        assert!(approx(estimator.smoothed_rate(now), 10.0 * 0.5 + 5.0 * 0.5));
+    }
+
+    #[test]
+    fn stall_brings_rates_down() {
+        let start = Instant::now();
+        let estimator = steady(start);
+        let now = secs(start, 14.0);
+
+        // The last interval, still going on, is now 10 steps in 5 seconds.
+        let weight = 0.5_f64.powf(2.5);
+        assert!(approx(
+            estimator.smoothed_rate(now),
+            10.0 * weight + 2.0 * (1.0 - weight)
+        ));
+        assert!(approx(estimator.windowed_rate(now), 50.0 / 9.0));
+        assert_eq!(estimator.rate(now), estimator.smoothed_rate(now));
+    }
+
+    #[test]
+    fn window_slides() {
+        let start = Instant::now();
+        let mut estimator = steady(start);
+        (11..=15).for_each(|sec| estimator.record(secs(start, sec as f64), 100 + (sec - 10) * 30));
+
+        assert!(approx(estimator.windowed_rate(secs(start, 15.0)), 30.0));
+    }
+
+    #[test]
+    fn going_back_starts_over() {
+        let start = Instant::now();
+        let mut estimator = steady(start);
+        estimator.record(secs(start, 11.0), 5);
+
+        assert_eq!(estimator.rate(secs(start, 11.0)), None);
+        estimator.record(secs(start, 12.0), 7);
+        assert!(approx(estimator.rate(secs(start, 12.0)), 2.0));
+    }
+
+    #[test]
+    fn no_progress_no_eta() {
+        let start = Instant::now();
+        let estimator = RateEstimator::new(start, 0);
+
+        assert!(approx(estimator.rate(secs(start, 1.0)), 0.0));
+        assert_eq!(estimator.eta(secs(start, 1.0), 10), None);
+    }
+
+    #[test]
+    fn half_life() {
+        let start = Instant::now();
+        let mut estimator = RateEstimator::new(start, 0).half_life(Duration::from_secs(1));
+        estimator.record(secs(start, 1.0), 10);
+        estimator.record(secs(start, 2.0), 40);
+
+        assert!(approx(estimator.smoothed_rate(secs(start, 2.0)), 20.0));
+    }
+}
+
+#[cfg(test)]
+mod test_bar_clock {
+    use super::*;
+
+    /// A bar of `length` steps on `clock`, laid out by `template`.
+    fn bar(clock: &ManualClock, length: u64, template: &str) -> ProgressBar {
+        ProgressBar::new(length)
+            .clock(clock.clone())
+            .template(template.parse::<Template>().unwrap())
+            .width(40)
+            .target(std::io::sink())
+    }
+
+    #[test]
+    fn manual_clock() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        let other = clock.clone();
+        other.advance(Duration::from_millis(1500));
+
+        assert_eq!(clock.now() - start, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn elapsed_and_eta() {
+        let clock = ManualClock::new();
+        let bar = bar(&clock, 100, "{elapsed} eta {eta} at {rate}");
+        assert_eq!(bar.render(), "0s eta ? at 0.0/s");
+
+        (0..4).for_each(|_| {
+            clock.advance(Duration::from_secs(1));
+            bar.inc(5);
+        });
+        assert_eq!(bar.render(), "4s eta 16s at 5.0/s");
+        assert_eq!(bar.elapsed(), Duration::from_secs(4));
+        assert!((bar.eta().unwrap().as_secs_f64() - 16.0).abs() < 1e-6);
+        assert!((bar.rate() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn eta_grows_while_stalled() {
+        let clock = ManualClock::new();
+        let bar = bar(&clock, 100, "eta {eta}");
+
+        clock.advance(Duration::from_secs(1));
+        bar.inc(10);
+        assert_eq!(bar.render(), "eta 9s");
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(bar.render(), "eta 27s");
+    }
+
+    #[test]
+    fn bytes_rate() {
+        let clock = ManualClock::new();
+        let bar = bar(&clock, 10 << 20, "{bytes}/{total_bytes} {bytes_rate}");
+
+        clock.advance(Duration::from_secs(2));
+        bar.set_position(3 << 20);
+        assert_eq!(bar.render(), "3.0 MiB/10.0 MiB 1.5 MiB/s");
+    }
+
+    #[test]
+    fn human_counts() {
+        let clock = ManualClock::new();
+        let bar = bar(&clock, 1_250_000, "{human_pos}/{human_len}");
+
+        bar.set_position(12_345);
+        assert_eq!(bar.render(), "12,345/1,250,000");
+    }
+
+    #[test]
+    fn clock_restarts_bar() {
+        let clock = ManualClock::new();
+        clock.advance(Duration::from_secs(30));
+        let bar = ProgressBar::new(10)
+            .target(std::io::sink())
+            .clock(clock.clone());
+
+        assert_eq!(bar.elapsed(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,264 @@
+//! Test drawing of spinners and pulsing bars.
+
+mod common;
+
+use std::io;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use conch_ansi::Modifier;
+use conch_progress::{BarStyle, MultiProgress, ProgressBar, SpinnerStyle, Template};
+
+use common::Capture;
+
+/// Render `bar` after each of `ticks` ticks.
+fn frames(bar: &ProgressBar, ticks: usize) -> Vec<String> {
+    (0..ticks)
+        .map(|_| {
+            let line = bar.render();
+            bar.tick();
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_frames {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $style:expr,
+            $expected:expr
+        ) => {
+            #[test]
+            fn $name() {
+                let spinner = ProgressBar::spinner()
+                    .spinner_style($style)
+                    .target(io::sink());
+                let expected: &[&str] = &$expected;
+
+                assert_eq!(frames(&spinner, expected.len()), expected);
+            }
+        };
+    }
+
+    test_factory!(
+        dots,
+        SpinnerStyle::dots(),
+        ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏", "⠋"]
+    );
+    test_factory!(line, SpinnerStyle::line(), ["-", "\\", "|", "/", "-"]);
+    test_factory!(
+        arc,
+        SpinnerStyle::arc(),
+        ["◜", "◠", "◝", "◞", "◡", "◟", "◜"]
+    );
+    test_factory!(
+        bouncing_bar,
+        SpinnerStyle::bouncing_bar(),
+        ["[    ]", "[=   ]", "[==  ]", "[=== ]", "[====]", "[ ===]"]
+    );
+    test_factory!(
+        custom,
+        SpinnerStyle::new(["a", "b"]).modifier(Modifier::intensity("Bold").unwrap()),
+        ["\x1b[1ma\x1b[22m", "\x1b[1mb\x1b[22m", "\x1b[1ma\x1b[22m"]
+    );
+    test_factory!(no_frames, SpinnerStyle::new(Vec::<String>::new()), ["", ""]);
+}
+
+#[cfg(test)]
+mod test_pulse {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $style:expr,
+            $expected:expr
+        ) => {
+            #[test]
+            fn $name() {
+                let style = $style;
+                let expected: &[&str] = &$expected;
+
+                assert_eq!(
+                    (0..expected.len() as u64)
+                        .map(|ticks| style.render_pulse(ticks))
+                        .collect::<Vec<_>>(),
+                    expected
+                );
+            }
+        };
+    }
+
+    test_factory!(
+        bounces,
+        BarStyle::ascii().width(5).pulse(2),
+        ["##---", "-##--", "--##-", "---##", "--##-", "-##--", "##---"]
+    );
+    test_factory!(
+        full_width,
+        BarStyle::ascii().width(3).pulse(5),
+        ["###", "###"]
+    );
+    test_factory!(
+        zero_pulse,
+        BarStyle::ascii().width(3).pulse(0),
+        ["#--", "-#-", "--#"]
+    );
+    test_factory!(without_pulse, BarStyle::ascii().width(3), ["---", "---"]);
+    test_factory!(
+        styled,
+        BarStyle::ascii()
+            .width(3)
+            .pulse(1)
+            .filled_modifier(Modifier::colour("Red").unwrap()),
+        ["\x1b[38;5;1m#\x1b[39m--", "-\x1b[38;5;1m#\x1b[39m-"]
+    );
+}
+
+#[test]
+fn pulse_without_length() {
+    let bar = ProgressBar::without_length()
+        .style(BarStyle::ascii().width(4).pulse(2))
+        .target(io::sink());
+
+    assert_eq!(frames(&bar, 3), vec!["##-- 0", "-##- 0", "--## 0"]);
+
+    bar.set_length(4);
+    assert_eq!(bar.render(), "---- 0/4   0%");
+}
+
+#[test]
+fn prefix_and_message() {
+    let spinner = ProgressBar::spinner()
+        .spinner_style(SpinnerStyle::line())
+        .prefix("[1/3]")
+        .message("Resolving")
+        .target(io::sink());
+
+    assert_eq!(spinner.render(), "- [1/3] Resolving");
+
+    spinner.set_message("Fetching");
+    assert_eq!(spinner.render(), "- [1/3] Fetching");
+}
+
+#[test]
+fn succeed() {
+    let capture = Capture::default();
+    let spinner = ProgressBar::spinner()
+        .spinner_style(SpinnerStyle::line())
+        .message("Resolving")
+        .target(capture.clone());
+
+    spinner.tick();
+    spinner.succeed("Resolved");
+    assert!(spinner.is_finished());
+    assert_eq!(
+        capture.output(),
+        "\r\\ Resolving\x1b[K\r\x1b[38;5;10m✔\x1b[39m Resolved\x1b[K\n"
+    );
+
+    // Finishing again, or ticking, has no effect.
+    spinner.fail("Failed");
+    spinner.tick();
+    assert_eq!(spinner.render(), "\x1b[38;5;10m✔\x1b[39m Resolved");
+}
+
+#[test]
+fn fail() {
+    let spinner = ProgressBar::spinner()
+        .message("Resolving")
+        .target(io::sink());
+
+    spinner.fail("Could not resolve");
+    assert_eq!(spinner.render(), "\x1b[38;5;9m✘\x1b[39m Could not resolve");
+}
+
+#[test]
+fn custom_outcomes() {
+    let style = SpinnerStyle::line()
+        .success("ok")
+        .success_modifier(Modifier::Nothing)
+        .failure("ko")
+        .failure_modifier(Modifier::Nothing);
+
+    let spinner = ProgressBar::spinner()
+        .spinner_style(style.clone())
+        .target(io::sink());
+    spinner.succeed("done");
+    assert_eq!(spinner.render(), "ok done");
+
+    let spinner = ProgressBar::spinner()
+        .spinner_style(style)
+        .target(io::sink());
+    spinner.fail("");
+    assert_eq!(spinner.render(), "ko");
+}
+
+#[test]
+fn outcome_of_bar_keeps_layout() {
+    let bar = ProgressBar::new(2)
+        .style(BarStyle::ascii().width(2))
+        .target(io::sink());
+
+    bar.inc(2);
+    bar.succeed("done");
+    assert_eq!(bar.render(), "## 2/2 100% done");
+}
+
+#[test]
+fn template_spinner() {
+    let template: Template = "{spinner} {msg} ({pos})".parse().unwrap();
+    let spinner = ProgressBar::spinner()
+        .spinner_style(SpinnerStyle::line())
+        .template(template)
+        .message("Scanning")
+        .width(20)
+        .target(io::sink());
+
+    spinner.tick();
+    spinner.inc(3);
+    assert_eq!(spinner.render(), "\\ Scanning (3)");
+}
+
+#[test]
+fn spinners_in_multi_progress() {
+    let multi = MultiProgress::new().target(io::sink());
+    let spinner = multi.add(
+        ProgressBar::spinner()
+            .spinner_style(SpinnerStyle::line())
+            .message("a"),
+    );
+    let _bar = multi.add(ProgressBar::new(2).style(BarStyle::ascii().width(2)));
+
+    spinner.tick();
+    assert_eq!(multi.lines(), vec!["\\ a", "-- 0/2   0%"]);
+
+    spinner.succeed("a");
+    assert_eq!(
+        multi.lines(),
+        vec!["\x1b[38;5;10m✔\x1b[39m a", "-- 0/2   0%"]
+    );
+}
+
+#[test]
+fn steady_tick() {
+    let spinner = ProgressBar::spinner()
+        .spinner_style(SpinnerStyle::line().interval(Duration::from_millis(1)))
+        .target(io::sink());
+    spinner.enable_steady_tick();
+    spinner.enable_steady_tick();
+
+    let started = Instant::now();
+    while spinner.render() == "-" && started.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(1));
+    }
+    assert_ne!(spinner.render(), "-");
+
+    spinner.succeed("done");
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(spinner.render(), "\x1b[38;5;10m✔\x1b[39m done");
+}
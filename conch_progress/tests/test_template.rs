@@ -0,0 +1,245 @@
+//! Test parsing and rendering of [`Template`].
+
+use std::ops::Range;
+
+use conch_progress::{BarStyle, ProgressBar, Template, TemplateErrorKind};
+
+/// A bar at `position` out of `length`, laid out by `template` within 40 columns.
+fn render(template: &str, position: u64, length: Option<u64>) -> String {
+    let bar = match length {
+        Some(length) => ProgressBar::new(length),
+        None => ProgressBar::without_length(),
+    }
+    .prefix("Copying")
+    .message("veni.txt")
+    .style(BarStyle::ascii().width(10))
+    .template(template.parse().unwrap())
+    .width(40)
+    .target(std::io::sink());
+    bar.set_position(position);
+
+    bar.render()
+}
+
+#[cfg(test)]
+mod test_render {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $template:expr,
+            $position:expr,
+            $length:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                assert_eq!(render($template, $position, $length), $expected);
+            }
+        };
+    }
+
+    test_factory!(counts, "{pos}/{len} {percent}%", 3, Some(12), "3/12 25%");
+    test_factory!(
+        unknown_length,
+        "{pos}/{len} {percent}% {total_bytes} eta {eta}",
+        3,
+        None,
+        "3/? ?% ? eta ?"
+    );
+    test_factory!(
+        prefix_and_message,
+        "{prefix}: {msg}",
+        0,
+        Some(1),
+        "Copying: veni.txt"
+    );
+    test_factory!(escaped_braces, "{{{pos}}}", 7, Some(9), "{7}");
+    test_factory!(bar, "[{bar}]", 5, Some(10), "[#####-----]");
+    test_factory!(bar_width, "[{bar:4}]", 5, Some(10), "[##--]");
+    test_factory!(
+        bar_styles,
+        "{bar:4:Bold/fg:BrightBlack}",
+        5,
+        Some(10),
+        "\x1b[1m##\x1b[22m\x1b[38;5;8m--\x1b[39m"
+    );
+    test_factory!(
+        bar_fills_line,
+        "{prefix} [{bar:*}] {percent:>3}%",
+        20,
+        Some(40),
+        "Copying [############-------------]  50%"
+    );
+    test_factory!(
+        right_aligned_fill,
+        "{prefix}{msg:>*}",
+        0,
+        Some(1),
+        "Copying                         veni.txt"
+    );
+    test_factory!(
+        fills_split_evenly,
+        "{bar:*}|{msg:^*}",
+        1,
+        Some(2),
+        "#########----------|      veni.txt      "
+    );
+    test_factory!(
+        fill_truncated,
+        "{prefix:35}{msg:*}",
+        0,
+        Some(1),
+        "Copying                            veni…"
+    );
+    test_factory!(
+        fixed_widths,
+        "[{pos:>4}|{len:<4}|{percent:^5}]",
+        1,
+        Some(10),
+        "[   1|10  | 10  ]"
+    );
+    test_factory!(
+        styled_text,
+        "{prefix:Bold fg:Cyan} {msg:Italic}",
+        0,
+        Some(1),
+        "\x1b[1m\x1b[38;5;6mCopying\x1b[39m\x1b[22m \x1b[3mveni.txt\x1b[23m"
+    );
+    test_factory!(
+        styles_in_any_case,
+        "{prefix:bold fg:Cyan} {msg:ITALIC}",
+        0,
+        Some(1),
+        "\x1b[1m\x1b[38;5;6mCopying\x1b[39m\x1b[22m \x1b[3mveni.txt\x1b[23m"
+    );
+    test_factory!(
+        bytes,
+        "{bytes}/{total_bytes}",
+        1536,
+        Some(3 << 20),
+        "1.5 KiB/3.0 MiB"
+    );
+    test_factory!(small_bytes, "{bytes}", 512, Some(1024), "512 B");
+    test_factory!(elapsed, "{elapsed}", 0, Some(1), "0s");
+}
+
+#[test]
+fn lowercase_style_names() {
+    let lowercase =
+        "{prefix:bold} [{bar:40:BrightGreen/Grayscale08}] {pos}/{len} {percent}% eta {eta} {msg}";
+    let capitalised =
+        "{prefix:Bold} [{bar:40:BrightGreen/Grayscale08}] {pos}/{len} {percent}% eta {eta} {msg}";
+
+    assert_eq!(
+        lowercase.parse::<Template>().unwrap(),
+        capitalised.parse::<Template>().unwrap()
+    );
+}
+
+#[cfg(test)]
+mod test_parse_errors {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $template:expr,
+            $kind:expr,
+            $span:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let err = $template.parse::<Template>().unwrap_err();
+                println!("{}", err.render($template));
+
+                assert_eq!(err.kind, $kind);
+                let span: Range<usize> = $span;
+                assert_eq!(err.span, span);
+            }
+        };
+    }
+
+    test_factory!(
+        unclosed,
+        "{bar} {pos",
+        TemplateErrorKind::UnclosedPlaceholder,
+        6..10
+    );
+    test_factory!(
+        nested,
+        "{bar {pos}",
+        TemplateErrorKind::UnclosedPlaceholder,
+        0..5
+    );
+    test_factory!(
+        unmatched,
+        "{bar} pos}",
+        TemplateErrorKind::UnmatchedBrace,
+        9..10
+    );
+    test_factory!(
+        unknown_key,
+        "{bar} {size}",
+        TemplateErrorKind::UnknownKey,
+        7..11
+    );
+    test_factory!(empty_key, "{bar} {}", TemplateErrorKind::UnknownKey, 7..7);
+    test_factory!(
+        invalid_width,
+        "{bar:4O}",
+        TemplateErrorKind::InvalidWidth,
+        5..7
+    );
+    test_factory!(
+        invalid_aligned_width,
+        "{msg:>x}",
+        TemplateErrorKind::InvalidWidth,
+        6..7
+    );
+    test_factory!(
+        invalid_style,
+        "{bar:Bold fg:Bleu}",
+        TemplateErrorKind::InvalidStyle,
+        13..17
+    );
+    test_factory!(
+        invalid_empty_style,
+        "{bar:Green/Bleu}",
+        TemplateErrorKind::InvalidStyle,
+        11..15
+    );
+    test_factory!(
+        split_style_on_text,
+        "{msg:Green/Red}",
+        TemplateErrorKind::InvalidStyle,
+        10..11
+    );
+    test_factory!(
+        duplicate_width,
+        "{bar:10:20}",
+        TemplateErrorKind::DuplicateOption,
+        8..10
+    );
+    test_factory!(
+        duplicate_style,
+        "{bar:Bold:Green}",
+        TemplateErrorKind::DuplicateOption,
+        10..15
+    );
+
+    #[test]
+    fn message() {
+        let err = "{bar} {size}".parse::<Template>().unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Template: unknown placeholder; expected one of `prefix`, `msg`, `bar`, `spinner`, \
+            `pos`, `len`, `human_pos`, `human_len`, `percent`, `elapsed`, `eta`, `rate`, \
+            `bytes`, `total_bytes` or `bytes_rate`, found `size` at byte 7"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use conch_base_models::visible_width;
+
+/// Horizontal alignment of a text within a fixed width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+
+    /// Align numbers by their decimal points, such that the integer parts line up to
+    /// the right and the fractional parts to the left.
+    ///
+    /// This only makes sense across multiple lines, such as in a column of a
+    /// [`crate::Table`]; a single line is aligned as [`Alignment::Right`].
+    Decimal,
+
+    /// Stretch the spaces between words, such that the text fills the width exactly.
+    ///
+    /// The last line of a paragraph is conventionally not justified; use
+    /// [`Alignment::last_line()`] for it. A single word is aligned as
+    /// [`Alignment::Left`].
+    Justify,
+}
+impl Alignment {
+    /// Pad `text` with spaces to `width` columns according to this alignment.
+    ///
+    /// Widths are measured ignoring any escape codes; `text` wider than `width` is
+    /// returned unchanged.
+    ///
+    /// ```rust
+    /// use conch_split::Alignment;
+    ///
+    /// assert_eq!(Alignment::Center.pad("\x1b[1mveni\x1b[22m", 8), "  \x1b[1mveni\x1b[22m  ");
+    /// assert_eq!(Alignment::Right.pad("vidi", 6), "  vidi");
+    /// assert_eq!(Alignment::Justify.pad("veni vidi vici", 17), "veni   vidi  vici");
+    /// ```
+    pub fn pad(&self, text: &str, width: usize) -> String {
+        let space = width.saturating_sub(visible_width(text));
+
+        let (left, right) = match self {
+            Self::Justify => return justify(text, width),
+            Self::Left => (0, space),
+            Self::Center => (space / 2, space - space / 2),
+            Self::Right | Self::Decimal => (space, 0),
+        };
+
+        " ".repeat(left) + text + &" ".repeat(right)
+    }
+
+    /// The alignment to use for the last line of a paragraph, which is not
+    /// justified.
+    pub fn last_line(&self) -> Self {
+        match self {
+            Self::Justify => Self::Left,
+            alignment => *alignment,
+        }
+    }
+}
+
+/// Stretch the spaces between the words of `text` to fill `width` columns, giving
+/// any remainder to the leftmost gaps.
+///
+/// Leading whitespace is kept as indentation; the words are separated by single
+/// spaces before being stretched.
+fn justify(text: &str, width: usize) -> String {
+    let content = text.trim_start_matches(' ');
+    let indent = &text[..text.len() - content.len()];
+    let words: Vec<&str> = content.split(' ').filter(|word| !word.is_empty()).collect();
+
+    let gaps = words.len().saturating_sub(1);
+    let used = visible_width(indent) + words.iter().map(|word| visible_width(word)).sum::<usize>();
+
+    if gaps == 0 || used + gaps > width {
+        return Alignment::Left.pad(text, width);
+    }
+
+    let space = width - used;
+    words
+        .iter()
+        .enumerate()
+        .fold(indent.to_string(), |mut line, (idx, word)| {
+            if idx > 0 {
+                let gap = space / gaps + usize::from(idx <= space % gaps);
+                line.push_str(&" ".repeat(gap));
+            }
+            line.push_str(word);
+            line
+        })
+}
+
+/// Splits a number into the widths of its integer and fractional parts, for
+/// [`Alignment::Decimal`].
+///
+/// The fractional part includes the decimal point itself; texts without a decimal
+/// point are all integer part.
+pub(crate) fn decimal_widths(text: &str) -> (usize, usize) {
+    match text.rfind('.') {
+        Some(idx) => (visible_width(&text[..idx]), visible_width(&text[idx..])),
+        None => (visible_width(text), 0),
+    }
+}
+
+/// What to do with a text that is wider than the space it is given.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Wrap the text into multiple lines, as in [`crate::Lines::from_text()`].
+    #[default]
+    Wrap,
+
+    /// Cut the text short, ending it with `…`.
+    Truncate,
+}
+
+/// Which lines to keep when a [`crate::Lines`] is cut short to a maximum height.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Truncation {
+    /// Keep the first lines.
+    #[default]
+    Head,
+
+    /// Keep the last lines.
+    Tail,
+
+    /// Keep the first and last lines, leaving out the middle; if the lines kept
+    /// cannot be split evenly, the first lines get the extra one.
+    HeadAndTail,
+}
+
+/// Vertical alignment of a block of lines within a fixed height.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum VerticalAlignment {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+impl VerticalAlignment {
+    /// Split `space` blank lines into those above and below the block.
+    pub(crate) fn split(&self, space: usize) -> (usize, usize) {
+        match self {
+            Self::Top => (0, space),
+            Self::Middle => (space / 2, space - space / 2),
+            Self::Bottom => (space, 0),
+        }
+    }
+}
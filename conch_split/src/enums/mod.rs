@@ -0,0 +1,2 @@
+mod alignment;
+pub use alignment::*;
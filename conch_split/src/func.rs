@@ -0,0 +1,22 @@
+//! Helper functions for laying out text.
+
+use terminal_size::{terminal_size, Width};
+
+/// Width assumed when it cannot be found out from the terminal.
+pub const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+/// Find the width of the terminal in columns.
+///
+/// If `stdout` is not a terminal, this falls back to the `COLUMNS` environment
+/// variable, then to [`DEFAULT_TERMINAL_WIDTH`].
+pub fn terminal_width() -> usize {
+    terminal_size()
+        .map(|(Width(width), _)| usize::from(width))
+        .or_else(|| {
+            std::env::var("COLUMNS")
+                .ok()
+                .and_then(|columns| columns.trim().parse().ok())
+        })
+        .filter(|width| *width > 0)
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+}
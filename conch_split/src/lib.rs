@@ -1,3 +1,9 @@
 //! Text splitting functions.
+mod enums;
+pub use enums::*;
+
+mod func;
+pub use func::*;
+
 mod structs;
 pub use structs::*;
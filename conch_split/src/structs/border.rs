@@ -0,0 +1,169 @@
+/// A set of characters for drawing borders around and within a [`crate::Table`].
+///
+/// Each part is a [`str`] that is one column wide, or empty if that part should not
+/// be drawn at all. If [`Self::top`], [`Self::rule`] or [`Self::bottom`] is empty,
+/// the corresponding horizontal line is left out entirely.
+///
+/// Presets are provided as associated constants, such as [`BorderSet::ROUNDED`]:
+///
+/// ```text
+/// ASCII       LIGHT       HEAVY       ROUNDED
+/// +---+---+   ┌───┬───┐   ┏━━━┳━━━┓   ╭───┬───╮
+/// | a | b |   │ a │ b │   ┃ a ┃ b ┃   │ a │ b │
+/// +---+---+   ├───┼───┤   ┣━━━╋━━━┫   ├───┼───┤
+/// | 1 | 2 |   │ 1 │ 2 │   ┃ 1 ┃ 2 ┃   │ 1 │ 2 │
+/// +---+---+   └───┴───┘   ┗━━━┻━━━┛   ╰───┴───╯
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BorderSet {
+    pub top_left: &'static str,
+    pub top: &'static str,
+    pub top_junction: &'static str,
+    pub top_right: &'static str,
+
+    pub left: &'static str,
+    pub vertical: &'static str,
+    pub right: &'static str,
+
+    /// The left end of the rule between the header and the body.
+    pub rule_left: &'static str,
+    pub rule: &'static str,
+    pub rule_junction: &'static str,
+    pub rule_right: &'static str,
+
+    pub bottom_left: &'static str,
+    pub bottom: &'static str,
+    pub bottom_junction: &'static str,
+    pub bottom_right: &'static str,
+}
+impl BorderSet {
+    /// No borders at all; columns are separated by a single space.
+    pub const NONE: Self = Self {
+        top_left: "",
+        top: "",
+        top_junction: "",
+        top_right: "",
+        left: "",
+        vertical: " ",
+        right: "",
+        rule_left: "",
+        rule: "",
+        rule_junction: "",
+        rule_right: "",
+        bottom_left: "",
+        bottom: "",
+        bottom_junction: "",
+        bottom_right: "",
+    };
+
+    /// Borders drawn with `+`, `-` and `|` only.
+    pub const ASCII: Self = Self {
+        top_left: "+",
+        top: "-",
+        top_junction: "+",
+        top_right: "+",
+        left: "|",
+        vertical: "|",
+        right: "|",
+        rule_left: "+",
+        rule: "-",
+        rule_junction: "+",
+        rule_right: "+",
+        bottom_left: "+",
+        bottom: "-",
+        bottom_junction: "+",
+        bottom_right: "+",
+    };
+
+    /// Light Unicode box drawing characters.
+    pub const LIGHT: Self = Self {
+        top_left: "┌",
+        top: "─",
+        top_junction: "┬",
+        top_right: "┐",
+        left: "│",
+        vertical: "│",
+        right: "│",
+        rule_left: "├",
+        rule: "─",
+        rule_junction: "┼",
+        rule_right: "┤",
+        bottom_left: "└",
+        bottom: "─",
+        bottom_junction: "┴",
+        bottom_right: "┘",
+    };
+
+    /// Heavy Unicode box drawing characters.
+    pub const HEAVY: Self = Self {
+        top_left: "┏",
+        top: "━",
+        top_junction: "┳",
+        top_right: "┓",
+        left: "┃",
+        vertical: "┃",
+        right: "┃",
+        rule_left: "┣",
+        rule: "━",
+        rule_junction: "╋",
+        rule_right: "┫",
+        bottom_left: "┗",
+        bottom: "━",
+        bottom_junction: "┻",
+        bottom_right: "┛",
+    };
+
+    /// Light Unicode box drawing characters with rounded corners.
+    pub const ROUNDED: Self = Self {
+        top_left: "╭",
+        top_right: "╮",
+        bottom_left: "╰",
+        bottom_right: "╯",
+        ..Self::LIGHT
+    };
+
+    /// Draw a horizontal line across columns of `widths`, or [`None`] if `line` is
+    /// empty.
+    pub(crate) fn horizontal(
+        widths: impl IntoIterator<Item = usize>,
+        (left, line, junction, right): (&str, &str, &str, &str),
+    ) -> Option<String> {
+        if line.is_empty() {
+            return None;
+        }
+
+        let segments: Vec<String> = widths.into_iter().map(|width| line.repeat(width)).collect();
+
+        Some(left.to_string() + &segments.join(junction) + right)
+    }
+
+    /// Parts of the line at the top.
+    pub(crate) fn top_parts(&self) -> (&str, &str, &str, &str) {
+        (self.top_left, self.top, self.top_junction, self.top_right)
+    }
+
+    /// Parts of the rule between the header and the body.
+    pub(crate) fn rule_parts(&self) -> (&str, &str, &str, &str) {
+        (
+            self.rule_left,
+            self.rule,
+            self.rule_junction,
+            self.rule_right,
+        )
+    }
+
+    /// Parts of the line at the bottom.
+    pub(crate) fn bottom_parts(&self) -> (&str, &str, &str, &str) {
+        (
+            self.bottom_left,
+            self.bottom,
+            self.bottom_junction,
+            self.bottom_right,
+        )
+    }
+}
+impl Default for BorderSet {
+    fn default() -> Self {
+        Self::LIGHT
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt::Display;
+
+use conch_base_models::visible_width;
+
+use crate::{terminal_width, Alignment, Lines, VerticalAlignment, WrapOptions};
+
+/// How wide a column of [`Columns`] should be.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColumnWidth {
+    /// As wide as the widest line of the column.
+    #[default]
+    Fit,
+
+    /// Exactly this many columns; wider lines are wrapped.
+    Fixed(usize),
+
+    /// A share of the width left over by all the other columns, proportional to
+    /// this weight; wider lines are wrapped.
+    Flex(u16),
+}
+
+/// Several blocks of [`Lines`] joined side by side into one.
+///
+/// Each block is padded to the width of its column, measured ignoring any escape
+/// codes; blocks shorter than the tallest are padded with blank lines according to
+/// the [`VerticalAlignment`].
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{ColumnWidth, Columns, Lines, VerticalAlignment};
+///
+/// let columns = Columns::new()
+///     .push(vec!["Caesar"])
+///     .push_with(vec!["I came, I saw, I conquered."], ColumnWidth::Flex(1))
+///     .vertical_alignment(VerticalAlignment::Middle)
+///     .width(20);
+///
+/// assert_eq!(
+///     Lines::from(&columns).lines,
+///     vec![
+///         "        I came, I   ",
+///         "Caesar  saw, I      ",
+///         "        conquered.  ",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Columns {
+    pub blocks: Vec<(Vec<String>, ColumnWidth)>,
+    gutter: String,
+    vertical_alignment: VerticalAlignment,
+    width: Option<usize>,
+}
+impl Columns {
+    /// Create a new instance of [`Columns`] without any blocks, separated by
+    /// gutters of two spaces.
+    pub fn new() -> Self {
+        Self {
+            blocks: Vec::new(),
+            gutter: String::from("  "),
+            vertical_alignment: VerticalAlignment::Top,
+            width: None,
+        }
+    }
+
+    /// Append a block fitted to its content.
+    pub fn push<L>(self, block: L) -> Self
+    where
+        L: Into<Lines>,
+    {
+        self.push_with(block, ColumnWidth::Fit)
+    }
+
+    /// Append a block with the [`ColumnWidth`] provided.
+    pub fn push_with<L>(mut self, block: L, width: ColumnWidth) -> Self
+    where
+        L: Into<Lines>,
+    {
+        self.blocks.push((block.into().rendered(), width));
+        self
+    }
+
+    /// A chained function to set the separator between the blocks
+    /// on an instance.
+    pub fn gutter<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.gutter = value.to_string();
+        self
+    }
+
+    /// A chained function to set the [`VerticalAlignment`] of blocks shorter than the
+    /// tallest on an instance.
+    pub fn vertical_alignment(mut self, value: VerticalAlignment) -> Self {
+        self.vertical_alignment = value;
+        self
+    }
+
+    /// A chained function to set the total width available to
+    /// [`ColumnWidth::Flex`] blocks on an instance.
+    ///
+    /// If this is not set, the width of the terminal is used.
+    pub fn width(mut self, value: usize) -> Self {
+        self.width = Some(value);
+        self
+    }
+
+    /// Resolve the width of each of the blocks.
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self
+            .blocks
+            .iter()
+            .map(|(lines, width)| match width {
+                ColumnWidth::Fit => lines
+                    .iter()
+                    .map(|line| visible_width(line))
+                    .max()
+                    .unwrap_or(0),
+                ColumnWidth::Fixed(width) => *width,
+                ColumnWidth::Flex(_) => 0,
+            })
+            .collect();
+
+        let weights: Vec<(usize, usize)> = self
+            .blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, (_, width))| match width {
+                ColumnWidth::Flex(weight) => Some((idx, usize::from(*weight))),
+                _ => None,
+            })
+            .collect();
+
+        if weights.is_empty() {
+            return widths;
+        }
+
+        let gutters = visible_width(&self.gutter) * self.blocks.len().saturating_sub(1);
+        let remaining = self
+            .width
+            .unwrap_or_else(terminal_width)
+            .saturating_sub(widths.iter().sum::<usize>() + gutters);
+        let total_weight = weights
+            .iter()
+            .map(|(_, weight)| weight)
+            .sum::<usize>()
+            .max(1);
+
+        let mut allotted = 0;
+        weights.iter().for_each(|(idx, weight)| {
+            widths[*idx] = remaining * weight / total_weight;
+            allotted += widths[*idx];
+        });
+
+        // Give whatever is left from rounding down to the first flexible blocks.
+        weights
+            .iter()
+            .take(remaining - allotted)
+            .for_each(|(idx, _)| widths[*idx] += 1);
+
+        widths.iter_mut().for_each(|width| *width = (*width).max(1));
+        widths
+    }
+}
+impl Default for Columns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&Columns> for Lines {
+    fn from(value: &Columns) -> Self {
+        let widths = value.widths();
+
+        let blocks: Vec<Vec<String>> = value
+            .blocks
+            .iter()
+            .zip(&widths)
+            .map(|((lines, column_width), width)| match column_width {
+                ColumnWidth::Fit => lines.clone(),
+                _ => {
+                    let options = WrapOptions::new(u16::try_from(*width).unwrap_or(u16::MAX));
+                    lines.iter().flat_map(|line| options.wrap(line)).collect()
+                }
+            })
+            .collect();
+
+        let height = blocks.iter().map(Vec::len).max().unwrap_or(0);
+
+        let columns: Vec<Vec<String>> = blocks
+            .iter()
+            .zip(&widths)
+            .map(|(lines, width)| {
+                let (above, below) = value.vertical_alignment.split(height - lines.len());
+                let blank = " ".repeat(*width);
+
+                std::iter::repeat_n(blank.clone(), above)
+                    .chain(lines.iter().map(|line| Alignment::Left.pad(line, *width)))
+                    .chain(std::iter::repeat_n(blank, below))
+                    .collect()
+            })
+            .collect();
+
+        Lines::new(
+            (0..height)
+                .map(|line_idx| {
+                    columns
+                        .iter()
+                        .map(|column| column[line_idx].as_str())
+                        .collect::<Vec<_>>()
+                        .join(&value.gutter)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl From<Columns> for Lines {
+    fn from(value: Columns) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for Columns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
@@ -0,0 +1,452 @@
+use std::{fmt::Display, ops::Range};
+
+use conch_ansi::Modifier;
+use conch_base_models::{strip_escape_sequences, StringWrapper};
+
+use crate::Lines;
+
+/// A step of the shortest edit script turning one sequence into another, by the
+/// indices of the items in the old and new sequences.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Find the shortest edit script turning `old` into `new`, using Myers' algorithm.
+///
+/// Where there is a choice, deletions come before insertions.
+fn myers<T>(old: &[T], new: &[T]) -> Vec<Edit>
+where
+    T: PartialEq,
+{
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let offset = n + m + 1;
+    let diagonal = |k: isize| (k + offset) as usize;
+
+    // Whether the furthest path on diagonal `k` at distance `d` is reached by moving
+    // down, i.e. by an insertion, from diagonal `k + 1`; `x` gives the furthest
+    // paths at distance `d - 1` by their diagonal.
+    let moves_down =
+        |x: &dyn Fn(isize) -> isize, k: isize, d: isize| k == -d || (k != d && x(k - 1) < x(k + 1));
+
+    let mut v = vec![0; 2 * offset as usize + 1];
+
+    // The furthest paths before each distance, only on the diagonals `-d - 1..=d + 1`
+    // that can be reached, to keep the memory in O(D²) rather than O(D·(N + M)).
+    let mut trace = Vec::new();
+
+    'search: for d in 0..offset {
+        trace.push(v[diagonal(-d - 1)..=diagonal(d + 1)].to_vec());
+
+        for k in (-d..=d).step_by(2) {
+            let mut x = if moves_down(&|k| v[diagonal(k)], k, d) {
+                v[diagonal(k + 1)]
+            } else {
+                v[diagonal(k - 1)] + 1
+            };
+            let mut y = x - k;
+
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[diagonal(k)] = x;
+
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+
+    for (d, furthest) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| furthest[(k + d + 1) as usize];
+        let k = x - y;
+        let prev_k = if moves_down(&at, k, d) { k + 1 } else { k - 1 };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Equal(x as usize, y as usize));
+        }
+
+        if d > 0 {
+            if x == prev_x {
+                y -= 1;
+                edits.push(Edit::Insert(y as usize));
+            } else {
+                x -= 1;
+                edits.push(Edit::Delete(x as usize));
+            }
+        }
+    }
+
+    edits.reverse();
+    edits
+}
+
+/// Split a line into words, runs of whitespace and single punctuation characters,
+/// for highlighting changes within it.
+fn tokens(line: &str) -> Vec<&str> {
+    let class = |c: char| {
+        if c.is_whitespace() {
+            0
+        } else if c.is_alphanumeric() || c == '_' {
+            1
+        } else {
+            2
+        }
+    };
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        match chars.peek() {
+            Some((_, next)) if class(c) != 2 && class(c) == class(*next) => continue,
+            Some((idx, _)) => {
+                tokens.push(&line[start..*idx]);
+                start = *idx;
+            }
+            None => tokens.push(&line[start..]),
+        }
+    }
+
+    tokens
+}
+
+/// A line-level diff between two blocks of text, rendered as a unified diff.
+///
+/// Lines are compared with their escape codes stripped, so that lines differing
+/// only in styling are considered equal; the diff is displayed without the
+/// original escape codes, in its own styling instead.
+///
+/// Where lines are changed rather than only added or removed, the words that
+/// changed within them are highlighted as well.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{Diff, Lines};
+///
+/// let diff = Diff::from_text("veni\nvidi\nvici", "veni\nvidi, vici")
+///     .labels("before", "after")
+///     .plain();
+///
+/// assert_eq!(
+///     Lines::from(&diff).lines,
+///     vec![
+///         "--- before",
+///         "+++ after",
+///         "@@ -1,3 +1,2 @@",
+///         " veni",
+///         "-vidi",
+///         "-vici",
+///         "+vidi, vici",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Diff {
+    pub old: Vec<String>,
+    pub new: Vec<String>,
+    labels: Option<(String, String)>,
+    context: usize,
+    header_modifier: Modifier,
+    hunk_modifier: Modifier,
+    removed_modifier: Modifier,
+    added_modifier: Modifier,
+    removed_highlight: Modifier,
+    added_highlight: Modifier,
+}
+impl Diff {
+    /// Create a new instance of [`Diff`] between what `old` and `new` display as,
+    /// with 3 lines of context.
+    pub fn new<L1, L2>(old: L1, new: L2) -> Self
+    where
+        L1: Into<Lines>,
+        L2: Into<Lines>,
+    {
+        Self {
+            old: old.into().rendered(),
+            new: new.into().rendered(),
+            labels: None,
+            context: 3,
+            header_modifier: Modifier::intensity("Bold").unwrap_or_default(),
+            hunk_modifier: Modifier::colour("Cyan").unwrap_or_default(),
+            removed_modifier: Modifier::colour("Red").unwrap_or_default(),
+            added_modifier: Modifier::colour("Green").unwrap_or_default(),
+            removed_highlight: Modifier::background("R1G0B0").unwrap_or_default(),
+            added_highlight: Modifier::background("R0G1B0").unwrap_or_default(),
+        }
+    }
+
+    /// Create a new instance of [`Diff`] between two texts split into lines at
+    /// each `\n`.
+    pub fn from_text<S1, S2>(old: S1, new: S2) -> Self
+    where
+        S1: AsRef<str>,
+        S2: AsRef<str>,
+    {
+        Self::new(
+            old.as_ref().split('\n').collect::<Vec<_>>(),
+            new.as_ref().split('\n').collect::<Vec<_>>(),
+        )
+    }
+
+    /// A chained function to set the names of the old and new texts, shown in
+    /// `---` and `+++` headers, on an instance.
+    pub fn labels<S1, S2>(mut self, old: S1, new: S2) -> Self
+    where
+        S1: ToString,
+        S2: ToString,
+    {
+        self.labels = Some((old.to_string(), new.to_string()));
+        self
+    }
+
+    /// A chained function to set the number of unchanged lines shown around each
+    /// change on an instance.
+    pub fn context(mut self, value: usize) -> Self {
+        self.context = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the `---` and `+++` headers
+    /// on an instance.
+    pub fn header_modifier(mut self, value: Modifier) -> Self {
+        self.header_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the `@@` hunk headers
+    /// on an instance.
+    pub fn hunk_modifier(mut self, value: Modifier) -> Self {
+        self.hunk_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of removed lines
+    /// on an instance.
+    pub fn removed_modifier(mut self, value: Modifier) -> Self {
+        self.removed_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of added lines
+    /// on an instance.
+    pub fn added_modifier(mut self, value: Modifier) -> Self {
+        self.added_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of words removed from within a
+    /// changed line on an instance.
+    ///
+    /// This is usually a `Background`; set it to [`Modifier::Nothing`] to turn off
+    /// highlighting within lines.
+    pub fn removed_highlight(mut self, value: Modifier) -> Self {
+        self.removed_highlight = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of words added to within a
+    /// changed line on an instance.
+    ///
+    /// This is usually a `Background`; set it to [`Modifier::Nothing`] to turn off
+    /// highlighting within lines.
+    pub fn added_highlight(mut self, value: Modifier) -> Self {
+        self.added_highlight = value;
+        self
+    }
+
+    /// Remove all styling from an instance of [`Diff`], such that it displays as
+    /// a plain unified diff.
+    pub fn plain(self) -> Self {
+        self.header_modifier(Modifier::Nothing)
+            .hunk_modifier(Modifier::Nothing)
+            .removed_modifier(Modifier::Nothing)
+            .added_modifier(Modifier::Nothing)
+            .removed_highlight(Modifier::Nothing)
+            .added_highlight(Modifier::Nothing)
+    }
+
+    /// Group `edits` into the ranges of the hunks, each with its changes and up to
+    /// [`Diff::context()`] unchanged lines around them.
+    fn hunks(&self, edits: &[Edit]) -> Vec<Range<usize>> {
+        let changes = edits
+            .iter()
+            .enumerate()
+            .filter(|(_, edit)| !matches!(edit, Edit::Equal(..)))
+            .map(|(idx, _)| idx);
+
+        let mut hunks: Vec<Range<usize>> = Vec::new();
+        changes.for_each(|idx| {
+            let start = idx.saturating_sub(self.context);
+            let end = (idx + 1 + self.context).min(edits.len());
+
+            match hunks.last_mut() {
+                // Merge hunks whose context would touch or overlap.
+                Some(hunk) if start <= hunk.end => hunk.end = end,
+                _ => hunks.push(start..end),
+            }
+        });
+
+        hunks
+    }
+
+    /// Render a pair of changed lines, highlighting the words that differ.
+    ///
+    /// Lines with no words in common are not highlighted, as that would highlight
+    /// all of them.
+    fn highlight(&self, old: &str, new: &str) -> (String, String) {
+        let (old_tokens, new_tokens) = (tokens(old), tokens(new));
+        let edits = myers(&old_tokens, &new_tokens);
+
+        let shares_words = edits.iter().any(|edit| match edit {
+            Edit::Equal(idx, _) => !old_tokens[*idx].trim().is_empty(),
+            _ => false,
+        });
+        if !shares_words {
+            return (old.to_string(), new.to_string());
+        }
+
+        let (mut old_line, mut new_line) = (String::new(), String::new());
+        let (mut removed, mut added) = (String::new(), String::new());
+
+        edits.iter().for_each(|edit| match edit {
+            Edit::Equal(old_idx, new_idx) => {
+                old_line += &self
+                    .removed_highlight
+                    .wraps_non_empty(&std::mem::take(&mut removed));
+                new_line += &self
+                    .added_highlight
+                    .wraps_non_empty(&std::mem::take(&mut added));
+                old_line += old_tokens[*old_idx];
+                new_line += new_tokens[*new_idx];
+            }
+            Edit::Delete(idx) => removed += old_tokens[*idx],
+            Edit::Insert(idx) => added += new_tokens[*idx],
+        });
+        old_line += &self.removed_highlight.wraps_non_empty(&removed);
+        new_line += &self.added_highlight.wraps_non_empty(&added);
+
+        (old_line, new_line)
+    }
+
+    /// Render a run of consecutive removed and added lines, pairing them up in
+    /// order to highlight the words that changed.
+    fn render_changes(&self, removed: &[&str], added: &[&str], lines: &mut Vec<String>) {
+        let mut old_lines: Vec<String> = removed.iter().map(|line| line.to_string()).collect();
+        let mut new_lines: Vec<String> = added.iter().map(|line| line.to_string()).collect();
+
+        old_lines
+            .iter_mut()
+            .zip(new_lines.iter_mut())
+            .for_each(|(old, new)| (*old, *new) = self.highlight(old, new));
+
+        lines.extend(
+            old_lines
+                .iter()
+                .map(|line| self.removed_modifier.wraps(&format!("-{}", line))),
+        );
+        lines.extend(
+            new_lines
+                .iter()
+                .map(|line| self.added_modifier.wraps(&format!("+{}", line))),
+        );
+    }
+}
+
+/// Format the range of a hunk in one of the texts for its header, where `before` is
+/// the number of lines of the text before the hunk.
+fn hunk_range(before: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", before),
+        1 => format!("{}", before + 1),
+        count => format!("{},{}", before + 1, count),
+    }
+}
+
+impl From<&Diff> for Lines {
+    fn from(value: &Diff) -> Self {
+        let old: Vec<String> = value
+            .old
+            .iter()
+            .map(|line| strip_escape_sequences(line))
+            .collect();
+        let new: Vec<String> = value
+            .new
+            .iter()
+            .map(|line| strip_escape_sequences(line))
+            .collect();
+        let edits = myers(&old, &new);
+        let hunks = value.hunks(&edits);
+
+        let mut lines = Vec::new();
+        if hunks.is_empty() {
+            return Lines::new(lines);
+        }
+
+        if let Some((old_label, new_label)) = &value.labels {
+            lines.push(value.header_modifier.wraps(&format!("--- {}", old_label)));
+            lines.push(value.header_modifier.wraps(&format!("+++ {}", new_label)));
+        }
+
+        hunks.into_iter().for_each(|hunk| {
+            let in_old = |edit: &Edit| !matches!(edit, Edit::Insert(_));
+            let in_new = |edit: &Edit| !matches!(edit, Edit::Delete(_));
+
+            let before = &edits[..hunk.start];
+            let within = &edits[hunk];
+
+            lines.push(value.hunk_modifier.wraps(&format!(
+                "@@ -{} +{} @@",
+                hunk_range(
+                    before.iter().filter(|edit| in_old(edit)).count(),
+                    within.iter().filter(|edit| in_old(edit)).count()
+                ),
+                hunk_range(
+                    before.iter().filter(|edit| in_new(edit)).count(),
+                    within.iter().filter(|edit| in_new(edit)).count()
+                ),
+            )));
+
+            let (mut removed, mut added) = (Vec::new(), Vec::new());
+            within.iter().for_each(|edit| match edit {
+                Edit::Equal(old_idx, _) => {
+                    value.render_changes(&removed, &added, &mut lines);
+                    removed.clear();
+                    added.clear();
+                    lines.push(format!(" {}", old[*old_idx]));
+                }
+                Edit::Delete(idx) => removed.push(old[*idx].as_str()),
+                Edit::Insert(idx) => added.push(new[*idx].as_str()),
+            });
+            value.render_changes(&removed, &added, &mut lines);
+        });
+
+        Lines::new(lines)
+    }
+}
+
+impl From<Diff> for Lines {
+    fn from(value: Diff) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for Diff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
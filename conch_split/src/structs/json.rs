@@ -0,0 +1,278 @@
+use std::fmt::Display;
+
+use conch_ansi::Modifier;
+use conch_base_models::StringWrapper;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Lines;
+
+/// The [`Modifier`]s of each kind of token of a [`Json`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct JsonTheme {
+    pub key: Modifier,
+    pub string: Modifier,
+    pub number: Modifier,
+    pub boolean: Modifier,
+    pub null: Modifier,
+
+    /// Brackets, braces, colons and commas.
+    pub punctuation: Modifier,
+
+    /// The `…` of collapsed values and the marker of items left out of long arrays.
+    pub marker: Modifier,
+}
+impl JsonTheme {
+    /// A theme without any styling.
+    pub fn plain() -> Self {
+        Self {
+            key: Modifier::Nothing,
+            string: Modifier::Nothing,
+            number: Modifier::Nothing,
+            boolean: Modifier::Nothing,
+            null: Modifier::Nothing,
+            punctuation: Modifier::Nothing,
+            marker: Modifier::Nothing,
+        }
+    }
+}
+impl Default for JsonTheme {
+    fn default() -> Self {
+        Self {
+            key: Modifier::colour("BrightBlue").unwrap_or_default(),
+            string: Modifier::colour("Green").unwrap_or_default(),
+            number: Modifier::colour("Cyan").unwrap_or_default(),
+            boolean: Modifier::colour("Yellow").unwrap_or_default(),
+            null: Modifier::colour("BrightBlack").unwrap_or_default(),
+            punctuation: Modifier::Nothing,
+            marker: Modifier::intensity("Faint").unwrap_or_default(),
+        }
+    }
+}
+
+/// A pretty-printed JSON value, with each kind of token styled by a [`JsonTheme`].
+///
+/// Anything that implements [`Serialize`] can be printed through
+/// [`Json::from_serialize()`]. Long arrays can be cut short, and values nested
+/// deeper than a maximum depth collapsed into `[…]` or `{…}`.
+///
+/// Requires the `json` feature.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{Json, JsonTheme, Lines};
+///
+/// let json = Json::parse(
+///     r#"{"name": "conch", "tags": ["ansi", "shell", "tui"], "deps": {"regex": {"version": "1"}}}"#,
+/// )
+/// .unwrap()
+/// .theme(JsonTheme::plain())
+/// .max_array_items(2)
+/// .max_depth(2);
+///
+/// assert_eq!(
+///     Lines::from(&json).lines,
+///     vec![
+///         r#"{"#,
+///         r#"  "name": "conch","#,
+///         r#"  "tags": ["#,
+///         r#"    "ansi","#,
+///         r#"    "shell","#,
+///         r#"    … 1 more item"#,
+///         r#"  ],"#,
+///         r#"  "deps": {"#,
+///         r#"    "regex": {…}"#,
+///         r#"  }"#,
+///         r#"}"#,
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Json {
+    pub value: Value,
+    theme: JsonTheme,
+    indent: usize,
+    max_array_items: Option<usize>,
+    max_depth: Option<usize>,
+}
+impl Json {
+    /// Create a new instance of [`Json`] with the default [`JsonTheme`], indented by
+    /// 2 spaces.
+    pub fn new(value: Value) -> Self {
+        Self {
+            value,
+            theme: JsonTheme::default(),
+            indent: 2,
+            max_array_items: None,
+            max_depth: None,
+        }
+    }
+
+    /// Create a new instance of [`Json`] from any value that implements
+    /// [`Serialize`].
+    pub fn from_serialize<T>(value: &T) -> Result<Self, serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde_json::to_value(value).map(Self::new)
+    }
+
+    /// Create a new instance of [`Json`] by parsing a JSON text.
+    pub fn parse(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text).map(Self::new)
+    }
+
+    /// A chained function to set the [`JsonTheme`]
+    /// on an instance.
+    pub fn theme(mut self, value: JsonTheme) -> Self {
+        self.theme = value;
+        self
+    }
+
+    /// A chained function to set the number of spaces to indent by for each level
+    /// of nesting on an instance.
+    pub fn indent(mut self, value: usize) -> Self {
+        self.indent = value;
+        self
+    }
+
+    /// A chained function to set the maximum number of items of an array to show
+    /// on an instance.
+    ///
+    /// The rest are replaced by a marker such as `… 12 more items`.
+    pub fn max_array_items(mut self, value: usize) -> Self {
+        self.max_array_items = Some(value);
+        self
+    }
+
+    /// A chained function to set the maximum depth of nesting to show
+    /// on an instance.
+    ///
+    /// Non-empty arrays and objects nested deeper than this are collapsed into `[…]`
+    /// and `{…}`; the outermost value is at depth `0`.
+    pub fn max_depth(mut self, value: usize) -> Self {
+        self.max_depth = Some(value);
+        self
+    }
+
+    /// Apply [`JsonTheme::punctuation`] to `text`.
+    fn punctuation(&self, text: &str) -> String {
+        self.theme.punctuation.wraps(text)
+    }
+
+    /// Render a value that fits on a single line: a scalar or an empty container.
+    fn scalar(&self, value: &Value) -> String {
+        match value {
+            Value::Null => self.theme.null.wraps("null"),
+            Value::Bool(boolean) => self.theme.boolean.wraps(&boolean.to_string()),
+            Value::Number(number) => self.theme.number.wraps(&number.to_string()),
+            Value::String(_) => self.theme.string.wraps(&value.to_string()),
+            Value::Array(_) => self.punctuation("[]"),
+            Value::Object(_) => self.punctuation("{}"),
+        }
+    }
+
+    /// Render `value` at `depth` into `lines`, preceded by `key` if it is a member
+    /// of an object, and followed by a comma unless it is the `last` of its
+    /// siblings.
+    fn render(
+        &self,
+        value: &Value,
+        key: Option<&str>,
+        depth: usize,
+        last: bool,
+        lines: &mut Vec<String>,
+    ) {
+        let indent = " ".repeat(self.indent * depth);
+        let key = key.map_or(String::new(), |key| {
+            self.theme.key.wraps(&Value::from(key).to_string()) + &self.punctuation(":") + " "
+        });
+        let comma = if last {
+            String::new()
+        } else {
+            self.punctuation(",")
+        };
+
+        let (open, close, members): (&str, &str, Vec<(Option<&str>, &Value)>) = match value {
+            Value::Array(items) if !items.is_empty() => {
+                ("[", "]", items.iter().map(|item| (None, item)).collect())
+            }
+            Value::Object(map) if !map.is_empty() => (
+                "{",
+                "}",
+                map.iter()
+                    .map(|(key, member)| (Some(key.as_str()), member))
+                    .collect(),
+            ),
+            _ => {
+                lines.push(indent + &key + &self.scalar(value) + &comma);
+                return;
+            }
+        };
+
+        if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            lines.push(
+                indent
+                    + &key
+                    + &self.punctuation(open)
+                    + &self.theme.marker.wraps("…")
+                    + &self.punctuation(close)
+                    + &comma,
+            );
+            return;
+        }
+
+        let shown = match value {
+            Value::Array(_) => self.max_array_items.unwrap_or(usize::MAX),
+            _ => usize::MAX,
+        }
+        .min(members.len());
+        let hidden = members.len() - shown;
+
+        lines.push(indent.clone() + &key + &self.punctuation(open));
+        members
+            .iter()
+            .take(shown)
+            .enumerate()
+            .for_each(|(idx, (key, member))| {
+                self.render(
+                    member,
+                    *key,
+                    depth + 1,
+                    idx + 1 == shown && hidden == 0,
+                    lines,
+                )
+            });
+        if hidden > 0 {
+            let marker = match hidden {
+                1 => String::from("… 1 more item"),
+                hidden => format!("… {} more items", hidden),
+            };
+            lines.push(" ".repeat(self.indent * (depth + 1)) + &self.theme.marker.wraps(&marker));
+        }
+        lines.push(indent + &self.punctuation(close) + &comma);
+    }
+}
+
+impl From<&Json> for Lines {
+    fn from(value: &Json) -> Self {
+        let mut lines = Vec::new();
+        value.render(&value.value, None, 0, true, &mut lines);
+
+        Lines::new(lines)
+    }
+}
+
+impl From<Json> for Lines {
+    fn from(value: Json) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt::Display;
+
+use conch_ansi::Modifier;
+use conch_base_models::{visible_width, StringWrapper};
+
+use crate::{Alignment, Lines, WrapOptions};
+
+/// A group of entries of [`KeyValues`], with an optional header.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyValueSection {
+    pub header: Option<String>,
+    pub entries: Vec<(String, String)>,
+}
+
+/// A block of keys and values, such as `Version : 1.2.3`, with the separators
+/// aligned.
+///
+/// Keys are padded to the display width of the widest key across all sections.
+/// Values spanning multiple lines, whether wrapped or explicit, continue under the
+/// value column with a hanging indent.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{KeyValues, Lines};
+///
+/// let key_values = KeyValues::new()
+///     .entry("Name", "conch")
+///     .entry("Version", "0.1.0")
+///     .section("Description")
+///     .entry("Summary", "Pretty output for the shell.")
+///     .width(24);
+///
+/// assert_eq!(
+///     Lines::from(&key_values).lines,
+///     vec![
+///         "Name    : conch",
+///         "Version : 0.1.0",
+///         "",
+///         "Description",
+///         "Summary : Pretty output",
+///         "          for the shell.",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyValues {
+    pub sections: Vec<KeyValueSection>,
+    separator: String,
+    key_alignment: Alignment,
+    key_modifier: Modifier,
+    value_modifier: Modifier,
+    header_modifier: Modifier,
+    width: Option<u16>,
+}
+impl KeyValues {
+    /// Create a new, empty instance of [`KeyValues`], separated by ` : `.
+    pub fn new() -> Self {
+        Self {
+            sections: Vec::new(),
+            separator: String::from(" : "),
+            key_alignment: Alignment::Left,
+            key_modifier: Modifier::Nothing,
+            value_modifier: Modifier::Nothing,
+            header_modifier: Modifier::Nothing,
+            width: None,
+        }
+    }
+
+    /// Append an entry to the last section of an instance of [`KeyValues`].
+    pub fn entry<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        if self.sections.is_empty() {
+            self.sections.push(KeyValueSection::default());
+        }
+
+        self.sections
+            .last_mut()
+            .expect("There is at least one section.")
+            .entries
+            .push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Append multiple entries to the last section of an instance of [`KeyValues`].
+    pub fn entries<K, V>(self, entries: Vec<(K, V)>) -> Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        entries.into_iter().fold(self, |key_values, (key, value)| {
+            key_values.entry(key, value)
+        })
+    }
+
+    /// Start a new section with a header on an instance of [`KeyValues`]; entries
+    /// appended afterwards belong to it.
+    pub fn section<S>(mut self, header: S) -> Self
+    where
+        S: ToString,
+    {
+        self.sections.push(KeyValueSection {
+            header: Some(header.to_string()),
+            entries: Vec::new(),
+        });
+        self
+    }
+
+    /// A chained function to set the separator between keys and values
+    /// on an instance.
+    pub fn separator<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.separator = value.to_string();
+        self
+    }
+
+    /// A chained function to set the [`Alignment`] of the keys
+    /// on an instance.
+    pub fn key_alignment(mut self, value: Alignment) -> Self {
+        self.key_alignment = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the keys
+    /// on an instance.
+    pub fn key_modifier(mut self, value: Modifier) -> Self {
+        self.key_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the values
+    /// on an instance.
+    pub fn value_modifier(mut self, value: Modifier) -> Self {
+        self.value_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the section headers
+    /// on an instance.
+    pub fn header_modifier(mut self, value: Modifier) -> Self {
+        self.header_modifier = value;
+        self
+    }
+
+    /// A chained function to set the total width to wrap the values to, including
+    /// the keys and separators, on an instance.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = Some(value);
+        self
+    }
+
+    /// Render a single entry, with its key padded to `key_width`.
+    fn render_entry(&self, (key, value): &(String, String), key_width: usize) -> Vec<String> {
+        let key = self
+            .key_alignment
+            .last_line()
+            .pad(&self.key_modifier.wraps_non_empty(key), key_width);
+        let indent = " ".repeat(key_width + visible_width(&self.separator));
+
+        let values: Vec<String> = match self.width {
+            Some(width) => {
+                let value_width = usize::from(width).saturating_sub(visible_width(&indent));
+                let options =
+                    WrapOptions::new(u16::try_from(value_width.max(1)).unwrap_or(u16::MAX));
+                value
+                    .split('\n')
+                    .flat_map(|paragraph| options.wrap(paragraph))
+                    .collect()
+            }
+            None => value.split('\n').map(str::to_string).collect(),
+        };
+
+        values
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| {
+                let line = self.value_modifier.wraps_non_empty(line);
+
+                match (idx, line.is_empty()) {
+                    (0, true) => key.clone() + self.separator.trim_end(),
+                    (0, false) => key.clone() + &self.separator + &line,
+                    (_, true) => String::new(),
+                    (_, false) => indent.clone() + &line,
+                }
+            })
+            .collect()
+    }
+}
+impl Default for KeyValues {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&KeyValues> for Lines {
+    fn from(value: &KeyValues) -> Self {
+        let key_width = value
+            .sections
+            .iter()
+            .flat_map(|section| &section.entries)
+            .map(|(key, _)| visible_width(key))
+            .max()
+            .unwrap_or(0);
+
+        let lines = value
+            .sections
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, section)| {
+                // Separate sections by a blank line.
+                let blank = (idx > 0).then(String::new);
+                let header = section
+                    .header
+                    .as_ref()
+                    .map(|header| value.header_modifier.wraps_non_empty(header));
+
+                blank.into_iter().chain(header).chain(
+                    section
+                        .entries
+                        .iter()
+                        .flat_map(|entry| value.render_entry(entry, key_width)),
+                )
+            })
+            .collect();
+
+        Lines::new(lines)
+    }
+}
+
+impl From<KeyValues> for Lines {
+    fn from(value: KeyValues) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for KeyValues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use conch_ansi::Modifier;
+
+/// A closure choosing a [`Modifier`] for a line from its index and content.
+type LinePredicate = dyn Fn(usize, &str) -> Option<Modifier> + Send + Sync;
+
+/// Rules choosing [`Modifier`]s for individual lines of a [`crate::Lines`], on top
+/// of its lines modifier.
+///
+/// All the rules that apply to a line are combined, in the order of:
+///
+/// 1. alternating modifiers,
+/// 2. modifiers chosen by closures, in the order they were added,
+/// 3. the first and last line modifiers,
+/// 4. explicit overrides by index,
+///
+/// so that later rules take precedence over earlier ones where they conflict.
+#[derive(Clone, Default)]
+pub(crate) struct LineRules {
+    pub(crate) alternating: Vec<Modifier>,
+    pub(crate) predicates: Vec<Arc<LinePredicate>>,
+    pub(crate) first: Option<Modifier>,
+    pub(crate) last: Option<Modifier>,
+    pub(crate) overrides: Vec<(usize, Modifier)>,
+}
+impl LineRules {
+    /// Combine all the [`Modifier`]s that apply to the line at `idx` out of `count`
+    /// lines.
+    pub(crate) fn modifier(&self, idx: usize, count: usize, line: &str) -> Modifier {
+        let alternating = self
+            .alternating
+            .get(idx % self.alternating.len().max(1))
+            .cloned();
+        let predicates = self
+            .predicates
+            .iter()
+            .filter_map(|predicate| predicate(idx, line));
+        let first = self.first.clone().filter(|_| idx == 0);
+        let last = self.last.clone().filter(|_| idx + 1 == count);
+        let overrides = self
+            .overrides
+            .iter()
+            .filter(|(line_idx, _)| *line_idx == idx)
+            .map(|(_, modifier)| modifier.clone());
+
+        alternating
+            .into_iter()
+            .chain(predicates)
+            .chain(first)
+            .chain(last)
+            .chain(overrides)
+            .fold(Modifier::Nothing, |lhs, rhs| lhs + rhs)
+    }
+}
+impl std::fmt::Debug for LineRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LineRules")
+            .field("alternating", &self.alternating)
+            .field("predicates", &self.predicates.len())
+            .field("first", &self.first)
+            .field("last", &self.last)
+            .field("overrides", &self.overrides)
+            .finish()
+    }
+}
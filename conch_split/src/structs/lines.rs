@@ -1,9 +1,56 @@
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
-use conch_ansi::Modifier;
-use conch_base_models::StringWrapper;
+use conch_ansi::{Background, Modifier};
+use conch_base_models::{visible_width, StringWrapper};
+
+use super::line_rules::LineRules;
+use crate::{Alignment, Pages, Truncation, WrapOptions};
 
 /// A wrapper around [`Vec<String>`] to provide more control over display.
+///
+/// Besides [`Lines::modifier()`], which applies to every line, individual lines can
+/// be styled by rules: alternating modifiers, modifiers chosen by a closure, first
+/// and last line modifiers, and overrides by index. Rules only apply to
+/// [`Lines::lines`], not the title; where several apply to the same line, they are
+/// combined on top of the lines modifier in that order, so the later ones take
+/// precedence.
+///
+/// Lines can also be aligned within a target width, measured ignoring any escape
+/// codes, and filled with a background across that width to render as solid bars:
+///
+/// ```rust
+/// use conch_ansi::Background;
+/// use conch_split::{Alignment, Lines};
+///
+/// let lines = Lines::from(vec!["veni", "vidi, vici"])
+///     .alignment(Alignment::Center)
+///     .width(12)
+///     .fill(Background::Grayscale02);
+///
+/// assert_eq!(
+///     lines.to_string(),
+///     "\x1b[48;5;234m    veni    \x1b[49m\n\
+///      \x1b[48;5;234m vidi, vici \x1b[49m"
+/// );
+/// ```
+///
+/// ```rust
+/// use conch_ansi::Modifier;
+/// use conch_split::Lines;
+///
+/// let lines = Lines::from(vec!["INFO: veni", "ERROR: vidi", "INFO: vici"])
+///     .modifier_when(|_, line| {
+///         line.starts_with("ERROR").then(|| Modifier::colour("BrightRed").unwrap())
+///     })
+///     .last_line_modifier(Modifier::intensity("Bold").unwrap());
+///
+/// assert_eq!(
+///     lines.to_string(),
+///     "INFO: veni\n\
+///      \x1b[38;5;9mERROR: vidi\x1b[39m\n\
+///      \x1b[1mINFO: vici\x1b[22m"
+/// );
+/// ```
 #[derive(Clone, Debug)]
 pub struct Lines {
     pub title: Option<String>,
@@ -12,7 +59,25 @@ pub struct Lines {
     prefix: String,
     title_modifier: Option<Modifier>,
     lines_modifier: Modifier,
+    rules: LineRules,
+    alignment: Alignment,
+    width: Option<usize>,
+    fill: Option<Modifier>,
+    max_height: Option<usize>,
+    truncation: Truncation,
+    more_modifier: Modifier,
     spacing: u8,
+
+    /// The index of the first line and the number of lines of the [`Lines`] these
+    /// were paged from, so that rules by index apply to the same lines on every page.
+    window: Option<(usize, usize)>,
+}
+
+/// A line to display, which is either one of [`Lines::lines`] by its index, or the
+/// marker for a number of lines that were left out.
+enum Entry<'a> {
+    Line(usize, &'a str),
+    More(usize),
 }
 impl Lines {
     /// Create a new instance of [`Lines`] with default empty settings.
@@ -24,20 +89,57 @@ impl Lines {
             prefix: String::new(),
             title_modifier: None,
             lines_modifier: Modifier::Nothing,
+            rules: LineRules::default(),
+            alignment: Alignment::Left,
+            width: None,
+            fill: None,
+            max_height: None,
+            truncation: Truncation::Head,
+            more_modifier: Modifier::intensity("Faint").unwrap_or_default(),
             spacing: 1,
+            window: None,
         }
     }
 
-    /// Create a new instance of [`Lines`] by splitting a block of text
-    /// into lines.
+    /// Create a new instance of [`Lines`] by wrapping a block of text
+    /// into lines of at most `max_len` columns.
+    ///
+    /// See [`WrapOptions::wrap()`] for how the text is wrapped.
+    ///
+    /// ```rust
+    /// use conch_split::Lines;
+    ///
+    /// let lines = Lines::from_text("veni, vidi, vici", 11);
+    ///
+    /// assert_eq!(lines.lines, vec!["veni, vidi,", "vici"]);
+    /// ```
     pub fn from_text<S>(text: S, max_len: u16) -> Self
     where
         S: ToString,
     {
-        let _ = text.to_string();
-        drop(max_len);
+        Self::from_text_with(text, WrapOptions::new(max_len))
+    }
 
-        todo!("Not implemented yet.")
+    /// Create a new instance of [`Lines`] by wrapping a block of text
+    /// into lines using the [`WrapOptions`] provided.
+    pub fn from_text_with<S>(text: S, options: WrapOptions) -> Self
+    where
+        S: ToString,
+    {
+        Self::new(options.wrap(&text.to_string()))
+    }
+
+    /// Render an instance of [`Lines`] into the lines it displays as, including
+    /// its title, prefixes, modifiers and spacing.
+    ///
+    /// An instance that displays as nothing at all renders into no lines.
+    pub fn rendered(&self) -> Vec<String> {
+        let rendered = self.to_string();
+        if rendered.is_empty() && self.lines.is_empty() {
+            return Vec::new();
+        }
+
+        rendered.split('\n').map(str::to_string).collect()
     }
 
     /// Extend the lines in an instance of [`Lines`].
@@ -102,12 +204,267 @@ impl Lines {
         self
     }
 
+    /// A chained function to set the [`Modifier`]s to alternate between, line by
+    /// line, on an instance.
+    ///
+    /// For zebra stripes, alternate between [`Modifier::Nothing`] and a background.
+    pub fn alternating_modifiers(mut self, value: Vec<Modifier>) -> Self {
+        self.rules.alternating = value;
+        self
+    }
+
+    /// Add a rule choosing a [`Modifier`] for each line from its index and content
+    /// to an instance of [`Lines`].
+    ///
+    /// Lines for which the closure returns [`None`] are not affected by this rule.
+    pub fn modifier_when<F>(mut self, rule: F) -> Self
+    where
+        F: Fn(usize, &str) -> Option<Modifier> + Send + Sync + 'static,
+    {
+        self.rules.predicates.push(Arc::new(rule));
+        self
+    }
+
+    /// Override the [`Modifier`] of the line at `idx` on an instance of [`Lines`].
+    pub fn line_modifier(mut self, idx: usize, value: Modifier) -> Self {
+        self.rules.overrides.push((idx, value));
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the first line
+    /// on an instance.
+    pub fn first_line_modifier(mut self, value: Modifier) -> Self {
+        self.rules.first = Some(value);
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the last line
+    /// on an instance.
+    pub fn last_line_modifier(mut self, value: Modifier) -> Self {
+        self.rules.last = Some(value);
+        self
+    }
+
+    /// A chained function to set the [`Alignment`] of the title and lines
+    /// on an instance.
+    ///
+    /// Without a [`Lines::width()`], lines are aligned to the widest of them. With
+    /// [`Alignment::Justify`], the title, the last line and any line followed by a
+    /// blank line are aligned to the left instead.
+    pub fn alignment(mut self, value: Alignment) -> Self {
+        self.alignment = value;
+        self
+    }
+
+    /// A chained function to set the target width of the title and lines, including
+    /// their prefixes, on an instance.
+    ///
+    /// Lines wider than this are left as they are.
+    pub fn width(mut self, value: usize) -> Self {
+        self.width = Some(value);
+        self
+    }
+
+    /// A chained function to set a [`Background`] spanning the full width of each
+    /// line on an instance.
+    pub fn fill(mut self, value: Background) -> Self {
+        self.fill = Some(Modifier::Background(value));
+        self
+    }
+
+    /// A chained function to set the maximum number of terminal rows to display,
+    /// including the title and any spacing, on an instance.
+    ///
+    /// Lines that do not fit are left out according to the [`Truncation`], and a
+    /// marker such as `… 1234 more lines` is displayed in their place.
+    ///
+    /// ```rust
+    /// use conch_ansi::Modifier;
+    /// use conch_split::{Lines, Truncation};
+    ///
+    /// let lines = Lines::from((1..=100).collect::<Vec<_>>())
+    ///     .max_height(5)
+    ///     .truncation(Truncation::HeadAndTail)
+    ///     .more_modifier(Modifier::Nothing);
+    ///
+    /// assert_eq!(lines.to_string(), "1\n2\n… 96 more lines\n99\n100");
+    /// ```
+    pub fn max_height(mut self, value: usize) -> Self {
+        self.max_height = Some(value);
+        self
+    }
+
+    /// A chained function to set which lines to keep when cut short by
+    /// [`Lines::max_height()`] on an instance.
+    pub fn truncation(mut self, value: Truncation) -> Self {
+        self.truncation = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the marker for the lines left
+    /// out by [`Lines::max_height()`] on an instance.
+    ///
+    /// The marker is faint by default.
+    pub fn more_modifier(mut self, value: Modifier) -> Self {
+        self.more_modifier = value;
+        self
+    }
+
     /// A chained function to set [`Lines::spacing`]
     /// on an instance.
     pub fn spacing(mut self, value: u8) -> Self {
         self.spacing = value;
         self
     }
+
+    /// Split an instance of [`Lines`] into pages of at most `height` terminal rows
+    /// each, including the title and any spacing.
+    ///
+    /// Every page repeats the title and all other settings except
+    /// [`Lines::max_height()`]; at least one line is put on every page, even if it
+    /// does not fit.
+    ///
+    /// ```rust
+    /// use conch_split::Lines;
+    ///
+    /// let lines = Lines::from(vec!["veni", "vidi", "vici"]).title("Caesar");
+    ///
+    /// assert_eq!(
+    ///     lines.pages(4).map(|page| page.to_string()).collect::<Vec<_>>(),
+    ///     vec!["Caesar\n\nveni\nvidi", "Caesar\n\nvici"]
+    /// );
+    /// ```
+    pub fn pages(&self, height: usize) -> Pages<'_> {
+        Pages::new(self, height)
+    }
+
+    /// The number of terminal rows taken by the title and the blank line after it,
+    /// including any spacing.
+    fn title_rows(&self) -> usize {
+        match self.title {
+            Some(_) => 2 * usize::from(self.spacing),
+            None => 0,
+        }
+    }
+
+    /// The greatest number of lines that fit in `height` terminal rows alongside the
+    /// title.
+    pub(crate) fn capacity(&self, height: usize) -> usize {
+        if self.spacing == 0 {
+            // All lines are displayed on a single row.
+            return usize::MAX;
+        }
+
+        match height.saturating_sub(self.title_rows()) {
+            0 => 0,
+            rows => 1 + (rows - 1) / usize::from(self.spacing),
+        }
+    }
+
+    /// A copy of an instance of [`Lines`] with only the lines in `range`, as the
+    /// page of a [`Pages`].
+    pub(crate) fn page(&self, range: std::ops::Range<usize>) -> Self {
+        let (offset, total) = self.window.unwrap_or((0, self.lines.len()));
+
+        Self {
+            lines: self.lines[range.clone()].to_vec(),
+            max_height: None,
+            window: Some((offset + range.start, total)),
+            ..self.clone()
+        }
+    }
+
+    /// The lines to display after truncating to [`Lines::max_height()`].
+    fn entries(&self) -> Vec<Entry<'_>> {
+        let lines = self
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| Entry::Line(idx, line.as_str()));
+
+        let capacity = self
+            .max_height
+            .map_or(usize::MAX, |height| self.capacity(height));
+        if self.lines.len() <= capacity {
+            return lines.collect();
+        }
+
+        // Keep one line fewer than fits to make room for the marker.
+        let kept = capacity.max(1) - 1;
+        let more = Entry::More(self.lines.len() - kept);
+        let (head, tail) = match self.truncation {
+            Truncation::Head => (kept, 0),
+            Truncation::Tail => (0, kept),
+            Truncation::HeadAndTail => (kept - kept / 2, kept / 2),
+        };
+
+        lines
+            .clone()
+            .take(head)
+            .chain(std::iter::once(more))
+            .chain(lines.skip(self.lines.len() - tail))
+            .collect()
+    }
+
+    /// The width to pad all `entries` to, if any padding is needed at all.
+    fn target_width(&self, entries: &[Entry]) -> Option<usize> {
+        if self.width.is_some() || (self.alignment == Alignment::Left && self.fill.is_none()) {
+            return self.width;
+        }
+
+        let prefix = visible_width(&self.prefix);
+        let title = self.title.as_ref().map(|title| {
+            visible_width(self.title_prefix.as_ref().unwrap_or(&self.prefix)) + visible_width(title)
+        });
+
+        entries
+            .iter()
+            .map(|entry| match entry {
+                Entry::Line(_, line) => prefix + visible_width(line),
+                Entry::More(count) => prefix + visible_width(&more_marker(*count)),
+            })
+            .chain(title)
+            .max()
+    }
+
+    /// Lay out a single line: align `text` after `prefix` within `width`, then apply
+    /// `modifier` and the fill.
+    fn layout(
+        &self,
+        prefix: &str,
+        text: &str,
+        alignment: Alignment,
+        modifier: &Modifier,
+        width: Option<usize>,
+    ) -> String {
+        let text = match width {
+            Some(width) => alignment.pad(text, width.saturating_sub(visible_width(prefix))),
+            None => text.to_string(),
+        };
+        let line = modifier.wraps(&(prefix.to_string() + &text));
+
+        match &self.fill {
+            Some(fill) => fill.wraps(&line),
+            None => line,
+        }
+    }
+
+    /// The [`Alignment`] of the line at `idx`, which is not justified if it ends a
+    /// paragraph.
+    fn line_alignment(&self, idx: usize) -> Alignment {
+        match self.lines.get(idx + 1) {
+            Some(next) if !next.trim().is_empty() => self.alignment,
+            _ => self.alignment.last_line(),
+        }
+    }
+}
+
+/// The marker for `count` lines left out by [`Lines::max_height()`].
+fn more_marker(count: usize) -> String {
+    match count {
+        1 => String::from("… 1 more line"),
+        count => format!("… {} more lines", count),
+    }
 }
 
 impl From<Lines> for Vec<String> {
@@ -132,20 +489,21 @@ impl Display for Lines {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // SPACER BLOCK
         let spacer = (0..self.spacing).fold(String::new(), |s, _| s + "\n");
+        let entries = self.entries();
+        let width = self.target_width(&entries);
+        let (offset, total) = self.window.unwrap_or((0, self.lines.len()));
 
         // TITLE BLOCK
         self.title
             .as_ref()
             .map(|title| {
-                let mut title = self.title_prefix.as_ref().unwrap_or(&self.prefix).clone() + title;
-
-                title = self
-                    .title_modifier
-                    .as_ref()
-                    .unwrap_or(&self.lines_modifier)
-                    .wraps(&title);
-
-                title
+                self.layout(
+                    self.title_prefix.as_ref().unwrap_or(&self.prefix),
+                    title,
+                    self.alignment.last_line(),
+                    self.title_modifier.as_ref().unwrap_or(&self.lines_modifier),
+                    width,
+                )
             })
             .map(|title| {
                 write!(f, "{}", title).and_then(|res| {
@@ -154,7 +512,13 @@ impl Display for Lines {
                             f,
                             "{}{}{}",
                             &spacer,
-                            self.lines_modifier.wraps(&self.prefix),
+                            self.layout(
+                                &self.prefix,
+                                "",
+                                Alignment::Left,
+                                &self.lines_modifier,
+                                width
+                            ),
                             &spacer
                         )
                     } else {
@@ -166,20 +530,43 @@ impl Display for Lines {
             .unwrap_or(Ok(()))?;
 
         // TEXT BLOCK
-        let text = self.lines.iter().fold(String::new(), |s, line| {
-            let sep = {
-                if s.len() > 0 {
-                    &spacer
-                } else {
-                    ""
-                }
-            };
-            s + sep
-                + self
-                    .lines_modifier
-                    .wraps(&(self.prefix.to_string() + line))
-                    .as_str()
-        });
+        let text = entries
+            .iter()
+            .enumerate()
+            .fold(String::new(), |s, (position, entry)| {
+                // Blank lines render as empty strings, so `s` cannot tell whether this is
+                // the first entry.
+                let sep = {
+                    if position > 0 {
+                        &spacer
+                    } else {
+                        ""
+                    }
+                };
+                let line = match entry {
+                    Entry::Line(idx, line) => {
+                        let modifier = self.lines_modifier.clone()
+                            + self.rules.modifier(offset + idx, total, line);
+
+                        self.layout(
+                            &self.prefix,
+                            line,
+                            self.line_alignment(*idx),
+                            &modifier,
+                            width,
+                        )
+                    }
+                    Entry::More(count) => self.layout(
+                        &self.prefix,
+                        &more_marker(*count),
+                        self.alignment.last_line(),
+                        &(self.lines_modifier.clone() + self.more_modifier.clone()),
+                        width,
+                    ),
+                };
+
+                s + sep + line.as_str()
+            });
 
         write!(f, "{}", text)
     }
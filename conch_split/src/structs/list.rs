@@ -0,0 +1,350 @@
+use std::fmt::Display;
+
+use conch_ansi::Modifier;
+use conch_base_models::{visible_width, StringWrapper};
+
+use crate::{Alignment, Lines, WrapOptions};
+
+/// Ways to number the items of a [`List`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Numbering {
+    /// `1`, `2`, `3`...
+    Decimal,
+
+    /// `a`, `b`, `c`... `z`, `aa`, `ab`...
+    LowerAlpha,
+
+    /// `A`, `B`, `C`... `Z`, `AA`, `AB`...
+    UpperAlpha,
+
+    /// `i`, `ii`, `iii`, `iv`...
+    LowerRoman,
+
+    /// `I`, `II`, `III`, `IV`...
+    UpperRoman,
+}
+impl Numbering {
+    /// Format `number` with this numbering.
+    ///
+    /// ```rust
+    /// use conch_split::Numbering;
+    ///
+    /// assert_eq!(Numbering::Decimal.format(12), "12");
+    /// assert_eq!(Numbering::LowerAlpha.format(28), "ab");
+    /// assert_eq!(Numbering::UpperRoman.format(1994), "MCMXCIV");
+    /// ```
+    pub fn format(&self, number: usize) -> String {
+        match self {
+            Self::Decimal => number.to_string(),
+            Self::LowerAlpha => alpha(number),
+            Self::UpperAlpha => alpha(number).to_uppercase(),
+            Self::LowerRoman => roman(number).to_lowercase(),
+            Self::UpperRoman => roman(number),
+        }
+    }
+}
+
+/// Format `number` in bijective base 26, where `1` is `a` and `27` is `aa`.
+fn alpha(mut number: usize) -> String {
+    let mut letters = Vec::new();
+
+    while number > 0 {
+        number -= 1;
+        letters.push(char::from(b'a' + (number % 26) as u8));
+        number /= 26;
+    }
+
+    letters.iter().rev().collect()
+}
+
+/// Format `number` in Roman numerals; `0` has none, so it is written in decimal.
+fn roman(mut number: usize) -> String {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    if number == 0 {
+        return String::from("0");
+    }
+
+    NUMERALS
+        .iter()
+        .fold(String::new(), |mut output, (value, numeral)| {
+            while number >= *value {
+                output.push_str(numeral);
+                number -= value;
+            }
+            output
+        })
+}
+
+/// The marker in front of each item of a [`List`] at one level of nesting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ListMarker {
+    /// The same symbol for every item, such as `•`.
+    Bullet(&'static str),
+
+    /// A number followed by a suffix, such as `1.`, `a)` or `iv.`.
+    Numbered(Numbering, &'static str),
+}
+impl ListMarker {
+    /// Format the marker of the item numbered `number`.
+    fn format(&self, number: usize) -> String {
+        match self {
+            Self::Bullet(bullet) => bullet.to_string(),
+            Self::Numbered(numbering, suffix) => numbering.format(number) + suffix,
+        }
+    }
+}
+
+/// An entry of a [`List`].
+#[derive(Clone, Debug)]
+pub enum ListItem {
+    /// An item with a marker in front of it.
+    Text(String),
+
+    /// A list nested under the previous item.
+    Sublist(List),
+
+    /// Any [`Lines`] nested under the previous item, such as a paragraph that
+    /// belongs to it.
+    Lines(Box<Lines>),
+}
+
+/// A bulleted or numbered list, which may be nested.
+///
+/// Lines of an item after the first, whether wrapped or explicit, are indented to
+/// line up with the text rather than the marker. Nested [`ListItem::Sublist`]s are
+/// rendered with the markers and modifiers of the next level of the list they are
+/// nested in.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{Lines, List};
+///
+/// let list = List::numbered()
+///     .item("veni")
+///     .sublist(List::new().item("I came").item("to Zela"))
+///     .item("vidi, vici")
+///     .width(12);
+///
+/// assert_eq!(
+///     Lines::from(&list).lines,
+///     vec![
+///         "1. veni",
+///         "   a) I came",
+///         "   b) to",
+///         "      Zela",
+///         "2. vidi,",
+///         "   vici",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct List {
+    pub items: Vec<ListItem>,
+    start: usize,
+    markers: Vec<ListMarker>,
+    marker_modifiers: Vec<Modifier>,
+    width: Option<u16>,
+}
+impl List {
+    /// Create a new, empty bulleted [`List`].
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            start: 1,
+            markers: vec![
+                ListMarker::Bullet("•"),
+                ListMarker::Bullet("◦"),
+                ListMarker::Bullet("▪"),
+            ],
+            marker_modifiers: Vec::new(),
+            width: None,
+        }
+    }
+
+    /// Create a new, empty numbered [`List`], numbered by `1.`, `a)` then `i.` as
+    /// it is nested.
+    pub fn numbered() -> Self {
+        Self::new().markers(vec![
+            ListMarker::Numbered(Numbering::Decimal, "."),
+            ListMarker::Numbered(Numbering::LowerAlpha, ")"),
+            ListMarker::Numbered(Numbering::LowerRoman, "."),
+        ])
+    }
+
+    /// Append an item to an instance of [`List`].
+    pub fn item<S>(mut self, text: S) -> Self
+    where
+        S: ToString,
+    {
+        self.items.push(ListItem::Text(text.to_string()));
+        self
+    }
+
+    /// Append multiple items to an instance of [`List`].
+    pub fn items<S>(self, texts: Vec<S>) -> Self
+    where
+        S: ToString,
+    {
+        texts.into_iter().fold(self, |list, text| list.item(text))
+    }
+
+    /// Nest a [`List`] under the last item of an instance of [`List`].
+    pub fn sublist(mut self, list: List) -> Self {
+        self.items.push(ListItem::Sublist(list));
+        self
+    }
+
+    /// Nest any [`Lines`] under the last item of an instance of [`List`].
+    pub fn lines<L>(mut self, lines: L) -> Self
+    where
+        L: Into<Lines>,
+    {
+        self.items.push(ListItem::Lines(Box::new(lines.into())));
+        self
+    }
+
+    /// A chained function to set the number of the first item
+    /// on an instance.
+    pub fn start(mut self, value: usize) -> Self {
+        self.start = value;
+        self
+    }
+
+    /// A chained function to set the [`ListMarker`] of each level of nesting
+    /// on an instance.
+    ///
+    /// Levels deeper than the number of markers given start from the first marker
+    /// again.
+    pub fn markers(mut self, value: Vec<ListMarker>) -> Self {
+        self.markers = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the markers of each level of
+    /// nesting on an instance.
+    ///
+    /// Levels deeper than the number of [`Modifier`]s given use the last one.
+    pub fn marker_modifiers(mut self, value: Vec<Modifier>) -> Self {
+        self.marker_modifiers = value;
+        self
+    }
+
+    /// A chained function to wrap the items to a maximum width
+    /// on an instance.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = Some(value);
+        self
+    }
+
+    /// Render the items of `list` at `level`, indented by `indent` columns, using
+    /// the markers and modifiers of `self`.
+    fn render(&self, list: &List, level: usize, indent: usize, lines: &mut Vec<String>) {
+        let marker = self
+            .markers
+            .get(level % self.markers.len().max(1))
+            .copied()
+            .unwrap_or(ListMarker::Bullet("-"));
+        let modifier = self
+            .marker_modifiers
+            .get(level)
+            .or(self.marker_modifiers.last());
+
+        let texts = list
+            .items
+            .iter()
+            .filter(|item| matches!(item, ListItem::Text(_)))
+            .count();
+        let markers: Vec<String> = (list.start..list.start + texts)
+            .map(|number| marker.format(number))
+            .collect();
+        let marker_width = markers
+            .iter()
+            .map(|marker| visible_width(marker))
+            .max()
+            .unwrap_or(0);
+
+        let nested_indent = " ".repeat(indent + marker_width + 1);
+        let mut markers = markers.into_iter();
+
+        list.items.iter().for_each(|item| match item {
+            ListItem::Text(text) => {
+                let marker = Alignment::Right.pad(
+                    &markers.next().expect("There is a marker for every item."),
+                    marker_width,
+                );
+                let marker = match modifier {
+                    Some(modifier) => modifier.wraps(&marker),
+                    None => marker,
+                };
+                let first = " ".repeat(indent) + &marker + " ";
+
+                text.split('\n').enumerate().for_each(|(idx, paragraph)| {
+                    // Only the first line of the item has the marker.
+                    let prefix = if idx == 0 { &first } else { &nested_indent };
+
+                    match self.width {
+                        Some(width) => lines.extend(
+                            WrapOptions::new(width)
+                                .initial_indent(prefix)
+                                .subsequent_indent(&nested_indent)
+                                .wrap(paragraph),
+                        ),
+                        None => lines.push(prefix.to_string() + paragraph),
+                    }
+                });
+            }
+            ListItem::Sublist(sublist) => {
+                self.render(sublist, level + 1, nested_indent.len(), lines)
+            }
+            ListItem::Lines(block) => lines.extend(
+                block
+                    .rendered()
+                    .into_iter()
+                    .map(|line| nested_indent.clone() + &line),
+            ),
+        });
+    }
+}
+impl Default for List {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&List> for Lines {
+    fn from(value: &List) -> Self {
+        let mut lines = Vec::new();
+        value.render(value, 0, 0, &mut lines);
+
+        Lines::new(lines)
+    }
+}
+
+impl From<List> for Lines {
+    fn from(value: List) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for List {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
@@ -0,0 +1,735 @@
+use std::fmt::Display;
+
+use conch_ansi::Modifier;
+use conch_base_models::{strip_escape_sequences, visible_width, Resetter, StringWrapper};
+use pulldown_cmark::{Alignment as ColumnAlignment, Event, Options, Parser, Tag, TagEnd};
+
+use crate::{
+    Alignment, Lines, List, ListMarker, Numbering, Table, TableColumn, WrapOptions,
+    DEFAULT_TERMINAL_WIDTH,
+};
+
+/// Bullets of unordered lists at each level of nesting.
+const BULLETS: [&str; 3] = ["•", "◦", "▪"];
+
+/// Escape sequence that closes a hyperlink.
+const HYPERLINK_CLOSE: &str = "\x1b]8;;\x1b\\";
+
+/// The [`Modifier`]s of each kind of element of a [`Markdown`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarkdownTheme {
+    /// Modifiers of headings by level, starting from `#`; levels deeper than the
+    /// number of modifiers given use the last one.
+    pub headings: Vec<Modifier>,
+    pub strong: Modifier,
+    pub emphasis: Modifier,
+    pub strikethrough: Modifier,
+
+    /// Inline code spans, such as `` `cargo build` ``.
+    pub code: Modifier,
+
+    /// Indented and fenced code blocks, applied to whole lines padded to the same
+    /// width.
+    pub code_block: Modifier,
+
+    /// The bar in front of each line of a block quote.
+    pub quote_bar: Modifier,
+    pub link: Modifier,
+    pub list_marker: Modifier,
+    pub table_header: Modifier,
+    pub rule: Modifier,
+}
+impl MarkdownTheme {
+    /// A theme without any styling.
+    pub fn plain() -> Self {
+        Self {
+            headings: Vec::new(),
+            strong: Modifier::Nothing,
+            emphasis: Modifier::Nothing,
+            strikethrough: Modifier::Nothing,
+            code: Modifier::Nothing,
+            code_block: Modifier::Nothing,
+            quote_bar: Modifier::Nothing,
+            link: Modifier::Nothing,
+            list_marker: Modifier::Nothing,
+            table_header: Modifier::Nothing,
+            rule: Modifier::Nothing,
+        }
+    }
+
+    /// The [`Modifier`] of headings at `level`.
+    fn heading(&self, level: usize) -> Modifier {
+        self.headings
+            .get(level.saturating_sub(1))
+            .or(self.headings.last())
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+impl Default for MarkdownTheme {
+    fn default() -> Self {
+        let bold = Modifier::intensity("Bold").unwrap_or_default();
+        let italic = Modifier::decoration("Italic").unwrap_or_default();
+        let underline = Modifier::decoration("Underline").unwrap_or_default();
+        let dimmed = Modifier::colour("BrightBlack").unwrap_or_default();
+
+        Self {
+            headings: vec![
+                bold.clone() + underline.clone(),
+                bold.clone(),
+                italic.clone(),
+            ],
+            strong: bold.clone(),
+            emphasis: italic,
+            strikethrough: Modifier::decoration("Strikethrough").unwrap_or_default(),
+            code: Modifier::background("Grayscale03").unwrap_or_default(),
+            code_block: Modifier::background("Grayscale02").unwrap_or_default(),
+            quote_bar: dimmed.clone(),
+            link: underline + Modifier::colour("BrightBlue").unwrap_or_default(),
+            list_marker: Modifier::Nothing,
+            table_header: bold,
+            rule: dimmed,
+        }
+    }
+}
+
+/// Ways to show the destinations of links of a [`Markdown`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LinkStyle {
+    /// Make the text of each link a terminal hyperlink, through the OSC 8 escape
+    /// sequence.
+    #[default]
+    Hyperlink,
+
+    /// Number each link, such as `conch[1]`, and list the destinations at the end.
+    ///
+    /// Links whose text is the destination itself are left as they are.
+    Footnotes,
+}
+
+/// A CommonMark document, rendered for the terminal with each kind of element
+/// styled by a [`MarkdownTheme`].
+///
+/// Lists are rendered through [`List`] and tables through [`Table`]. Besides
+/// CommonMark, tables, strikethrough and task lists are supported.
+///
+/// Requires the `markdown` feature.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{LinkStyle, Lines, Markdown, MarkdownTheme};
+///
+/// let markdown = Markdown::new(
+///     "# conch\n\nPretty output for [the shell](https://example.com).\n\n- veni\n- vidi",
+/// )
+/// .theme(MarkdownTheme::plain())
+/// .link_style(LinkStyle::Footnotes)
+/// .width(20);
+///
+/// assert_eq!(
+///     Lines::from(&markdown).lines,
+///     vec![
+///         "conch",
+///         "",
+///         "Pretty output for",
+///         "the shell[1].",
+///         "",
+///         "• veni",
+///         "• vidi",
+///         "",
+///         "[1]",
+///         "https://example.com",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Markdown {
+    pub source: String,
+    theme: MarkdownTheme,
+    link_style: LinkStyle,
+    width: Option<u16>,
+}
+impl Markdown {
+    /// Create a new instance of [`Markdown`] with the default [`MarkdownTheme`].
+    pub fn new<S>(source: S) -> Self
+    where
+        S: ToString,
+    {
+        Self {
+            source: source.to_string(),
+            theme: MarkdownTheme::default(),
+            link_style: LinkStyle::default(),
+            width: None,
+        }
+    }
+
+    /// A chained function to set the [`MarkdownTheme`]
+    /// on an instance.
+    pub fn theme(mut self, value: MarkdownTheme) -> Self {
+        self.theme = value;
+        self
+    }
+
+    /// A chained function to set the [`LinkStyle`]
+    /// on an instance.
+    pub fn link_style(mut self, value: LinkStyle) -> Self {
+        self.link_style = value;
+        self
+    }
+
+    /// A chained function to wrap the document to a maximum width
+    /// on an instance.
+    ///
+    /// Without a width, paragraphs are not wrapped at all, and horizontal rules are
+    /// [`DEFAULT_TERMINAL_WIDTH`] wide.
+    pub fn width(mut self, value: u16) -> Self {
+        self.width = Some(value);
+        self
+    }
+}
+
+/// Whether `event` belongs to the content of a paragraph, rather than being a block.
+fn is_inline(event: &Event) -> bool {
+    match event {
+        Event::Start(tag) => matches!(
+            tag,
+            Tag::Emphasis
+                | Tag::Strong
+                | Tag::Strikethrough
+                | Tag::Superscript
+                | Tag::Subscript
+                | Tag::Link { .. }
+                | Tag::Image { .. }
+        ),
+        Event::End(tag) => matches!(
+            tag,
+            TagEnd::Emphasis
+                | TagEnd::Strong
+                | TagEnd::Strikethrough
+                | TagEnd::Superscript
+                | TagEnd::Subscript
+                | TagEnd::Link
+                | TagEnd::Image
+        ),
+        Event::Text(_)
+        | Event::Code(_)
+        | Event::InlineMath(_)
+        | Event::DisplayMath(_)
+        | Event::InlineHtml(_)
+        | Event::FootnoteReference(_)
+        | Event::SoftBreak
+        | Event::HardBreak
+        | Event::TaskListMarker(_) => true,
+        Event::Html(_) | Event::Rule => false,
+    }
+}
+
+/// The resetters of each of the attributes `modifier` sets.
+fn resetters(modifier: &Modifier) -> Vec<Modifier> {
+    match modifier {
+        Modifier::Combo(modifiers) => modifiers.iter().flat_map(resetters).collect(),
+        Modifier::Nothing => Vec::new(),
+        modifier => vec![modifier.resetter(None)],
+    }
+}
+
+/// Convert a width into the width of [`WrapOptions`].
+fn wrap_width(width: usize) -> u16 {
+    u16::try_from(width.max(1)).unwrap_or(u16::MAX)
+}
+
+/// The state of rendering a [`Markdown`] while walking through its events.
+struct Renderer<'m> {
+    markdown: &'m Markdown,
+    events: Vec<Event<'m>>,
+
+    /// Index of the next event.
+    next: usize,
+
+    /// Number of lists the next event is nested in.
+    list_depth: usize,
+
+    /// Destinations of the links numbered so far, for [`LinkStyle::Footnotes`].
+    footnotes: Vec<String>,
+}
+impl<'m> Renderer<'m> {
+    fn new(markdown: &'m Markdown) -> Self {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+        Self {
+            markdown,
+            events: Parser::new_ext(&markdown.source, options).collect(),
+            next: 0,
+            list_depth: 0,
+            footnotes: Vec::new(),
+        }
+    }
+
+    fn theme(&self) -> &'m MarkdownTheme {
+        &self.markdown.theme
+    }
+
+    fn peek(&self) -> Option<&Event<'m>> {
+        self.events.get(self.next)
+    }
+
+    fn advance(&mut self) -> Option<Event<'m>> {
+        let event = self.events.get(self.next).cloned();
+        self.next += 1;
+        event
+    }
+
+    /// Render the whole document, followed by the footnotes of its links.
+    fn render(mut self) -> Vec<String> {
+        let width = self.markdown.width.map(usize::from);
+        let mut lines = self.blocks(width);
+
+        if !self.footnotes.is_empty() {
+            lines.push(String::new());
+        }
+        self.footnotes.iter().enumerate().for_each(|(idx, url)| {
+            let footnote = format!("[{}] {}", idx + 1, self.theme().link.wraps_non_empty(url));
+            lines.extend(Self::paragraph(&footnote, width));
+        });
+
+        lines
+            .iter()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    /// Render blocks until the end of the block they are nested in, separated by
+    /// blank lines.
+    fn blocks(&mut self, width: Option<usize>) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        // Text directly within the item of a tight list is not separated from the
+        // blocks around it.
+        let mut tight = false;
+
+        loop {
+            let (block, is_tight) = match self.peek() {
+                Some(event) if is_inline(event) => (
+                    Self::paragraph(&self.inline(&Modifier::Nothing), width),
+                    true,
+                ),
+                None | Some(Event::End(_)) => break,
+                Some(_) => (self.block(width), false),
+            };
+
+            if !lines.is_empty() && !tight && !block.is_empty() {
+                lines.push(String::new());
+            }
+            tight = is_tight;
+            lines.extend(block);
+        }
+
+        lines
+    }
+
+    /// Render the block starting at the next event.
+    fn block(&mut self, width: Option<usize>) -> Vec<String> {
+        let theme = self.theme();
+
+        let tag = match self.advance() {
+            Some(Event::Start(tag)) => tag,
+            Some(Event::Rule) => {
+                let width = width.unwrap_or(DEFAULT_TERMINAL_WIDTH);
+                return vec![theme.rule.wraps_non_empty(&"─".repeat(width))];
+            }
+            Some(Event::Html(html)) => return html.lines().map(str::to_string).collect(),
+            _ => return Vec::new(),
+        };
+
+        let lines = match tag {
+            Tag::Paragraph => Self::paragraph(&self.inline(&Modifier::Nothing), width),
+            Tag::Heading { level, .. } => {
+                let modifier = theme.heading(level as usize);
+                let text = self.inline(&modifier);
+                Self::paragraph(&modifier.wraps_non_empty(&text), width)
+            }
+            Tag::BlockQuote(_) => {
+                let bar = theme.quote_bar.wraps_non_empty("│");
+                self.blocks(width.map(|width| width.saturating_sub(2)))
+                    .iter()
+                    .map(|line| format!("{} {}", bar, line))
+                    .collect()
+            }
+            Tag::CodeBlock(_) => {
+                let mut code = String::new();
+                while let Some(Event::Text(text)) = self.peek() {
+                    code.push_str(text);
+                    self.next += 1;
+                }
+
+                self.code_block(&code, width)
+            }
+            Tag::HtmlBlock => {
+                let mut html = String::new();
+                while let Some(Event::Html(text) | Event::Text(text)) = self.peek() {
+                    html.push_str(text);
+                    self.next += 1;
+                }
+                html.lines().map(str::to_string).collect()
+            }
+            Tag::List(start) => self.list(start, width),
+            Tag::Table(alignments) => self.table(&alignments, width),
+            _ => {
+                // Anything else is not supported, so leave it out altogether.
+                self.skip();
+                return Vec::new();
+            }
+        };
+
+        // The end of the block.
+        self.advance();
+        lines
+    }
+
+    /// Skip the rest of the block that has just started, including its end.
+    fn skip(&mut self) {
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.advance() {
+                Some(Event::Start(_)) => depth += 1,
+                Some(Event::End(_)) => depth -= 1,
+                Some(_) => {}
+                None => break,
+            }
+        }
+    }
+
+    /// Render the content of a paragraph into a single line, with explicit newlines
+    /// at hard breaks.
+    ///
+    /// The text is to be wrapped in `outer`, such as the modifier of a heading. As
+    /// closing a style resets its attributes, the styles around it that set any of
+    /// them are opened again afterwards.
+    fn inline(&mut self, outer: &Modifier) -> String {
+        let theme = self.theme();
+        let link_style = self.markdown.link_style;
+
+        let mut text = String::new();
+        // The styles the text is within, outermost first.
+        let mut open: Vec<&Modifier> = vec![outer];
+        let close = |text: &mut String, open: &mut Vec<&Modifier>, modifier: &Modifier| {
+            text.push_str(&modifier.resetter(None).to_string());
+            if let Some(idx) = open.iter().rposition(|active| *active == modifier) {
+                open.remove(idx);
+            }
+
+            let reset = resetters(modifier);
+            open.iter()
+                .filter(|active| resetters(active).iter().any(|attr| reset.contains(attr)))
+                .for_each(|active| text.push_str(&active.to_string()));
+        };
+        // The destination of each link the text is within, with where its text
+        // starts.
+        let mut links: Vec<(String, usize)> = Vec::new();
+
+        while self.peek().is_some_and(is_inline) {
+            let Some(event) = self.advance() else {
+                break;
+            };
+
+            let (modifier, start) = match event {
+                Event::Text(content)
+                | Event::InlineHtml(content)
+                | Event::InlineMath(content)
+                | Event::DisplayMath(content) => {
+                    text.push_str(&content);
+                    continue;
+                }
+                Event::Code(code) => {
+                    text.push_str(&theme.code.wraps_non_empty(&code));
+                    continue;
+                }
+                Event::FootnoteReference(label) => {
+                    text.push_str(&format!("[{}]", label));
+                    continue;
+                }
+                Event::TaskListMarker(checked) => {
+                    text.push_str(if checked { "[x] " } else { "[ ] " });
+                    continue;
+                }
+                Event::SoftBreak => {
+                    text.push(' ');
+                    continue;
+                }
+                Event::HardBreak => {
+                    text.push('\n');
+                    continue;
+                }
+                Event::Start(Tag::Emphasis) => (&theme.emphasis, true),
+                Event::End(TagEnd::Emphasis) => (&theme.emphasis, false),
+                Event::Start(Tag::Strong) => (&theme.strong, true),
+                Event::End(TagEnd::Strong) => (&theme.strong, false),
+                Event::Start(Tag::Strikethrough) => (&theme.strikethrough, true),
+                Event::End(TagEnd::Strikethrough) => (&theme.strikethrough, false),
+                Event::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+                    if link_style == LinkStyle::Hyperlink {
+                        text.push_str(&format!("\x1b]8;;{}\x1b\\", dest_url));
+                    }
+                    links.push((dest_url.to_string(), text.len()));
+                    (&theme.link, true)
+                }
+                Event::End(TagEnd::Link | TagEnd::Image) => {
+                    close(&mut text, &mut open, &theme.link);
+
+                    if let Some((url, start)) = links.pop() {
+                        match link_style {
+                            LinkStyle::Hyperlink => text.push_str(HYPERLINK_CLOSE),
+                            LinkStyle::Footnotes
+                                if strip_escape_sequences(&text[start..]) != url =>
+                            {
+                                text.push_str(&format!("[{}]", self.footnote(url)))
+                            }
+                            LinkStyle::Footnotes => {}
+                        }
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            if start {
+                text.push_str(&modifier.to_string());
+                open.push(modifier);
+            } else {
+                close(&mut text, &mut open, modifier);
+            }
+        }
+
+        text
+    }
+
+    /// Number of the footnote of `url`, numbering it if it has not been yet.
+    fn footnote(&mut self, url: String) -> usize {
+        match self.footnotes.iter().position(|footnote| *footnote == url) {
+            Some(idx) => idx + 1,
+            None => {
+                self.footnotes.push(url);
+                self.footnotes.len()
+            }
+        }
+    }
+
+    /// Wrap `text` to `width`, if any.
+    fn paragraph(text: &str, width: Option<usize>) -> Vec<String> {
+        match width {
+            Some(width) => WrapOptions::new(wrap_width(width)).wrap(text),
+            None => text.split('\n').map(str::to_string).collect(),
+        }
+    }
+
+    /// Render a code block, with every line padded to the same width so that
+    /// [`MarkdownTheme::code_block`] covers a rectangle.
+    fn code_block(&self, code: &str, width: Option<usize>) -> Vec<String> {
+        let code = code.strip_suffix('\n').unwrap_or(code);
+        let lines: Vec<String> = match width {
+            Some(width) => {
+                let options = WrapOptions::new(wrap_width(width.saturating_sub(2)));
+                code.split('\n')
+                    .flat_map(|line| options.wrap(line))
+                    .collect()
+            }
+            None => code.split('\n').map(str::to_string).collect(),
+        };
+
+        let inner = width.map_or_else(
+            || {
+                lines
+                    .iter()
+                    .map(|line| visible_width(line))
+                    .max()
+                    .unwrap_or(0)
+            },
+            |width| width.saturating_sub(2),
+        );
+
+        lines
+            .iter()
+            .map(|line| {
+                let line = format!(" {} ", Alignment::Left.pad(line, inner));
+                self.theme().code_block.wraps(&line)
+            })
+            .collect()
+    }
+
+    /// Number of items of the list that has just started.
+    fn item_count(&self) -> usize {
+        let mut depth = 0;
+        let mut count = 0;
+
+        for event in &self.events[self.next..] {
+            match event {
+                Event::Start(Tag::Item) if depth == 0 => {
+                    count += 1;
+                    depth += 1;
+                }
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth == 0 => break,
+                Event::End(_) => depth -= 1,
+                _ => {}
+            }
+        }
+
+        count
+    }
+
+    /// Render the items of a list that has just started, numbered from `start` if
+    /// it is ordered.
+    fn list(&mut self, start: Option<u64>, width: Option<usize>) -> Vec<String> {
+        let count = self.item_count();
+        let (marker, marker_width) = match start {
+            Some(start) => {
+                let start = usize::try_from(start).unwrap_or(usize::MAX);
+                let last = start.saturating_add(count.saturating_sub(1));
+                (
+                    ListMarker::Numbered(Numbering::Decimal, "."),
+                    Numbering::Decimal.format(last).len() + 1,
+                )
+            }
+            None => {
+                let bullet = BULLETS[self.list_depth % BULLETS.len()];
+                (ListMarker::Bullet(bullet), visible_width(bullet))
+            }
+        };
+
+        // Items of loose lists are made of paragraphs, and separated by blank lines.
+        let loose = matches!(
+            self.events.get(self.next + 1),
+            Some(Event::Start(Tag::Paragraph))
+        );
+        let content_width = width.map(|width| width.saturating_sub(marker_width + 1));
+
+        let mut list = List::new()
+            .markers(vec![marker])
+            .marker_modifiers(vec![self.theme().list_marker.clone()])
+            .start(start.map_or(1, |start| usize::try_from(start).unwrap_or(usize::MAX)));
+
+        self.list_depth += 1;
+        for idx in 0..count {
+            // The start of the item.
+            self.advance();
+            let mut lines = self.blocks(content_width).into_iter();
+            // The end of the item.
+            self.advance();
+
+            if loose && idx > 0 {
+                list = list.lines(Lines::new(vec![String::new()]));
+            }
+            list = list.item(lines.next().unwrap_or_default());
+
+            let rest: Vec<String> = lines.collect();
+            if !rest.is_empty() {
+                list = list.lines(Lines::new(rest));
+            }
+        }
+        self.list_depth -= 1;
+
+        Lines::from(&list).lines
+    }
+
+    /// Render the cells of the head or a row of a table.
+    fn cells(&mut self) -> Vec<String> {
+        let mut cells = Vec::new();
+
+        while let Some(Event::Start(Tag::TableCell)) = self.peek() {
+            self.advance();
+            cells.push(self.inline(&Modifier::Nothing));
+            // The end of the cell.
+            self.advance();
+        }
+
+        cells
+    }
+
+    /// Render a table that has just started, with its columns narrowed to fit
+    /// within `width` if needed.
+    fn table(&mut self, alignments: &[ColumnAlignment], width: Option<usize>) -> Vec<String> {
+        let mut table = Table::new().header_modifier(self.theme().table_header.clone());
+
+        while let Some(Event::Start(tag)) = self.peek().cloned() {
+            self.advance();
+            let cells = self.cells();
+            table = match tag {
+                Tag::TableHead => table.headers(cells),
+                _ => table.row(cells),
+            };
+            // The end of the head or row.
+            self.advance();
+        }
+
+        let natural: Vec<usize> = (0..alignments.len())
+            .map(|idx| {
+                table
+                    .rows
+                    .iter()
+                    .chain([&table.headers])
+                    .filter_map(|row| row.get(idx))
+                    .map(|cell| visible_width(cell))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        // Share the width between the columns, narrowest first, so that only the
+        // widest columns are wrapped.
+        let mut limits = natural.clone();
+        if let Some(width) = width {
+            let mut remaining = width.saturating_sub(3 * natural.len() + 1);
+            let mut order: Vec<usize> = (0..natural.len()).collect();
+            order.sort_by_key(|idx| natural[*idx]);
+
+            order.iter().enumerate().for_each(|(rank, idx)| {
+                let share = remaining / (natural.len() - rank);
+                limits[*idx] = natural[*idx].min(share).max(1);
+                remaining = remaining.saturating_sub(limits[*idx]);
+            });
+        }
+
+        let table = alignments
+            .iter()
+            .enumerate()
+            .fold(table, |table, (idx, alignment)| {
+                let alignment = match alignment {
+                    ColumnAlignment::None | ColumnAlignment::Left => Alignment::Left,
+                    ColumnAlignment::Center => Alignment::Center,
+                    ColumnAlignment::Right => Alignment::Right,
+                };
+                let column = TableColumn::new().alignment(alignment);
+                let column = if limits[idx] < natural[idx] {
+                    column.max_width(limits[idx])
+                } else {
+                    column
+                };
+
+                table.column(idx, column)
+            });
+
+        Lines::from(&table).lines
+    }
+}
+
+impl From<&Markdown> for Lines {
+    fn from(value: &Markdown) -> Self {
+        Lines::new(Renderer::new(value).render())
+    }
+}
+
+impl From<Markdown> for Lines {
+    fn from(value: Markdown) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for Markdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
@@ -1,2 +1,31 @@
+mod border;
+pub use border::*;
+mod columns;
+pub use columns::*;
+mod diff;
+pub use diff::*;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::*;
+mod key_values;
+pub use key_values::*;
+mod line_rules;
 mod lines;
 pub use lines::*;
+mod list;
+pub use list::*;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "markdown")]
+pub use markdown::*;
+mod pages;
+pub use pages::*;
+mod panel;
+pub use panel::*;
+mod table;
+pub use table::*;
+mod tree;
+pub use tree::*;
+mod wrap;
+pub use wrap::*;
@@ -0,0 +1,46 @@
+use crate::Lines;
+
+/// An iterator over pages of [`Lines`], each fitting in a fixed number of terminal
+/// rows.
+///
+/// Created by [`Lines::pages()`].
+#[derive(Clone, Debug)]
+pub struct Pages<'a> {
+    lines: &'a Lines,
+    capacity: usize,
+    next: usize,
+}
+impl<'a> Pages<'a> {
+    /// Create a new instance of [`Pages`] of `height` terminal rows each.
+    pub(crate) fn new(lines: &'a Lines, height: usize) -> Self {
+        Self {
+            lines,
+            capacity: lines.capacity(height).max(1),
+            next: 0,
+        }
+    }
+}
+
+impl Iterator for Pages<'_> {
+    type Item = Lines;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.lines.lines.len() {
+            return None;
+        }
+
+        let start = self.next;
+        self.next = start
+            .saturating_add(self.capacity)
+            .min(self.lines.lines.len());
+
+        Some(self.lines.page(start..self.next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pages = (self.lines.lines.len() - self.next).div_ceil(self.capacity);
+        (pages, Some(pages))
+    }
+}
+
+impl ExactSizeIterator for Pages<'_> {}
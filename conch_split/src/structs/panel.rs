@@ -0,0 +1,278 @@
+use std::fmt::Display;
+
+use conch_ansi::Modifier;
+use conch_base_models::{visible_width, StringWrapper};
+
+use crate::{truncate_to_width, Alignment, BorderSet, Lines, WrapOptions};
+
+/// A block of [`Lines`] framed in a border, with an optional title in the top edge
+/// and footer in the bottom edge.
+///
+/// Anything that can be converted into [`Lines`] can be framed, such as a
+/// `CalendarMonth`. All widths are measured in terminal columns ignoring any escape
+/// codes, so styled content still lines up with the right edge.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{Alignment, BorderSet, Lines, Panel};
+///
+/// let panel = Panel::new(vec!["veni", "vidi", "vici"])
+///     .title("Caesar")
+///     .title_alignment(Alignment::Center)
+///     .footer("47 BC")
+///     .border(BorderSet::ROUNDED);
+///
+/// assert_eq!(
+///     Lines::from(&panel).lines,
+///     vec![
+///         "╭─ Caesar ─╮",
+///         "│ veni     │",
+///         "│ vidi     │",
+///         "│ vici     │",
+///         "╰─ 47 BC ──╯",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Panel {
+    pub content: Vec<String>,
+    pub title: Option<String>,
+    pub footer: Option<String>,
+    title_alignment: Alignment,
+    footer_alignment: Alignment,
+    border: BorderSet,
+    border_modifier: Modifier,
+    padding: usize,
+    vertical_padding: usize,
+    width: Option<usize>,
+}
+impl Panel {
+    /// Create a new instance of [`Panel`] around `content`, with
+    /// [`BorderSet::LIGHT`] borders fitted to the content.
+    pub fn new<L>(content: L) -> Self
+    where
+        L: Into<Lines>,
+    {
+        Self {
+            content: content.into().rendered(),
+            title: None,
+            footer: None,
+            title_alignment: Alignment::Left,
+            footer_alignment: Alignment::Left,
+            border: BorderSet::default(),
+            border_modifier: Modifier::Nothing,
+            padding: 1,
+            vertical_padding: 0,
+            width: None,
+        }
+    }
+
+    /// A chained function to set [`Panel::title`]
+    /// on an instance.
+    pub fn title<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.title = Some(value.to_string());
+        self
+    }
+
+    /// A chained function to set [`Panel::footer`]
+    /// on an instance.
+    pub fn footer<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.footer = Some(value.to_string());
+        self
+    }
+
+    /// A chained function to set the [`Alignment`] of the title
+    /// on an instance.
+    pub fn title_alignment(mut self, value: Alignment) -> Self {
+        self.title_alignment = value;
+        self
+    }
+
+    /// A chained function to set the [`Alignment`] of the footer
+    /// on an instance.
+    pub fn footer_alignment(mut self, value: Alignment) -> Self {
+        self.footer_alignment = value;
+        self
+    }
+
+    /// A chained function to set the [`BorderSet`]
+    /// on an instance.
+    pub fn border(mut self, value: BorderSet) -> Self {
+        self.border = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the border
+    /// on an instance.
+    pub fn border_modifier(mut self, value: Modifier) -> Self {
+        self.border_modifier = value;
+        self
+    }
+
+    /// A chained function to set the number of spaces between the content and the
+    /// left and right borders on an instance.
+    pub fn padding(mut self, value: usize) -> Self {
+        self.padding = value;
+        self
+    }
+
+    /// A chained function to set the number of blank lines between the content and
+    /// the top and bottom borders on an instance.
+    pub fn vertical_padding(mut self, value: usize) -> Self {
+        self.vertical_padding = value;
+        self
+    }
+
+    /// A chained function to fix the total width of the panel, including its
+    /// borders, on an instance.
+    ///
+    /// Content wider than the space available is wrapped; if this is not set, the
+    /// panel is fitted to its content.
+    pub fn width(mut self, value: usize) -> Self {
+        self.width = Some(value);
+        self
+    }
+
+    /// Apply [`Self::border_modifier`] to a part of the border.
+    fn paint_border(&self, part: &str) -> String {
+        if part.is_empty() {
+            String::new()
+        } else {
+            self.border_modifier.wraps(part)
+        }
+    }
+
+    /// Width between the left and right borders.
+    fn inner_width(&self) -> usize {
+        match self.width {
+            Some(width) => width
+                .saturating_sub(visible_width(self.border.left))
+                .saturating_sub(visible_width(self.border.right)),
+            None => {
+                let content = self
+                    .content
+                    .iter()
+                    .map(|line| visible_width(line))
+                    .max()
+                    .unwrap_or(0);
+
+                // Leave room for a space on each side of the title and footer, and at
+                // least one border character on each side of those.
+                [&self.title, &self.footer]
+                    .into_iter()
+                    .flatten()
+                    .map(|label| visible_width(label) + 4)
+                    .fold(content + self.padding * 2, usize::max)
+            }
+        }
+    }
+
+    /// Draw the top or bottom edge, with an optional `label` embedded in it.
+    fn edge(
+        &self,
+        (left, line, right): (&str, &str, &str),
+        label: Option<&String>,
+        alignment: Alignment,
+        width: usize,
+    ) -> Option<String> {
+        let fill = if line.is_empty() { " " } else { line };
+
+        let Some(label) = label else {
+            return (!line.is_empty())
+                .then(|| self.paint_border(&(left.to_string() + &fill.repeat(width) + right)));
+        };
+
+        let label = format!(
+            " {} ",
+            truncate_to_width(label, width.saturating_sub(4), "…")
+        );
+        let space = width.saturating_sub(visible_width(&label));
+        let before = match alignment {
+            Alignment::Left | Alignment::Justify => 1.min(space),
+            Alignment::Center => space / 2,
+            Alignment::Right | Alignment::Decimal => space.saturating_sub(1),
+        };
+
+        Some(
+            self.paint_border(&(left.to_string() + &fill.repeat(before)))
+                + &label
+                + &self.paint_border(&(fill.repeat(space - before) + right)),
+        )
+    }
+}
+
+impl From<&Panel> for Lines {
+    fn from(value: &Panel) -> Self {
+        let width = value.inner_width();
+        let content_width = width.saturating_sub(value.padding * 2).max(1);
+        let padding = " ".repeat(value.padding);
+
+        let content: Vec<String> = match value.width {
+            Some(_) => {
+                let options = WrapOptions::new(u16::try_from(content_width).unwrap_or(u16::MAX));
+                value
+                    .content
+                    .iter()
+                    .flat_map(|line| options.wrap(line))
+                    .collect()
+            }
+            None => value.content.clone(),
+        };
+        let blank = vec![String::new(); value.vertical_padding];
+
+        let body = blank
+            .iter()
+            .chain(content.iter())
+            .chain(blank.iter())
+            .map(|line| {
+                value.paint_border(value.border.left)
+                    + &padding
+                    + &Alignment::Left.pad(line, content_width)
+                    + &padding
+                    + &value.paint_border(value.border.right)
+            });
+
+        let top = value.edge(
+            (
+                value.border.top_left,
+                value.border.top,
+                value.border.top_right,
+            ),
+            value.title.as_ref(),
+            value.title_alignment,
+            width,
+        );
+        let bottom = value.edge(
+            (
+                value.border.bottom_left,
+                value.border.bottom,
+                value.border.bottom_right,
+            ),
+            value.footer.as_ref(),
+            value.footer_alignment,
+            width,
+        );
+
+        Lines::new(top.into_iter().chain(body).chain(bottom).collect())
+    }
+}
+
+impl From<Panel> for Lines {
+    fn from(value: Panel) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for Panel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
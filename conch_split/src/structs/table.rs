@@ -0,0 +1,420 @@
+use std::fmt::Display;
+
+use conch_ansi::Modifier;
+use conch_base_models::{visible_width, StringWrapper};
+
+use crate::{
+    decimal_widths, truncate_to_width, Alignment, BorderSet, Lines, Overflow, WrapOptions,
+};
+
+/// Options of a single column of a [`Table`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableColumn {
+    pub alignment: Alignment,
+    pub min_width: Option<usize>,
+    pub max_width: Option<usize>,
+    pub overflow: Overflow,
+}
+impl TableColumn {
+    /// Create a new instance of [`TableColumn`], left aligned without any width
+    /// limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A chained function to set [`TableColumn::alignment`]
+    /// on an instance.
+    pub fn alignment(mut self, value: Alignment) -> Self {
+        self.alignment = value;
+        self
+    }
+
+    /// A chained function to set [`TableColumn::min_width`]
+    /// on an instance.
+    pub fn min_width(mut self, value: usize) -> Self {
+        self.min_width = Some(value);
+        self
+    }
+
+    /// A chained function to set [`TableColumn::max_width`]
+    /// on an instance.
+    ///
+    /// Cells wider than this are wrapped or truncated according to
+    /// [`TableColumn::overflow`].
+    pub fn max_width(mut self, value: usize) -> Self {
+        self.max_width = Some(value);
+        self
+    }
+
+    /// A chained function to set [`TableColumn::overflow`]
+    /// on an instance.
+    pub fn overflow(mut self, value: Overflow) -> Self {
+        self.overflow = value;
+        self
+    }
+
+    /// Lay out the lines of a cell to `width`.
+    fn layout(&self, cell: &str, width: usize) -> Vec<String> {
+        match self.overflow {
+            Overflow::Wrap => {
+                let width = u16::try_from(width).unwrap_or(u16::MAX);
+                WrapOptions::new(width).wrap(cell)
+            }
+            Overflow::Truncate => cell
+                .split('\n')
+                .map(|line| truncate_to_width(line, width, "…"))
+                .collect(),
+        }
+    }
+}
+
+/// A table of styled cells, rendered into [`Lines`].
+///
+/// Column widths are measured in terminal columns, ignoring any escape codes within
+/// the cells.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{Alignment, BorderSet, Lines, Table, TableColumn};
+///
+/// let table = Table::new()
+///     .headers(vec!["Fruit", "Price"])
+///     .row(vec!["Apple", "1.5"])
+///     .row(vec!["Durian", "12.25"])
+///     .column(1, TableColumn::new().alignment(Alignment::Decimal))
+///     .border(BorderSet::ASCII);
+///
+/// assert_eq!(
+///     Lines::from(&table).lines,
+///     vec![
+///         "+--------+-------+",
+///         "| Fruit  | Price |",
+///         "+--------+-------+",
+///         "| Apple  |  1.5  |",
+///         "| Durian | 12.25 |",
+///         "+--------+-------+",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Table {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    columns: Vec<TableColumn>,
+    border: BorderSet,
+    padding: usize,
+    header_modifier: Modifier,
+    stripe_modifier: Modifier,
+}
+impl Table {
+    /// Create a new, empty [`Table`] with [`BorderSet::LIGHT`] borders.
+    pub fn new() -> Self {
+        Self {
+            headers: Vec::new(),
+            rows: Vec::new(),
+            columns: Vec::new(),
+            border: BorderSet::default(),
+            padding: 1,
+            header_modifier: Modifier::Nothing,
+            stripe_modifier: Modifier::Nothing,
+        }
+    }
+
+    /// A chained function to set [`Table::headers`]
+    /// on an instance.
+    pub fn headers<S>(mut self, headers: Vec<S>) -> Self
+    where
+        S: ToString,
+    {
+        self.headers = headers.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Append a row to an instance of [`Table`].
+    pub fn row<S>(mut self, row: Vec<S>) -> Self
+    where
+        S: ToString,
+    {
+        self.rows.push(row.iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    /// Append multiple rows to an instance of [`Table`].
+    pub fn rows<S>(self, rows: Vec<Vec<S>>) -> Self
+    where
+        S: ToString,
+    {
+        rows.into_iter().fold(self, |table, row| table.row(row))
+    }
+
+    /// A chained function to set the options of the column at `index`.
+    pub fn column(mut self, index: usize, column: TableColumn) -> Self {
+        if self.columns.len() <= index {
+            self.columns.resize(index + 1, TableColumn::default());
+        }
+        self.columns[index] = column;
+        self
+    }
+
+    /// A chained function to set the [`BorderSet`]
+    /// on an instance.
+    pub fn border(mut self, value: BorderSet) -> Self {
+        self.border = value;
+        self
+    }
+
+    /// A chained function to set the number of spaces on each side of a cell
+    /// on an instance.
+    pub fn padding(mut self, value: usize) -> Self {
+        self.padding = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the header cells
+    /// on an instance.
+    pub fn header_modifier(mut self, value: Modifier) -> Self {
+        self.header_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of every other row of the body,
+    /// starting from the second, on an instance.
+    pub fn stripe_modifier(mut self, value: Modifier) -> Self {
+        self.stripe_modifier = value;
+        self
+    }
+
+    /// Number of columns in this table.
+    fn column_count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.headers.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the options of the column at `index`.
+    fn column_options(&self, index: usize) -> TableColumn {
+        self.columns.get(index).cloned().unwrap_or_default()
+    }
+
+    /// Iterate over the cells of the column at `index` in the body.
+    fn body_cells(&self, index: usize) -> impl Iterator<Item = &str> {
+        self.rows
+            .iter()
+            .map(move |row| row.get(index).map_or("", String::as_str))
+    }
+
+    /// For [`Alignment::Decimal`], the widest integer and fractional parts of the
+    /// column at `index`.
+    fn decimal_widths(&self, index: usize) -> (usize, usize) {
+        self.body_cells(index)
+            .flat_map(|cell| cell.split('\n'))
+            .map(decimal_widths)
+            .fold((0, 0), |(int, frac), (cell_int, cell_frac)| {
+                (int.max(cell_int), frac.max(cell_frac))
+            })
+    }
+
+    /// Like [`Self::decimal_widths()`], but measured on the lines of the cells once
+    /// laid out within `width`, as they are wrapped or truncated.
+    fn laid_out_decimal_widths(&self, index: usize, width: usize) -> (usize, usize) {
+        let options = self.column_options(index);
+
+        self.body_cells(index)
+            .flat_map(|cell| options.layout(cell, width))
+            .map(|line| decimal_widths(&line))
+            .fold((0, 0), |(int, frac), (line_int, line_frac)| {
+                (int.max(line_int), frac.max(line_frac))
+            })
+    }
+
+    /// Width of the content of the column at `index`.
+    fn column_width(&self, index: usize) -> usize {
+        let options = self.column_options(index);
+
+        let header_width = self
+            .headers
+            .get(index)
+            .map_or(0, |header| max_line_width(header));
+
+        let body_width = match options.alignment {
+            Alignment::Decimal => {
+                let (int, frac) = self.decimal_widths(index);
+                int + frac
+            }
+            _ => self
+                .body_cells(index)
+                .map(max_line_width)
+                .max()
+                .unwrap_or(0),
+        };
+
+        let width = header_width.max(body_width);
+        let width = options.max_width.map_or(width, |max| width.min(max));
+        options.min_width.map_or(width, |min| width.max(min)).max(1)
+    }
+
+    /// Render a row of `cells` into lines, with each of the cells wrapped in
+    /// `modifier`.
+    ///
+    /// `decimals` holds the [`Self::laid_out_decimal_widths()`] of each column, which
+    /// are only used by the columns of [`Alignment::Decimal`].
+    fn render_row(
+        &self,
+        cells: &[String],
+        widths: &[usize],
+        decimals: &[(usize, usize)],
+        modifier: &Modifier,
+        is_header: bool,
+    ) -> Vec<String> {
+        let padding = " ".repeat(self.padding);
+
+        let columns: Vec<Vec<String>> = widths
+            .iter()
+            .enumerate()
+            .map(|(index, width)| {
+                let options = self.column_options(index);
+                let cell = cells.get(index).map_or("", String::as_str);
+                let lines = options.layout(cell, *width);
+
+                match (options.alignment, is_header) {
+                    (Alignment::Decimal, false) => {
+                        let (int, frac) = decimals[index];
+                        lines
+                            .iter()
+                            .map(|line| {
+                                // Lines that cannot be aligned within the width are
+                                // padded as far as they fit.
+                                let (line_int, line_frac) = decimal_widths(line);
+                                let room = width.saturating_sub(visible_width(line));
+                                let left = int.saturating_sub(line_int).min(room);
+                                let right = frac.saturating_sub(line_frac).min(room - left);
+
+                                " ".repeat(left) + line + &" ".repeat(right)
+                            })
+                            .map(|line| Alignment::Right.pad(&line, *width))
+                            .collect()
+                    }
+                    (alignment, _) => lines
+                        .iter()
+                        .enumerate()
+                        .map(|(line_idx, line)| {
+                            if line_idx + 1 == lines.len() {
+                                alignment.last_line().pad(line, *width)
+                            } else {
+                                alignment.pad(line, *width)
+                            }
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let height = columns.iter().map(Vec::len).max().unwrap_or(0);
+
+        (0..height)
+            .map(|line_idx| {
+                let cells: Vec<String> = columns
+                    .iter()
+                    .zip(widths)
+                    .map(|(lines, width)| {
+                        let content = lines
+                            .get(line_idx)
+                            .cloned()
+                            .unwrap_or_else(|| " ".repeat(*width));
+                        modifier.wraps(&(padding.clone() + &content + &padding))
+                    })
+                    .collect();
+
+                self.border.left.to_string() + &cells.join(self.border.vertical) + self.border.right
+            })
+            .collect()
+    }
+}
+impl Default for Table {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Width of the widest line within `text`.
+fn max_line_width(text: &str) -> usize {
+    text.split('\n').map(visible_width).max().unwrap_or(0)
+}
+
+impl From<&Table> for Lines {
+    fn from(value: &Table) -> Self {
+        let widths: Vec<usize> = (0..value.column_count())
+            .map(|index| value.column_width(index))
+            .collect();
+
+        if widths.is_empty() {
+            return Lines::new(Vec::new());
+        }
+
+        let decimals: Vec<(usize, usize)> = widths
+            .iter()
+            .enumerate()
+            .map(
+                |(index, width)| match value.column_options(index).alignment {
+                    Alignment::Decimal => value.laid_out_decimal_widths(index, *width),
+                    _ => (0, 0),
+                },
+            )
+            .collect();
+
+        let padded_widths = || widths.iter().map(|width| width + value.padding * 2);
+        let mut lines = Vec::new();
+
+        lines.extend(BorderSet::horizontal(
+            padded_widths(),
+            value.border.top_parts(),
+        ));
+
+        if !value.headers.is_empty() {
+            lines.extend(value.render_row(
+                &value.headers,
+                &widths,
+                &decimals,
+                &value.header_modifier,
+                true,
+            ));
+            lines.extend(BorderSet::horizontal(
+                padded_widths(),
+                value.border.rule_parts(),
+            ));
+        }
+
+        value.rows.iter().enumerate().for_each(|(row_idx, row)| {
+            let modifier = if row_idx % 2 == 1 {
+                &value.stripe_modifier
+            } else {
+                &Modifier::Nothing
+            };
+            lines.extend(value.render_row(row, &widths, &decimals, modifier, false));
+        });
+
+        lines.extend(BorderSet::horizontal(
+            padded_widths(),
+            value.border.bottom_parts(),
+        ));
+
+        Lines::new(lines)
+    }
+}
+
+impl From<Table> for Lines {
+    fn from(value: Table) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
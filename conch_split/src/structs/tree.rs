@@ -0,0 +1,287 @@
+use std::fmt::Display;
+
+use conch_ansi::Modifier;
+use conch_base_models::StringWrapper;
+
+use crate::Lines;
+
+/// A node of a [`Tree`], with a label that may be styled or span multiple lines.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeNode {
+    pub label: String,
+    pub children: Vec<TreeNode>,
+
+    /// Whether the children of this node are hidden, in which case
+    /// the collapsed marker of the [`Tree`] is shown after its label instead.
+    pub collapsed: bool,
+}
+impl TreeNode {
+    /// Create a new instance of [`TreeNode`] without any children.
+    pub fn new<S>(label: S) -> Self
+    where
+        S: ToString,
+    {
+        Self {
+            label: label.to_string(),
+            children: Vec::new(),
+            collapsed: false,
+        }
+    }
+
+    /// Append a child to an instance of [`TreeNode`].
+    pub fn child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Append multiple children to an instance of [`TreeNode`].
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator<Item = TreeNode>,
+    {
+        self.children.extend(children);
+        self
+    }
+
+    /// A chained function to set [`TreeNode::collapsed`]
+    /// on an instance.
+    pub fn collapsed(mut self, value: bool) -> Self {
+        self.collapsed = value;
+        self
+    }
+}
+
+impl<S> From<S> for TreeNode
+where
+    S: AsRef<str>,
+{
+    fn from(value: S) -> Self {
+        Self::new(value.as_ref())
+    }
+}
+
+/// The guides drawn in front of the nodes of a [`Tree`].
+///
+/// All parts should have the same display width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TreeGuides {
+    /// In front of a node that has siblings after it.
+    pub branch: &'static str,
+
+    /// In front of the last node among its siblings.
+    pub last: &'static str,
+
+    /// In front of descendants of a node that has siblings after it.
+    pub vertical: &'static str,
+
+    /// In front of descendants of the last node among its siblings.
+    pub blank: &'static str,
+}
+impl TreeGuides {
+    /// Guides drawn with Unicode box drawing characters.
+    pub const UNICODE: Self = Self {
+        branch: "├── ",
+        last: "└── ",
+        vertical: "│   ",
+        blank: "    ",
+    };
+
+    /// Guides drawn with ASCII characters only.
+    pub const ASCII: Self = Self {
+        branch: "|-- ",
+        last: "`-- ",
+        vertical: "|   ",
+        blank: "    ",
+    };
+}
+impl Default for TreeGuides {
+    fn default() -> Self {
+        Self::UNICODE
+    }
+}
+
+/// A hierarchy of [`TreeNode`]s, rendered into [`Lines`] with guides showing the
+/// structure.
+///
+/// Continuation lines of multi-line labels are prefixed with the guides of their
+/// node, so they line up under the first line of the label.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{Lines, Tree, TreeNode};
+///
+/// let tree = Tree::new(
+///     TreeNode::new("conch")
+///         .child(TreeNode::new("conch_ansi").child("regex".into()))
+///         .child(TreeNode::new("conch_split").child("unicode-width".into()).collapsed(true))
+///         .child("multi-line\nlabel".into()),
+/// );
+///
+/// assert_eq!(
+///     Lines::from(&tree).lines,
+///     vec![
+///         "conch",
+///         "├── conch_ansi",
+///         "│   └── regex",
+///         "├── conch_split […]",
+///         "└── multi-line",
+///         "    label",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tree {
+    pub root: TreeNode,
+    guides: TreeGuides,
+    guide_modifier: Modifier,
+    depth_modifiers: Vec<Modifier>,
+    collapsed_marker: String,
+}
+impl Tree {
+    /// Create a new instance of [`Tree`] with [`TreeGuides::UNICODE`].
+    pub fn new(root: TreeNode) -> Self {
+        Self {
+            root,
+            guides: TreeGuides::default(),
+            guide_modifier: Modifier::Nothing,
+            depth_modifiers: Vec::new(),
+            collapsed_marker: String::from(" […]"),
+        }
+    }
+
+    /// A chained function to set the [`TreeGuides`]
+    /// on an instance.
+    pub fn guides(mut self, value: TreeGuides) -> Self {
+        self.guides = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`] of the guides
+    /// on an instance.
+    pub fn guide_modifier(mut self, value: Modifier) -> Self {
+        self.guide_modifier = value;
+        self
+    }
+
+    /// A chained function to set the [`Modifier`]s of the labels by depth, starting
+    /// from the root, on an instance.
+    ///
+    /// Nodes deeper than the number of [`Modifier`]s given use the last one.
+    pub fn depth_modifiers(mut self, value: Vec<Modifier>) -> Self {
+        self.depth_modifiers = value;
+        self
+    }
+
+    /// A chained function to set the marker shown after the label of a collapsed
+    /// node on an instance.
+    pub fn collapsed_marker<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.collapsed_marker = value.to_string();
+        self
+    }
+
+    /// Apply the [`Modifier`] of `depth` to a line of a label.
+    fn paint_label(&self, line: &str, depth: usize) -> String {
+        match self
+            .depth_modifiers
+            .get(depth)
+            .or(self.depth_modifiers.last())
+        {
+            Some(modifier) if !line.is_empty() => modifier.wraps(line),
+            _ => line.to_string(),
+        }
+    }
+
+    /// Apply [`Self::guide_modifier`] to a guide.
+    fn paint_guide(&self, guide: &str) -> String {
+        if guide.trim().is_empty() {
+            guide.to_string()
+        } else {
+            self.guide_modifier.wraps(guide)
+        }
+    }
+
+    /// Render the label of `node`, with `first` in front of its first line and
+    /// `rest` in front of the others.
+    fn render_label(
+        &self,
+        node: &TreeNode,
+        depth: usize,
+        (first, rest): (&str, &str),
+        lines: &mut Vec<String>,
+    ) {
+        let mut label = node.label.clone();
+        if node.collapsed && !node.children.is_empty() {
+            label = match label.split_once('\n') {
+                Some((head, tail)) => format!("{}{}\n{}", head, self.collapsed_marker, tail),
+                None => label + &self.collapsed_marker,
+            };
+        }
+
+        label.split('\n').enumerate().for_each(|(idx, line)| {
+            let guide = if idx == 0 { first } else { rest };
+            lines.push(guide.to_string() + &self.paint_label(line, depth));
+        });
+    }
+
+    /// Render the descendants of `node`, each prefixed by `prefix`.
+    fn render_children(
+        &self,
+        node: &TreeNode,
+        prefix: &str,
+        depth: usize,
+        lines: &mut Vec<String>,
+    ) {
+        if node.collapsed {
+            return;
+        }
+
+        node.children.iter().enumerate().for_each(|(idx, child)| {
+            let is_last = idx + 1 == node.children.len();
+            let (guide, continuation) = if is_last {
+                (self.guides.last, self.guides.blank)
+            } else {
+                (self.guides.branch, self.guides.vertical)
+            };
+            let continuation = prefix.to_string() + &self.paint_guide(continuation);
+
+            self.render_label(
+                child,
+                depth,
+                (
+                    &(prefix.to_string() + &self.paint_guide(guide)),
+                    &continuation,
+                ),
+                lines,
+            );
+            self.render_children(child, &continuation, depth + 1, lines);
+        });
+    }
+}
+
+impl From<&Tree> for Lines {
+    fn from(value: &Tree) -> Self {
+        let mut lines = Vec::new();
+
+        value.render_label(&value.root, 0, ("", ""), &mut lines);
+        value.render_children(&value.root, "", 1, &mut lines);
+
+        Lines::new(lines)
+    }
+}
+
+impl From<Tree> for Lines {
+    fn from(value: Tree) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl Display for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Lines::from(self))
+    }
+}
@@ -0,0 +1,530 @@
+//! ANSI aware word wrapping, used by [`crate::Lines::from_text()`].
+
+use std::fmt::Write;
+
+use conch_ansi::{Background, Colour, Decoration, Intensity, Modifier};
+use conch_base_models::{
+    scan_escape_sequence, visible_width, ANSIEscapeCodeRef, EscapeSequenceScan, Resetter,
+    ESCAPE_CHAR,
+};
+use unicode_width::UnicodeWidthChar;
+
+/// Escape code that resets all styles.
+const RESET_ALL: &str = "\x1b[0m";
+
+/// Prefix of the Operating System Command that opens or closes a hyperlink.
+const HYPERLINK_PREFIX: &str = "\x1b]8;";
+
+/// Escape sequence that closes a hyperlink.
+const HYPERLINK_CLOSE: &str = "\x1b]8;;\x1b\\";
+
+/// Options for wrapping a text into lines of a maximum display width.
+///
+/// Example
+/// -------
+///
+/// ```rust
+/// use conch_split::{Lines, WrapOptions};
+///
+/// let lines = Lines::from_text_with(
+///     "--verbose  Print everything that is happening.",
+///     WrapOptions::new(24).subsequent_indent("           "),
+/// );
+///
+/// assert_eq!(
+///     lines.lines,
+///     vec![
+///         "--verbose  Print",
+///         "           everything",
+///         "           that is",
+///         "           happening.",
+///     ]
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrapOptions {
+    /// Maximum display width of each line, including any indent.
+    pub width: u16,
+
+    /// Indent of the first line of each paragraph.
+    pub initial_indent: String,
+
+    /// Indent of all the other lines.
+    pub subsequent_indent: String,
+
+    /// Whether to end a line with `-` when a word too long to fit on a line is
+    /// broken up.
+    pub hyphenate: bool,
+
+    /// Whether to remove whitespace at the end of each line.
+    pub trim_trailing_whitespace: bool,
+}
+impl WrapOptions {
+    /// Create a new instance of [`WrapOptions`] with no indent, no hyphenation and
+    /// trailing whitespace trimmed.
+    pub fn new(width: u16) -> Self {
+        Self {
+            width,
+            initial_indent: String::new(),
+            subsequent_indent: String::new(),
+            hyphenate: false,
+            trim_trailing_whitespace: true,
+        }
+    }
+
+    /// A chained function to set [`WrapOptions::initial_indent`]
+    /// on an instance.
+    pub fn initial_indent<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.initial_indent = value.to_string();
+        self
+    }
+
+    /// A chained function to set [`WrapOptions::subsequent_indent`]
+    /// on an instance.
+    pub fn subsequent_indent<S>(mut self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        self.subsequent_indent = value.to_string();
+        self
+    }
+
+    /// A chained function to set both [`WrapOptions::initial_indent`] and
+    /// [`WrapOptions::subsequent_indent`] on an instance.
+    pub fn indent<S>(self, value: S) -> Self
+    where
+        S: ToString,
+    {
+        let value = value.to_string();
+        self.initial_indent(&value).subsequent_indent(value)
+    }
+
+    /// A chained function to set [`WrapOptions::hyphenate`]
+    /// on an instance.
+    pub fn hyphenate(mut self, value: bool) -> Self {
+        self.hyphenate = value;
+        self
+    }
+
+    /// A chained function to set [`WrapOptions::trim_trailing_whitespace`]
+    /// on an instance.
+    pub fn trim_trailing_whitespace(mut self, value: bool) -> Self {
+        self.trim_trailing_whitespace = value;
+        self
+    }
+
+    /// Wrap `text` into lines.
+    ///
+    /// Lines are broken at whitespace wherever possible; words longer than a whole
+    /// line are broken up wherever they need to be. Explicit newlines in `text` are
+    /// kept, with each of them starting a new paragraph.
+    ///
+    /// Widths are measured in terminal columns, ignoring any escape codes. If `text`
+    /// contains styles, every line is self-contained: styles active at the end of a
+    /// line are closed there, and opened again at the start of the next line.
+    pub fn wrap(&self, text: &str) -> Vec<String> {
+        let mut wrapper = Wrapper::new(self);
+
+        text.split('\n')
+            .map(|paragraph| paragraph.strip_suffix('\r').unwrap_or(paragraph))
+            .for_each(|paragraph| wrapper.push_paragraph(paragraph));
+
+        wrapper.lines
+    }
+}
+
+/// Cut `text` short to at most `width` columns, ending it with `ellipsis` if
+/// anything has been cut.
+///
+/// Widths are measured ignoring any escape codes. Escape codes before the cut are
+/// kept, and any styles they leave active are closed after the `ellipsis`.
+///
+/// ```rust
+/// use conch_split::truncate_to_width;
+///
+/// assert_eq!(truncate_to_width("veni, vidi, vici", 10, "…"), "veni, vid…");
+/// assert_eq!(
+///     truncate_to_width("\x1b[1mveni, vidi\x1b[22m, vici", 6, "…"),
+///     "\x1b[1mveni,…\x1b[22m",
+/// );
+/// assert_eq!(truncate_to_width("veni", 4, "…"), "veni");
+/// ```
+pub fn truncate_to_width(text: &str, width: usize, ellipsis: &str) -> String {
+    if visible_width(text) <= width {
+        return text.to_string();
+    }
+
+    let available = width.saturating_sub(visible_width(ellipsis));
+    let mut output = String::with_capacity(text.len());
+    let mut style = ActiveStyle::default();
+    let mut used = 0;
+
+    for unit in units(text) {
+        if unit.as_bytes()[0] == ESCAPE_CHAR {
+            style.update(unit);
+            output.push_str(unit);
+            continue;
+        }
+
+        used += visible_width(unit);
+        if used > available {
+            break;
+        }
+        output.push_str(unit);
+    }
+
+    output.push_str(ellipsis);
+    style.write_close(&mut output);
+    output
+}
+
+/// Split `text` into escape sequences and single characters.
+fn units(text: &str) -> impl Iterator<Item = &str> {
+    let mut position = 0;
+
+    std::iter::from_fn(move || {
+        let rest = &text[position..];
+
+        let len = match scan_escape_sequence(rest.as_bytes()) {
+            EscapeSequenceScan::Complete(len) => {
+                // A two-byte sequence may swallow the first byte of a multibyte char.
+                (len..=rest.len())
+                    .find(|len| rest.is_char_boundary(*len))
+                    .unwrap_or(rest.len())
+            }
+            // An unterminated escape sequence swallows the rest of the text.
+            EscapeSequenceScan::Incomplete => rest.len(),
+            EscapeSequenceScan::NotEscape => rest.chars().next()?.len_utf8(),
+        };
+
+        position += len;
+        Some(&rest[..len])
+    })
+}
+
+/// Split `paragraph` into runs of whitespace and words, flagging the whitespace.
+///
+/// Escape sequences are counted as part of the words.
+fn segments(paragraph: &str) -> Vec<(bool, &str)> {
+    let mut segments: Vec<(bool, &str)> = Vec::new();
+    let mut start = 0;
+
+    for unit in units(paragraph) {
+        let end = start + unit.len();
+        let is_space = unit.starts_with(char::is_whitespace);
+
+        match segments.last_mut() {
+            Some((last_is_space, segment)) if *last_is_space == is_space => {
+                *segment = &paragraph[start - segment.len()..end];
+            }
+            _ => segments.push((is_space, unit)),
+        }
+
+        start = end;
+    }
+
+    segments
+}
+
+/// The styles that are in effect at a point of a text.
+#[derive(Clone, Debug, Default)]
+struct ActiveStyle {
+    intensity: Option<Intensity>,
+    colour: Option<Colour>,
+    background: Option<Background>,
+    decorations: Vec<Decoration>,
+
+    /// The escape sequence that opened the hyperlink the text is within, if any.
+    hyperlink: Option<String>,
+
+    /// Escape codes that cannot be parsed into a [`Modifier`], such as truecolour
+    /// ones, with the code that resets their attribute if known; they can only be
+    /// closed by resetting everything.
+    others: Vec<(Option<u16>, String)>,
+}
+impl ActiveStyle {
+    /// The code that resets the attribute set or reset by the SGR `code`, which
+    /// identifies the attributes that override each other.
+    fn resetter_code(code: u16) -> Option<u16> {
+        match code {
+            1 | 2 | 22 => Some(22),
+            3 | 23 => Some(23),
+            4 | 21 | 24 => Some(24),
+            5 | 6 | 25 => Some(25),
+            7 | 27 => Some(27),
+            8 | 28 => Some(28),
+            9 | 29 => Some(29),
+            30..=39 | 90..=97 => Some(39),
+            40..=49 | 100..=107 => Some(49),
+            58 | 59 => Some(59),
+            _ => None,
+        }
+    }
+
+    /// Update the styles with an escape sequence found in the text.
+    fn update(&mut self, escape: &str) {
+        if let Some(link) = escape.strip_prefix(HYPERLINK_PREFIX) {
+            // A hyperlink with an empty URI closes the one that is open.
+            let link = link
+                .strip_suffix('\x07')
+                .or_else(|| link.strip_suffix("\x1b\\"))
+                .unwrap_or(link);
+            let uri = link.split_once(';').map_or("", |(_, uri)| uri);
+            self.hyperlink = (!uri.is_empty()).then(|| escape.to_string());
+            return;
+        }
+
+        if escape == "\x1b[m" {
+            *self = Self {
+                hyperlink: self.hyperlink.take(),
+                ..Self::default()
+            };
+            return;
+        }
+
+        let Ok(code) = ANSIEscapeCodeRef::parse(escape) else {
+            return;
+        };
+
+        if code.escape_code.end_char != 'm' {
+            return;
+        }
+
+        if code.escape_code.code == Some(0) && code.escape_code.modifiers.is_empty() {
+            *self = Self {
+                hyperlink: self.hyperlink.take(),
+                ..Self::default()
+            };
+            return;
+        }
+
+        // Anything setting or resetting an attribute overrides the unparsable codes
+        // setting the same one.
+        let resetter = code.escape_code.code.and_then(Self::resetter_code);
+        if resetter.is_some() {
+            self.others.retain(|(other, _)| *other != resetter);
+        }
+
+        match code.try_convert::<Modifier>() {
+            Ok(Modifier::Intensity(Intensity::Normal)) => self.intensity = None,
+            Ok(Modifier::Intensity(intensity)) => self.intensity = Some(intensity),
+            Ok(Modifier::Colour(Colour::Reset)) => self.colour = None,
+            Ok(Modifier::Colour(colour)) => self.colour = Some(colour),
+            Ok(Modifier::Background(Background::Reset)) => self.background = None,
+            Ok(Modifier::Background(background)) => self.background = Some(background),
+            Ok(Modifier::Decoration(decoration)) => {
+                let resetter = decoration.resetter(None);
+                self.decorations
+                    .retain(|active| active.resetter(None) != resetter);
+
+                if decoration != resetter {
+                    self.decorations.push(decoration);
+                }
+            }
+            Ok(_) => {}
+            // Resetting an attribute leaves nothing to reopen.
+            Err(_) if resetter.is_some() && resetter == code.escape_code.code => {}
+            Err(_) => self.others.push((resetter, escape.to_string())),
+        }
+    }
+
+    /// Write the escape codes that open all the active styles.
+    fn write_open(&self, w: &mut String) {
+        self.intensity.iter().for_each(|intensity| {
+            write!(w, "{}", intensity).expect("Writing to a `String` should never fail.")
+        });
+        self.colour.iter().for_each(|colour| {
+            write!(w, "{}", colour).expect("Writing to a `String` should never fail.")
+        });
+        self.background.iter().for_each(|background| {
+            write!(w, "{}", background).expect("Writing to a `String` should never fail.")
+        });
+        self.decorations.iter().for_each(|decoration| {
+            write!(w, "{}", decoration).expect("Writing to a `String` should never fail.")
+        });
+        self.others
+            .iter()
+            .for_each(|(_, escape)| w.push_str(escape));
+        self.hyperlink.iter().for_each(|escape| w.push_str(escape));
+    }
+
+    /// Write the escape codes that close all the active styles, in reverse order.
+    fn write_close(&self, w: &mut String) {
+        if self.hyperlink.is_some() {
+            w.push_str(HYPERLINK_CLOSE);
+        }
+
+        if !self.others.is_empty() {
+            w.push_str(RESET_ALL);
+            return;
+        }
+
+        self.decorations.iter().rev().for_each(|decoration| {
+            write!(w, "{}", decoration.resetter(None))
+                .expect("Writing to a `String` should never fail.")
+        });
+        self.background.iter().for_each(|background| {
+            write!(w, "{}", background.resetter(None))
+                .expect("Writing to a `String` should never fail.")
+        });
+        self.colour.iter().for_each(|colour| {
+            write!(w, "{}", colour.resetter(None))
+                .expect("Writing to a `String` should never fail.")
+        });
+        self.intensity.iter().for_each(|intensity| {
+            write!(w, "{}", intensity.resetter(None))
+                .expect("Writing to a `String` should never fail.")
+        });
+    }
+}
+
+/// The state of [`WrapOptions::wrap()`] while it is building lines.
+struct Wrapper<'o> {
+    options: &'o WrapOptions,
+    lines: Vec<String>,
+    style: ActiveStyle,
+
+    /// The line being built.
+    line: String,
+
+    /// Display width of [`Self::line`], excluding its indent.
+    width: usize,
+
+    /// Whether [`Self::line`] is the first line of its paragraph.
+    first_line: bool,
+
+    /// Whitespace waiting for a word to follow it before it is added to the line.
+    pending: String,
+}
+impl<'o> Wrapper<'o> {
+    fn new(options: &'o WrapOptions) -> Self {
+        Self {
+            options,
+            lines: Vec::new(),
+            style: ActiveStyle::default(),
+            line: String::new(),
+            width: 0,
+            first_line: true,
+            pending: String::new(),
+        }
+    }
+
+    /// Display width available for the content of the current line.
+    fn available(&self) -> usize {
+        let indent = if self.first_line {
+            &self.options.initial_indent
+        } else {
+            &self.options.subsequent_indent
+        };
+
+        usize::from(self.options.width)
+            .saturating_sub(visible_width(indent))
+            .max(1)
+    }
+
+    /// Start a new line, with its indent and all the active styles.
+    fn start_line(&mut self, first_line: bool) {
+        self.first_line = first_line;
+        self.width = 0;
+        self.pending.clear();
+
+        self.line = if first_line {
+            self.options.initial_indent.clone()
+        } else {
+            self.options.subsequent_indent.clone()
+        };
+        self.style.write_open(&mut self.line);
+    }
+
+    /// Close all the active styles, and add the current line to the output.
+    fn finish_line(&mut self) {
+        let mut line = std::mem::take(&mut self.line);
+
+        if self.options.trim_trailing_whitespace {
+            line.truncate(line.trim_end().len());
+        } else {
+            line.push_str(&self.pending);
+        }
+
+        self.style.write_close(&mut line);
+        self.lines.push(line);
+    }
+
+    /// Add a run of escape sequences and characters to the current line.
+    fn push_str(&mut self, text: &str) {
+        units(text)
+            .filter(|unit| unit.as_bytes()[0] == ESCAPE_CHAR)
+            .for_each(|escape| self.style.update(escape));
+
+        self.line.push_str(text);
+        self.width += visible_width(text);
+    }
+
+    fn push_paragraph(&mut self, paragraph: &str) {
+        self.start_line(true);
+
+        for (is_space, segment) in segments(paragraph) {
+            if is_space {
+                self.pending.push_str(segment);
+            } else {
+                self.push_word(segment);
+            }
+        }
+
+        self.finish_line();
+    }
+
+    fn push_word(&mut self, word: &str) {
+        let width = visible_width(word);
+
+        if self.width > 0 && self.width + visible_width(&self.pending) + width > self.available() {
+            self.finish_line();
+            self.start_line(false);
+        }
+
+        // Only keep leading whitespace on the first line of a paragraph, and only if
+        // the word still fits after it.
+        let pending = std::mem::take(&mut self.pending);
+        if (self.width > 0 || self.first_line)
+            && self.width + visible_width(&pending) + width <= self.available()
+        {
+            self.push_str(&pending);
+        }
+
+        if self.width + width <= self.available() {
+            self.push_str(word);
+        } else {
+            self.push_long_word(word, width);
+        }
+    }
+
+    /// Add a word that does not fit on a line, breaking it up wherever it needs to.
+    fn push_long_word(&mut self, word: &str, mut remaining: usize) {
+        for unit in units(word) {
+            let unit_width = unit
+                .chars()
+                .next()
+                .filter(|_| unit.as_bytes()[0] != ESCAPE_CHAR)
+                .and_then(UnicodeWidthChar::width)
+                .unwrap_or(0);
+            remaining -= unit_width;
+
+            let hyphen = usize::from(self.options.hyphenate && remaining > 0);
+            if unit_width > 0
+                && self.width > 0
+                && self.width + unit_width + hyphen > self.available()
+            {
+                if self.options.hyphenate {
+                    self.line.push('-');
+                }
+                self.finish_line();
+                self.start_line(false);
+            }
+
+            self.push_str(unit);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use conch_split::{BorderSet, ColumnWidth, Columns, Lines, Table, VerticalAlignment};
+
+#[cfg(test)]
+mod test_columns {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $columns:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let columns: Columns = $columns;
+                println!("Test '{}':\n{}", stringify!($name), columns);
+
+                assert_eq!(Lines::from(&columns).lines, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        fitted_blocks,
+        Columns::new().push(vec!["veni", "vidi", "vici"]).push(vec![
+            "I came",
+            "I saw",
+            "I conquered"
+        ]),
+        vec![
+            "veni  I came     ",
+            "vidi  I saw      ",
+            "vici  I conquered"
+        ]
+    );
+
+    test_factory!(
+        gutter,
+        Columns::new()
+            .push(vec!["a", "b"])
+            .push(vec!["c", "d"])
+            .gutter(" | "),
+        vec!["a | c", "b | d"]
+    );
+
+    test_factory!(
+        vertical_alignment_top,
+        Columns::new()
+            .push(vec!["veni", "vidi", "vici"])
+            .push(vec!["47 BC"]),
+        vec!["veni  47 BC", "vidi       ", "vici       "]
+    );
+
+    test_factory!(
+        vertical_alignment_middle,
+        Columns::new()
+            .push(vec!["veni", "vidi", "vici"])
+            .push(vec!["47 BC"])
+            .vertical_alignment(VerticalAlignment::Middle),
+        vec!["veni       ", "vidi  47 BC", "vici       "]
+    );
+
+    test_factory!(
+        vertical_alignment_bottom,
+        Columns::new()
+            .push(vec!["veni", "vidi", "vici"])
+            .push(vec!["47 BC"])
+            .vertical_alignment(VerticalAlignment::Bottom),
+        vec!["veni       ", "vidi       ", "vici  47 BC"]
+    );
+
+    test_factory!(
+        styled_blocks,
+        Columns::new()
+            .push(vec!["\x1b[1mveni\x1b[22m", "vidi!"])
+            .push(vec!["vici"]),
+        vec!["\x1b[1mveni\x1b[22m   vici", "vidi!      "]
+    );
+
+    test_factory!(
+        fixed_width_wraps,
+        Columns::new()
+            .push_with(vec!["veni vidi vici"], ColumnWidth::Fixed(9))
+            .push(vec!["47 BC"]),
+        vec!["veni vidi  47 BC", "vici            "]
+    );
+
+    test_factory!(
+        flex_widths_by_weight,
+        Columns::new()
+            .push_with(vec!["a"], ColumnWidth::Flex(1))
+            .push(vec!["|"])
+            .push_with(vec!["b"], ColumnWidth::Flex(2))
+            .gutter("")
+            .width(11),
+        vec!["a   |b     "]
+    );
+
+    test_factory!(
+        flex_rounding,
+        Columns::new()
+            .push_with(vec!["a"], ColumnWidth::Flex(1))
+            .push_with(vec!["b"], ColumnWidth::Flex(1))
+            .gutter("")
+            .width(5),
+        vec!["a  b "]
+    );
+
+    test_factory!(
+        leading_blank_line,
+        Columns::new().push(vec!["", "veni"]).push(vec!["vidi"]),
+        vec!["      vidi", "veni      "]
+    );
+
+    test_factory!(
+        legend_next_to_table,
+        Columns::new()
+            .push(Table::new().row(vec!["1", "2"]).border(BorderSet::ASCII))
+            .push(Lines::from(vec!["1: one", "2: two"]).title("Legend"))
+            .vertical_alignment(VerticalAlignment::Middle),
+        vec![
+            "+---+---+  Legend",
+            "| 1 | 2 |        ",
+            "+---+---+  1: one",
+            "           2: two",
+        ]
+    );
+
+    test_factory!(empty, Columns::new(), Vec::<String>::new());
+}
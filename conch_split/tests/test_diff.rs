@@ -0,0 +1,212 @@
+use conch_ansi::Modifier;
+use conch_split::{Diff, Lines};
+
+const NUMBERS: &str = "1\n2\n3\n4\n5\n6\n7\n8\n9";
+
+#[cfg(test)]
+mod test_diff {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $diff:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let diff: Diff = $diff;
+                println!("Test '{}':\n{}", stringify!($name), diff);
+
+                assert_eq!(Lines::from(&diff).lines, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        identical,
+        Diff::from_text(NUMBERS, NUMBERS),
+        Vec::<String>::new()
+    );
+
+    test_factory!(
+        escape_codes_are_not_differences,
+        Diff::from_text("veni\n\x1b[1mvidi\x1b[22m", "veni\nvidi"),
+        Vec::<String>::new()
+    );
+
+    test_factory!(
+        escape_codes_are_stripped,
+        Diff::from_text("\x1b[1mveni\x1b[22m", "\x1b[1mvidi\x1b[22m").plain(),
+        vec!["@@ -1 +1 @@", "-veni", "+vidi"]
+    );
+
+    test_factory!(
+        labels,
+        Diff::from_text("veni", "vidi")
+            .labels("a/caesar.txt", "b/caesar.txt")
+            .plain(),
+        vec![
+            "--- a/caesar.txt",
+            "+++ b/caesar.txt",
+            "@@ -1 +1 @@",
+            "-veni",
+            "+vidi"
+        ]
+    );
+
+    test_factory!(
+        context,
+        Diff::from_text(NUMBERS, NUMBERS.replace('5', "V")).plain(),
+        vec![
+            "@@ -2,7 +2,7 @@",
+            " 2",
+            " 3",
+            " 4",
+            "-5",
+            "+V",
+            " 6",
+            " 7",
+            " 8"
+        ]
+    );
+
+    test_factory!(
+        separate_hunks,
+        Diff::from_text(NUMBERS, NUMBERS.replace('2', "II").replace('8', "VIII"))
+            .context(1)
+            .plain(),
+        vec![
+            "@@ -1,3 +1,3 @@",
+            " 1",
+            "-2",
+            "+II",
+            " 3",
+            "@@ -7,3 +7,3 @@",
+            " 7",
+            "-8",
+            "+VIII",
+            " 9"
+        ]
+    );
+
+    test_factory!(
+        merged_hunks,
+        Diff::from_text(NUMBERS, NUMBERS.replace('3', "III").replace('6', "VI"))
+            .context(1)
+            .plain(),
+        vec![
+            "@@ -2,6 +2,6 @@",
+            " 2",
+            "-3",
+            "+III",
+            " 4",
+            " 5",
+            "-6",
+            "+VI",
+            " 7"
+        ]
+    );
+
+    test_factory!(
+        insertion_without_context,
+        Diff::from_text("veni\nvici", "veni\nvidi\nvici")
+            .context(0)
+            .plain(),
+        vec!["@@ -1,0 +2 @@", "+vidi"]
+    );
+
+    test_factory!(
+        deletion_at_end,
+        Diff::from_text("veni\nvidi\nvici", "veni\nvidi")
+            .context(1)
+            .plain(),
+        vec!["@@ -2,2 +2 @@", " vidi", "-vici"]
+    );
+
+    test_factory!(
+        from_lines,
+        Diff::new(
+            Lines::from(vec!["veni", "vidi"]).prefix("> "),
+            Lines::from(vec!["veni", "vici"]).prefix("> ")
+        )
+        .plain(),
+        vec!["@@ -1,2 +1,2 @@", " > veni", "-> vidi", "+> vici"]
+    );
+
+    test_factory!(
+        styled,
+        Diff::from_text("veni\nvidi", "veni\nvici")
+            .context(0)
+            .removed_highlight(Modifier::Nothing)
+            .added_highlight(Modifier::Nothing),
+        vec![
+            "\x1b[38;5;6m@@ -2 +2 @@\x1b[39m",
+            "\x1b[38;5;1m-vidi\x1b[39m",
+            "\x1b[38;5;2m+vici\x1b[39m"
+        ]
+    );
+
+    test_factory!(
+        highlight_words,
+        Diff::from_text("veni vidi vici", "veni vidi vinci"),
+        vec![
+            "\x1b[38;5;6m@@ -1 +1 @@\x1b[39m",
+            "\x1b[38;5;1m-veni vidi \x1b[48;5;52mvici\x1b[49m\x1b[39m",
+            "\x1b[38;5;2m+veni vidi \x1b[48;5;22mvinci\x1b[49m\x1b[39m"
+        ]
+    );
+
+    test_factory!(
+        highlight_insertion_only,
+        Diff::from_text("veni vici", "veni vidi vici")
+            .removed_modifier(Modifier::Nothing)
+            .added_modifier(Modifier::Nothing)
+            .hunk_modifier(Modifier::Nothing),
+        vec![
+            "@@ -1 +1 @@",
+            "-veni vici",
+            "+veni \x1b[48;5;22mvidi \x1b[49mvici"
+        ]
+    );
+
+    test_factory!(
+        no_highlight_without_common_words,
+        Diff::from_text("veni", "vidi")
+            .removed_modifier(Modifier::Nothing)
+            .added_modifier(Modifier::Nothing)
+            .hunk_modifier(Modifier::Nothing),
+        vec!["@@ -1 +1 @@", "-veni", "+vidi"]
+    );
+
+    test_factory!(
+        empty_new,
+        Diff::new(vec!["1", "2", "1"], Vec::<String>::new()).plain(),
+        vec!["@@ -1,3 +0,0 @@", "-1", "-2", "-1"]
+    );
+
+    test_factory!(
+        empty_old,
+        Diff::new(Vec::<String>::new(), vec!["1", "2"]).plain(),
+        vec!["@@ -0,0 +1,2 @@", "+1", "+2"]
+    );
+
+    test_factory!(
+        leading_blank_line,
+        Diff::from_text("\nveni", "\nvidi").plain(),
+        vec!["@@ -1,2 +1,2 @@", " ", "-veni", "+vidi"]
+    );
+
+    test_factory!(
+        leading_blank_line_removed,
+        Diff::from_text("\nveni", "veni").plain(),
+        vec!["@@ -1,2 +1 @@", "-", " veni"]
+    );
+
+    test_factory!(
+        both_empty,
+        Diff::new(Vec::<String>::new(), Vec::<String>::new()),
+        Vec::<String>::new()
+    );
+}
@@ -0,0 +1,197 @@
+//! Test pretty-printing of [`Json`].
+#![cfg(feature = "json")]
+
+use conch_ansi::Modifier;
+use conch_split::{Json, JsonTheme, Lines, Panel};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Package {
+    name: &'static str,
+    version: (u8, u8, u8),
+    license: Option<&'static str>,
+    published: bool,
+}
+
+#[cfg(test)]
+mod test_json {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $json:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let json: Json = $json;
+                println!("Test '{}':\n{}", stringify!($name), json);
+
+                assert_eq!(Lines::from(&json).lines, $expected);
+            }
+        };
+    }
+
+    fn plain(text: &str) -> Json {
+        Json::parse(text).unwrap().theme(JsonTheme::plain())
+    }
+
+    test_factory!(scalar, plain("42"), vec!["42"]);
+
+    test_factory!(
+        escaped_string,
+        plain(r#""veni\n\"vidi\"""#),
+        vec![r#""veni\n\"vidi\"""#]
+    );
+
+    test_factory!(
+        empty_containers,
+        plain(r#"{"array": [], "object": {}}"#),
+        vec![r#"{"#, r#"  "array": [],"#, r#"  "object": {}"#, r#"}"#]
+    );
+
+    test_factory!(
+        nested,
+        plain(r#"[1, {"veni": [true, null]}]"#),
+        vec![
+            r#"["#,
+            r#"  1,"#,
+            r#"  {"#,
+            r#"    "veni": ["#,
+            r#"      true,"#,
+            r#"      null"#,
+            r#"    ]"#,
+            r#"  }"#,
+            r#"]"#,
+        ]
+    );
+
+    test_factory!(
+        keeps_key_order,
+        plain(r#"{"vidi": 2, "veni": 1, "vici": 3}"#),
+        vec![
+            r#"{"#,
+            r#"  "vidi": 2,"#,
+            r#"  "veni": 1,"#,
+            r#"  "vici": 3"#,
+            r#"}"#
+        ]
+    );
+
+    test_factory!(
+        indent,
+        plain(r#"{"veni": [1]}"#).indent(4),
+        vec![
+            r#"{"#,
+            r#"    "veni": ["#,
+            r#"        1"#,
+            r#"    ]"#,
+            r#"}"#
+        ]
+    );
+
+    test_factory!(
+        max_array_items,
+        plain("[1, 2, 3, 4, 5]").max_array_items(2),
+        vec!["[", "  1,", "  2,", "  … 3 more items", "]"]
+    );
+
+    test_factory!(
+        max_array_items_not_exceeded,
+        plain("[1, 2]").max_array_items(2),
+        vec!["[", "  1,", "  2", "]"]
+    );
+
+    test_factory!(
+        max_depth,
+        plain(r#"[[1, [2]], {"veni": {}}]"#).max_depth(1),
+        vec!["[", "  […],", r#"  {…}"#, "]"]
+    );
+
+    test_factory!(max_depth_zero, plain("[1, 2]").max_depth(0), vec!["[…]"]);
+
+    test_factory!(
+        themed,
+        Json::parse(r#"{"s": "veni", "n": 1.5, "b": false, "z": null}"#).unwrap(),
+        vec![
+            "{",
+            "  \x1b[38;5;12m\"s\"\x1b[39m: \x1b[38;5;2m\"veni\"\x1b[39m,",
+            "  \x1b[38;5;12m\"n\"\x1b[39m: \x1b[38;5;6m1.5\x1b[39m,",
+            "  \x1b[38;5;12m\"b\"\x1b[39m: \x1b[38;5;3mfalse\x1b[39m,",
+            "  \x1b[38;5;12m\"z\"\x1b[39m: \x1b[38;5;8mnull\x1b[39m",
+            "}",
+        ]
+    );
+
+    test_factory!(
+        themed_punctuation_and_marker,
+        Json::parse("[[1], 2, 3]")
+            .unwrap()
+            .theme(JsonTheme {
+                punctuation: Modifier::intensity("Bold").unwrap(),
+                marker: Modifier::colour("BrightRed").unwrap(),
+                ..JsonTheme::plain()
+            })
+            .max_depth(1)
+            .max_array_items(1),
+        vec![
+            "\x1b[1m[\x1b[22m",
+            "  \x1b[1m[\x1b[22m\x1b[38;5;9m…\x1b[39m\x1b[1m]\x1b[22m\x1b[1m,\x1b[22m",
+            "  \x1b[38;5;9m… 2 more items\x1b[39m",
+            "\x1b[1m]\x1b[22m",
+        ]
+    );
+
+    test_factory!(
+        from_serialize,
+        Json::from_serialize(&Package {
+            name: "conch",
+            version: (0, 1, 0),
+            license: None,
+            published: false,
+        })
+        .unwrap()
+        .theme(JsonTheme::plain())
+        .max_depth(1),
+        vec![
+            r#"{"#,
+            r#"  "name": "conch","#,
+            r#"  "version": […],"#,
+            r#"  "license": null,"#,
+            r#"  "published": false"#,
+            r#"}"#,
+        ]
+    );
+
+    #[test]
+    fn parse_error() {
+        assert!(Json::parse("{veni}").is_err());
+    }
+
+    #[test]
+    fn composes_with_lines() {
+        let lines = Lines::from(plain("[1, 2, 3]"))
+            .max_height(3)
+            .more_modifier(Modifier::Nothing);
+
+        assert_eq!(lines.to_string(), "[\n  1,\n… 3 more lines");
+    }
+
+    #[test]
+    fn composes_with_panel() {
+        let panel = Panel::new(plain(r#"{"veni": 1}"#)).title("JSON");
+
+        assert_eq!(
+            Lines::from(&panel).lines,
+            vec![
+                "┌─ JSON ──────┐",
+                "│ {           │",
+                r#"│   "veni": 1 │"#,
+                "│ }           │",
+                "└─────────────┘",
+            ]
+        );
+    }
+}
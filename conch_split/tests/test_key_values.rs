@@ -0,0 +1,130 @@
+use conch_ansi::Modifier;
+use conch_split::{Alignment, KeyValues, Lines, Panel};
+
+fn package() -> KeyValues {
+    KeyValues::new()
+        .entry("Name", "conch")
+        .entry("Version", "0.1.0")
+        .entry("License", "MIT")
+}
+
+#[cfg(test)]
+mod test_key_values {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $key_values:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let key_values: KeyValues = $key_values;
+                println!("Test '{}':\n{}", stringify!($name), key_values);
+
+                assert_eq!(Lines::from(&key_values).lines, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        aligned,
+        package(),
+        vec!["Name    : conch", "Version : 0.1.0", "License : MIT"]
+    );
+
+    test_factory!(
+        separator,
+        package().separator(" = "),
+        vec!["Name    = conch", "Version = 0.1.0", "License = MIT"]
+    );
+
+    test_factory!(
+        right_aligned_keys,
+        package().separator(": ").key_alignment(Alignment::Right),
+        vec!["   Name: conch", "Version: 0.1.0", "License: MIT"]
+    );
+
+    test_factory!(
+        wide_characters,
+        KeyValues::new().entries(vec![("名前", "conch"), ("Version", "0.1.0")]),
+        vec!["名前    : conch", "Version : 0.1.0"]
+    );
+
+    test_factory!(
+        wrapped_values,
+        KeyValues::new()
+            .entry("Quote", "I came, I saw, I conquered.")
+            .entry("By", "Julius Caesar")
+            .width(18),
+        vec![
+            "Quote : I came, I",
+            "        saw, I",
+            "        conquered.",
+            "By    : Julius",
+            "        Caesar",
+        ]
+    );
+
+    test_factory!(
+        multi_line_values,
+        KeyValues::new().entry("Quote", "veni\nvidi\nvici"),
+        vec!["Quote : veni", "        vidi", "        vici"]
+    );
+
+    test_factory!(
+        empty_value,
+        KeyValues::new().entry("Name", "conch").entry("Empty", ""),
+        vec!["Name  : conch", "Empty :"]
+    );
+
+    test_factory!(
+        sections,
+        KeyValues::new()
+            .section("Package")
+            .entries(vec![("Name", "conch"), ("Version", "0.1.0")])
+            .section("Build")
+            .entry("Profile", "release")
+            .header_modifier(Modifier::intensity("Bold").unwrap()),
+        vec![
+            "\x1b[1mPackage\x1b[22m",
+            "Name    : conch",
+            "Version : 0.1.0",
+            "",
+            "\x1b[1mBuild\x1b[22m",
+            "Profile : release",
+        ]
+    );
+
+    test_factory!(
+        key_and_value_modifiers,
+        KeyValues::new()
+            .entries(vec![("Name", "conch"), ("Version", "0.1.0")])
+            .key_modifier(Modifier::intensity("Bold").unwrap())
+            .value_modifier(Modifier::colour("BrightRed").unwrap()),
+        vec![
+            "\x1b[1mName\x1b[22m    : \x1b[38;5;9mconch\x1b[39m",
+            "\x1b[1mVersion\x1b[22m : \x1b[38;5;9m0.1.0\x1b[39m",
+        ]
+    );
+
+    test_factory!(empty, KeyValues::new(), Vec::<String>::new());
+
+    #[test]
+    fn composes_with_panel() {
+        let panel = Panel::new(package()).title("conch");
+
+        assert_eq!(
+            Lines::from(&panel).lines,
+            vec![
+                "┌─ conch ─────────┐",
+                "│ Name    : conch │",
+                "│ Version : 0.1.0 │",
+                "│ License : MIT   │",
+                "└─────────────────┘",
+            ]
+        );
+    }
+}
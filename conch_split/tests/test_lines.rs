@@ -1,6 +1,6 @@
-use conch_ansi::Modifier;
+use conch_ansi::{Background, Modifier};
 use conch_macros::s;
-use conch_split::Lines;
+use conch_split::{Alignment, Lines, Truncation, WrapOptions};
 
 #[cfg(test)]
 mod test_display {
@@ -53,6 +53,16 @@ mod test_display {
         )
     );
 
+    test_factory!(
+        leading_blank_line,
+        Lines::from(vec!["", "veni", "vidi"]),
+        s!(
+            "\n"
+            "veni\n"
+            "vidi"
+        )
+    );
+
     test_factory!(
         prefix_and_spacing,
         Lines::from(vec!["veni", "vidi", "vici",])
@@ -141,3 +151,561 @@ mod test_display {
         )
     );
 }
+
+#[cfg(test)]
+mod test_from_text {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $lines:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let lines: Lines = $lines;
+                println!("Test '{}':\n{}", stringify!($name), lines);
+
+                assert_eq!(lines.lines, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        word_boundaries,
+        Lines::from_text("The quick brown fox jumps over the lazy dog.", 15),
+        vec!["The quick brown", "fox jumps over", "the lazy dog."]
+    );
+
+    test_factory!(
+        explicit_newlines,
+        Lines::from_text("veni\n\nvidi vici\r\n", 20),
+        vec!["veni", "", "vidi vici", ""]
+    );
+
+    test_factory!(
+        leading_whitespace_of_paragraph,
+        Lines::from_text("  - item one\n  - item two", 20),
+        vec!["  - item one", "  - item two"]
+    );
+
+    test_factory!(
+        hard_break,
+        Lines::from_text("a supercalifragilistic word", 8),
+        vec!["a", "supercal", "ifragili", "stic", "word"]
+    );
+
+    test_factory!(
+        hard_break_hyphenated,
+        Lines::from_text_with(
+            "a supercalifragilistic word",
+            WrapOptions::new(8).hyphenate(true)
+        ),
+        vec!["a", "superca-", "lifragi-", "listic", "word"]
+    );
+
+    test_factory!(
+        wide_characters,
+        Lines::from_text("\u{4f60}\u{597d}\u{4e16}\u{754c} ok", 5),
+        vec!["\u{4f60}\u{597d}", "\u{4e16}\u{754c}", "ok"]
+    );
+
+    test_factory!(
+        indents,
+        Lines::from_text_with(
+            "-h, --help  Print help information and exit.",
+            WrapOptions::new(30)
+                .initial_indent("  ")
+                .subsequent_indent("              ")
+        ),
+        vec![
+            "  -h, --help  Print help",
+            "              information and",
+            "              exit.",
+        ]
+    );
+
+    test_factory!(
+        keep_trailing_whitespace,
+        Lines::from_text_with(
+            "veni vidi vici",
+            WrapOptions::new(9).trim_trailing_whitespace(false)
+        ),
+        vec!["veni vidi ", "vici"]
+    );
+
+    test_factory!(
+        escape_codes_have_no_width,
+        Lines::from_text("\x1b[1mveni\x1b[22m vidi vici", 9),
+        vec!["\x1b[1mveni\x1b[22m vidi", "vici"]
+    );
+
+    test_factory!(
+        styles_carried_across_lines,
+        Lines::from_text("\x1b[1m\x1b[38;5;9mveni vidi\x1b[39m vici\x1b[22m", 4),
+        vec![
+            "\x1b[1m\x1b[38;5;9mveni\x1b[39m\x1b[22m",
+            "\x1b[1m\x1b[38;5;9mvidi\x1b[39m\x1b[22m",
+            "\x1b[1mvici\x1b[22m",
+        ]
+    );
+
+    test_factory!(
+        styles_carried_across_newlines,
+        Lines::from_text("\x1b[48;5;4mveni\nvidi\x1b[49m", 10),
+        vec!["\x1b[48;5;4mveni\x1b[49m", "\x1b[48;5;4mvidi\x1b[49m"]
+    );
+
+    test_factory!(
+        unparsable_styles_reset_all,
+        Lines::from_text("\x1b[5mveni vidi\x1b[0m vici", 4),
+        vec!["\x1b[5mveni\x1b[0m", "\x1b[5mvidi\x1b[0m", "vici"]
+    );
+
+    test_factory!(
+        unparsable_styles_closed_by_resetters,
+        Lines::from_text_with(
+            "\x1b[38;2;255;0;0mred\x1b[39m plain words here ok",
+            WrapOptions::new(6)
+        ),
+        vec![
+            "\x1b[38;2;255;0;0mred\x1b[39m",
+            "plain",
+            "words",
+            "here",
+            "ok"
+        ]
+    );
+
+    test_factory!(
+        unparsable_styles_overridden,
+        Lines::from_text(
+            "\x1b[48;2;0;0;255m\x1b[5mveni\x1b[48;5;1m vidi\x1b[25m vici",
+            4
+        ),
+        vec![
+            "\x1b[48;2;0;0;255m\x1b[5mveni\x1b[48;5;1m\x1b[0m",
+            "\x1b[48;5;1m\x1b[5mvidi\x1b[25m\x1b[49m",
+            "\x1b[48;5;1mvici\x1b[49m",
+        ]
+    );
+
+    test_factory!(
+        decorations_carried_across_lines,
+        Lines::from_text("\x1b[3m\x1b[4mveni vidi\x1b[24m vici\x1b[23m", 4),
+        vec![
+            "\x1b[3m\x1b[4mveni\x1b[24m\x1b[23m",
+            "\x1b[3m\x1b[4mvidi\x1b[24m\x1b[23m",
+            "\x1b[3mvici\x1b[23m",
+        ]
+    );
+
+    test_factory!(
+        hyperlinks_carried_across_lines,
+        Lines::from_text(
+            "\x1b]8;;https://example.com\x1b\\veni vidi\x1b]8;;\x1b\\ vici",
+            4
+        ),
+        vec![
+            "\x1b]8;;https://example.com\x1b\\veni\x1b]8;;\x1b\\",
+            "\x1b]8;;https://example.com\x1b\\vidi\x1b]8;;\x1b\\",
+            "vici",
+        ]
+    );
+}
+
+#[cfg(test)]
+mod test_line_rules {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $lines:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let lines = $lines;
+                println!("Test '{}':\n{}", stringify!($name), lines);
+
+                assert_eq!(lines.to_string().as_str(), $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        alternating,
+        Lines::from(vec!["veni", "vidi", "vici",]).alternating_modifiers(vec![
+            Modifier::Nothing,
+            Modifier::background("Grayscale02").unwrap()
+        ]),
+        s!(
+            "veni\n"
+            "\u{1b}[48;5;234mvidi\u{1b}[49m\n"
+            "vici"
+        )
+    );
+
+    test_factory!(
+        modifier_when,
+        Lines::from(vec!["INFO: veni", "ERROR: vidi", "INFO: vici",]).modifier_when(|_, line| line
+            .contains("ERROR")
+            .then(|| Modifier::colour("BrightRed").unwrap())),
+        s!(
+            "INFO: veni\n"
+            "\u{1b}[38;5;9mERROR: vidi\u{1b}[39m\n"
+            "INFO: vici"
+        )
+    );
+
+    test_factory!(
+        modifier_when_by_index,
+        Lines::from(vec!["veni", "vidi", "vici",])
+            .modifier_when(|idx, _| (idx >= 1).then(|| Modifier::intensity("Faint").unwrap())),
+        s!(
+            "veni\n"
+            "\u{1b}[2mvidi\u{1b}[22m\n"
+            "\u{1b}[2mvici\u{1b}[22m"
+        )
+    );
+
+    test_factory!(
+        first_and_last,
+        Lines::from(vec!["veni", "vidi", "vici",])
+            .first_line_modifier(Modifier::intensity("Bold").unwrap())
+            .last_line_modifier(Modifier::colour("BrightRed").unwrap()),
+        s!(
+            "\u{1b}[1mveni\u{1b}[22m\n"
+            "vidi\n"
+            "\u{1b}[38;5;9mvici\u{1b}[39m"
+        )
+    );
+
+    test_factory!(
+        first_and_last_of_one_line,
+        Lines::from(vec!["veni",])
+            .first_line_modifier(Modifier::intensity("Bold").unwrap())
+            .last_line_modifier(Modifier::colour("BrightRed").unwrap()),
+        "\u{1b}[1m\u{1b}[38;5;9mveni\u{1b}[39m\u{1b}[22m"
+    );
+
+    test_factory!(
+        index_override_takes_precedence,
+        Lines::from(vec!["veni", "vidi", "vici",])
+            .alternating_modifiers(vec![Modifier::colour("Grayscale13").unwrap()])
+            .line_modifier(1, Modifier::colour("BrightRed").unwrap()),
+        s!(
+            "\u{1b}[38;5;245mveni\u{1b}[39m\n"
+            "\u{1b}[38;5;245m\u{1b}[38;5;9mvidi\u{1b}[39m\u{1b}[39m\n"
+            "\u{1b}[38;5;245mvici\u{1b}[39m"
+        )
+    );
+
+    test_factory!(
+        with_title_prefix_and_spacing,
+        Lines::from(vec!["veni", "vidi",])
+            .title("Julius Caesar")
+            .prefix("> ")
+            .spacing(2)
+            .modifier(Modifier::colour("Grayscale13").unwrap())
+            .first_line_modifier(Modifier::intensity("Bold").unwrap()),
+        s!(
+            "\u{1b}[38;5;245m> Julius Caesar\u{1b}[39m\n\n"
+            "\u{1b}[38;5;245m> \u{1b}[39m\n\n"
+            "\u{1b}[38;5;245m\u{1b}[1m> veni\u{1b}[22m\u{1b}[39m\n\n"
+            "\u{1b}[38;5;245m> vidi\u{1b}[39m"
+        )
+    );
+}
+
+#[cfg(test)]
+mod test_alignment {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $lines:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let lines = $lines;
+                println!("Test '{}':\n{}", stringify!($name), lines);
+
+                assert_eq!(lines.to_string().as_str(), $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        left_with_width,
+        Lines::from(vec!["veni", "vidi",]).width(6),
+        s!(
+            "veni  \n"
+            "vidi  "
+        )
+    );
+
+    test_factory!(
+        center,
+        Lines::from(vec!["veni", "vidi, vici",]).alignment(Alignment::Center),
+        s!(
+            "   veni   \n"
+            "vidi, vici"
+        )
+    );
+
+    test_factory!(
+        right_with_prefix_and_title,
+        Lines::from(vec!["veni", "vidi",])
+            .title("Caesar")
+            .prefix("> ")
+            .alignment(Alignment::Right)
+            .width(10),
+        s!(
+            ">   Caesar\n"
+            ">         \n"
+            ">     veni\n"
+            ">     vidi"
+        )
+    );
+
+    test_factory!(
+        right_ignores_escape_codes,
+        Lines::from(vec!["\x1b[1mveni\x1b[22m", "vidi",])
+            .alignment(Alignment::Right)
+            .width(6),
+        s!(
+            "  \x1b[1mveni\x1b[22m\n"
+            "  vidi"
+        )
+    );
+
+    test_factory!(
+        justify,
+        Lines::from_text("I came, I saw, I conquered. Veni, vidi, vici.", 20)
+            .alignment(Alignment::Justify)
+            .width(20),
+        s!(
+            "I  came,  I  saw,  I\n"
+            "conquered.     Veni,\n"
+            "vidi, vici.         "
+        )
+    );
+
+    test_factory!(
+        justify_paragraphs,
+        Lines::from(vec!["veni vidi", "vici", "", "veni vidi", "vici",])
+            .alignment(Alignment::Justify)
+            .width(11),
+        s!(
+            "veni   vidi\n"
+            "vici       \n"
+            "           \n"
+            "veni   vidi\n"
+            "vici       "
+        )
+    );
+
+    test_factory!(
+        wider_lines_unchanged,
+        Lines::from(vec!["veni, vidi, vici",])
+            .alignment(Alignment::Center)
+            .width(6),
+        "veni, vidi, vici"
+    );
+
+    test_factory!(
+        fill,
+        Lines::from(vec!["veni", "vidi, vici",])
+            .modifier(Modifier::colour("BrightRed").unwrap())
+            .fill(Background::Grayscale02),
+        s!(
+            "\x1b[48;5;234m\x1b[38;5;9mveni      \x1b[39m\x1b[49m\n"
+            "\x1b[48;5;234m\x1b[38;5;9mvidi, vici\x1b[39m\x1b[49m"
+        )
+    );
+
+    test_factory!(
+        fill_with_title,
+        Lines::from(vec!["veni",])
+            .title("Caesar")
+            .alignment(Alignment::Center)
+            .fill(Background::Grayscale02),
+        s!(
+            "\x1b[48;5;234mCaesar\x1b[49m\n"
+            "\x1b[48;5;234m      \x1b[49m\n"
+            "\x1b[48;5;234m veni \x1b[49m"
+        )
+    );
+}
+
+#[cfg(test)]
+mod test_max_height {
+    use super::*;
+
+    fn numbers() -> Lines {
+        Lines::from((1..=10).collect::<Vec<_>>()).more_modifier(Modifier::Nothing)
+    }
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $lines:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let lines = $lines;
+                println!("Test '{}':\n{}", stringify!($name), lines);
+
+                assert_eq!(lines.to_string().as_str(), $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        fits,
+        numbers().max_height(10),
+        "1\n2\n3\n4\n5\n6\n7\n8\n9\n10"
+    );
+
+    test_factory!(head, numbers().max_height(4), "1\n2\n3\n… 7 more lines");
+
+    test_factory!(
+        tail,
+        numbers().max_height(4).truncation(Truncation::Tail),
+        "… 7 more lines\n8\n9\n10"
+    );
+
+    test_factory!(
+        head_and_tail,
+        numbers().max_height(6).truncation(Truncation::HeadAndTail),
+        "1\n2\n3\n… 5 more lines\n9\n10"
+    );
+
+    test_factory!(one_more_line, numbers().max_height(1), "… 10 more lines");
+
+    test_factory!(
+        with_title_and_spacing,
+        numbers().title("Numbers").spacing(2).max_height(9),
+        s!(
+            "Numbers\n\n"
+            "\n\n"
+            "1\n\n"
+            "2\n\n"
+            "… 8 more lines"
+        )
+    );
+
+    test_factory!(
+        styled_marker,
+        numbers()
+            .prefix("> ")
+            .more_modifier(Modifier::colour("Grayscale13").unwrap())
+            .max_height(2),
+        s!(
+            "> 1\n"
+            "\x1b[38;5;245m> … 9 more lines\x1b[39m"
+        )
+    );
+
+    test_factory!(
+        default_marker_is_faint,
+        Lines::from(vec!["veni", "vidi", "vici"]).max_height(2),
+        s!(
+            "veni\n"
+            "\x1b[2m… 2 more lines\x1b[22m"
+        )
+    );
+
+    test_factory!(
+        marker_counts_for_alignment,
+        numbers().alignment(Alignment::Right).max_height(2),
+        s!(
+            "             1\n"
+            "… 9 more lines"
+        )
+    );
+}
+
+#[cfg(test)]
+mod test_pages {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $lines:expr,
+            $height:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let lines: Lines = $lines;
+                let pages: Vec<String> =
+                    lines.pages($height).map(|page| page.to_string()).collect();
+                println!("Test '{}':\n{:#?}", stringify!($name), pages);
+
+                assert_eq!(pages, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        without_title,
+        Lines::from(vec!["veni", "vidi", "vici"]),
+        2,
+        vec!["veni\nvidi", "vici"]
+    );
+
+    test_factory!(
+        repeats_title,
+        Lines::from(vec!["veni", "vidi", "vici"]).title("Caesar"),
+        3,
+        vec!["Caesar\n\nveni", "Caesar\n\nvidi", "Caesar\n\nvici"]
+    );
+
+    test_factory!(
+        with_spacing,
+        Lines::from(vec!["veni", "vidi", "vici"]).spacing(2),
+        4,
+        vec!["veni\n\nvidi", "vici"]
+    );
+
+    test_factory!(
+        at_least_one_line_per_page,
+        Lines::from(vec!["veni", "vidi"]).title("Caesar"),
+        1,
+        vec!["Caesar\n\nveni", "Caesar\n\nvidi"]
+    );
+
+    test_factory!(
+        rules_follow_lines_across_pages,
+        Lines::from(vec!["veni", "vidi", "vici"])
+            .first_line_modifier(Modifier::intensity("Bold").unwrap())
+            .last_line_modifier(Modifier::intensity("Faint").unwrap()),
+        2,
+        vec!["\x1b[1mveni\x1b[22m\nvidi", "\x1b[2mvici\x1b[22m"]
+    );
+
+    test_factory!(
+        empty,
+        Lines::from(Vec::<String>::new()),
+        10,
+        Vec::<String>::new()
+    );
+
+    #[test]
+    fn len() {
+        assert_eq!(Lines::from((1..=10).collect::<Vec<_>>()).pages(3).len(), 4);
+    }
+}
@@ -0,0 +1,124 @@
+use conch_ansi::Modifier;
+use conch_split::{Lines, List, ListMarker, Numbering};
+
+#[cfg(test)]
+mod test_list {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $list:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let list: List = $list;
+                println!("Test '{}':\n{}", stringify!($name), list);
+
+                assert_eq!(Lines::from(&list).lines, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        bulleted,
+        List::new().items(vec!["veni", "vidi", "vici"]),
+        vec!["• veni", "• vidi", "• vici"]
+    );
+
+    test_factory!(
+        numbered_markers_aligned,
+        List::numbered().items(vec!["a"; 10]),
+        vec![
+            " 1. a", " 2. a", " 3. a", " 4. a", " 5. a", " 6. a", " 7. a", " 8. a", " 9. a",
+            "10. a",
+        ]
+    );
+
+    test_factory!(
+        start,
+        List::numbered().items(vec!["vidi", "vici"]).start(2),
+        vec!["2. vidi", "3. vici"]
+    );
+
+    test_factory!(
+        nested_levels,
+        List::numbered()
+            .item("veni")
+            .sublist(
+                List::new()
+                    .item("I came")
+                    .sublist(List::new().items(vec!["to", "Zela"]))
+            )
+            .item("vidi"),
+        vec![
+            "1. veni",
+            "   a) I came",
+            "       i. to",
+            "      ii. Zela",
+            "2. vidi",
+        ]
+    );
+
+    test_factory!(
+        nested_bullets,
+        List::new()
+            .item("veni")
+            .sublist(List::new().item("vidi").sublist(List::new().item("vici"))),
+        vec!["• veni", "  ◦ vidi", "    ▪ vici"]
+    );
+
+    test_factory!(
+        custom_markers,
+        List::new()
+            .markers(vec![
+                ListMarker::Bullet("-"),
+                ListMarker::Numbered(Numbering::UpperAlpha, ".")
+            ])
+            .item("veni")
+            .sublist(List::new().items(vec!["vidi", "vici"])),
+        vec!["- veni", "  A. vidi", "  B. vici"]
+    );
+
+    test_factory!(
+        hanging_indent_when_wrapped,
+        List::numbered()
+            .item("I came, I saw, I conquered.")
+            .width(13),
+        vec!["1. I came, I", "   saw, I", "   conquered."]
+    );
+
+    test_factory!(
+        hanging_indent_for_newlines,
+        List::new().item("veni\nvidi\nvici"),
+        vec!["• veni", "  vidi", "  vici"]
+    );
+
+    test_factory!(
+        leading_blank_line,
+        List::new().item("\nveni"),
+        vec!["• ", "  veni"]
+    );
+
+    test_factory!(
+        nested_lines,
+        List::new()
+            .item("Caesar")
+            .lines(Lines::from(vec!["veni", "vidi", "vici"]).prefix("> "))
+            .item("Cicero"),
+        vec!["• Caesar", "  > veni", "  > vidi", "  > vici", "• Cicero"]
+    );
+
+    test_factory!(
+        marker_modifiers,
+        List::new()
+            .item("veni")
+            .sublist(List::new().item("vidi"))
+            .marker_modifiers(vec![Modifier::colour("BrightRed").unwrap()]),
+        vec!["\x1b[38;5;9m•\x1b[39m veni", "  \x1b[38;5;9m◦\x1b[39m vidi"]
+    );
+
+    test_factory!(empty, List::new(), Vec::<String>::new());
+}
@@ -0,0 +1,221 @@
+//! Test rendering of [`Markdown`].
+#![cfg(feature = "markdown")]
+
+use conch_ansi::Modifier;
+use conch_split::{Lines, LinkStyle, Markdown, MarkdownTheme};
+
+#[cfg(test)]
+mod test_markdown {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $markdown:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let markdown: Markdown = $markdown;
+                println!("Test '{}':\n{}", stringify!($name), markdown);
+
+                assert_eq!(Lines::from(&markdown).lines, $expected);
+            }
+        };
+    }
+
+    fn plain(source: &str) -> Markdown {
+        Markdown::new(source).theme(MarkdownTheme::plain())
+    }
+
+    fn bold() -> Modifier {
+        Modifier::intensity("Bold").unwrap()
+    }
+
+    fn italic() -> Modifier {
+        Modifier::decoration("Italic").unwrap()
+    }
+
+    fn underline() -> Modifier {
+        Modifier::decoration("Underline").unwrap()
+    }
+
+    test_factory!(
+        paragraphs_wrapped,
+        plain("veni vidi vici\nveni\n\nvidi vici").width(10),
+        vec!["veni vidi", "vici veni", "", "vidi vici"]
+    );
+
+    test_factory!(
+        hard_break,
+        plain("veni  \nvidi\\\nvici"),
+        vec!["veni", "vidi", "vici"]
+    );
+
+    test_factory!(
+        headings,
+        Markdown::new("# veni\n\n## vidi\n\n#### vici").theme(MarkdownTheme {
+            headings: vec![bold(), italic()],
+            ..MarkdownTheme::plain()
+        }),
+        vec![
+            "\x1b[1mveni\x1b[22m",
+            "",
+            "\x1b[3mvidi\x1b[23m",
+            "",
+            "\x1b[3mvici\x1b[23m",
+        ]
+    );
+
+    test_factory!(
+        strong_and_emphasis,
+        Markdown::new("**veni** *vidi* ***vici***").theme(MarkdownTheme {
+            strong: bold(),
+            emphasis: italic(),
+            ..MarkdownTheme::plain()
+        }),
+        vec!["\x1b[1mveni\x1b[22m \x1b[3mvidi\x1b[23m \x1b[3m\x1b[1mvici\x1b[22m\x1b[23m"]
+    );
+
+    test_factory!(
+        strong_within_heading,
+        Markdown::new("# veni **vidi** vici").theme(MarkdownTheme {
+            headings: vec![bold()],
+            strong: bold(),
+            ..MarkdownTheme::plain()
+        }),
+        vec!["\x1b[1mveni \x1b[1mvidi\x1b[22m\x1b[1m vici\x1b[22m"]
+    );
+
+    test_factory!(
+        link_within_heading,
+        Markdown::new("# veni [vidi](http://x) vici")
+            .theme(MarkdownTheme {
+                headings: vec![bold() + underline()],
+                link: underline(),
+                ..MarkdownTheme::plain()
+            })
+            .link_style(LinkStyle::Hyperlink),
+        vec![concat!(
+            "\x1b[1m\x1b[4mveni \x1b]8;;http://x\x1b\\\x1b[4mvidi\x1b[24m\x1b[1m\x1b[4m",
+            "\x1b]8;;\x1b\\ vici\x1b[24m\x1b[22m"
+        )]
+    );
+
+    test_factory!(
+        emphasis_carried_across_lines,
+        Markdown::new("*veni vidi* vici")
+            .theme(MarkdownTheme {
+                emphasis: italic(),
+                ..MarkdownTheme::plain()
+            })
+            .width(4),
+        vec!["\x1b[3mveni\x1b[23m", "\x1b[3mvidi\x1b[23m", "vici"]
+    );
+
+    test_factory!(
+        inline_code,
+        Markdown::new("Run `cargo build` now.").theme(MarkdownTheme {
+            code: Modifier::background("Grayscale02").unwrap(),
+            ..MarkdownTheme::plain()
+        }),
+        vec!["Run \x1b[48;5;234mcargo build\x1b[49m now."]
+    );
+
+    test_factory!(
+        code_block,
+        Markdown::new("```rust\nfn main() {\n    veni();\n}\n```").theme(MarkdownTheme {
+            code_block: Modifier::background("Grayscale02").unwrap(),
+            ..MarkdownTheme::plain()
+        }),
+        vec![
+            "\x1b[48;5;234m fn main() { \x1b[49m",
+            "\x1b[48;5;234m     veni(); \x1b[49m",
+            "\x1b[48;5;234m }           \x1b[49m",
+        ]
+    );
+
+    test_factory!(
+        code_block_to_width,
+        plain("    veni\n    vidi vici").width(8),
+        vec![" veni", " vidi", " vici"]
+    );
+
+    test_factory!(
+        block_quote,
+        plain("> veni vidi vici\n>\n> > veni").width(12),
+        vec!["│ veni vidi", "│ vici", "│", "│ │ veni"]
+    );
+
+    test_factory!(
+        unordered_list,
+        plain("- veni\n  - vidi\n    - vici\n- veni vidi vici").width(12),
+        vec!["• veni", "  ◦ vidi", "    ▪ vici", "• veni vidi", "  vici",]
+    );
+
+    test_factory!(
+        ordered_list,
+        plain("9. veni\n10. vidi\n\n    vici"),
+        vec![" 9. veni", "", "10. vidi", "", "    vici"]
+    );
+
+    test_factory!(
+        task_list,
+        plain("- [x] veni\n- [ ] vidi"),
+        vec!["• [x] veni", "• [ ] vidi"]
+    );
+
+    test_factory!(
+        table,
+        plain("| Fruit | Price |\n| :-- | --: |\n| Apple | 1.5 |\n| Durian | 12.25 |"),
+        vec![
+            "┌────────┬───────┐",
+            "│ Fruit  │ Price │",
+            "├────────┼───────┤",
+            "│ Apple  │   1.5 │",
+            "│ Durian │ 12.25 │",
+            "└────────┴───────┘",
+        ]
+    );
+
+    test_factory!(
+        table_to_width,
+        plain("| Key | Value |\n| --- | --- |\n| a | veni vidi vici |").width(18),
+        vec![
+            "┌─────┬──────────┐",
+            "│ Key │ Value    │",
+            "├─────┼──────────┤",
+            "│ a   │ veni     │",
+            "│     │ vidi     │",
+            "│     │ vici     │",
+            "└─────┴──────────┘",
+        ]
+    );
+
+    test_factory!(
+        rule,
+        plain("veni\n\n---\n\nvidi").width(6),
+        vec!["veni", "", "──────", "", "vidi"]
+    );
+
+    test_factory!(
+        hyperlinks,
+        plain("[veni](https://example.com)"),
+        vec!["\x1b]8;;https://example.com\x1b\\veni\x1b]8;;\x1b\\"]
+    );
+
+    test_factory!(
+        footnotes,
+        plain(
+            "[veni](https://a.com), [vidi](https://b.com), [vici](https://a.com) <https://c.com>"
+        )
+        .link_style(LinkStyle::Footnotes),
+        vec![
+            "veni[1], vidi[2], vici[1] https://c.com",
+            "",
+            "[1] https://a.com",
+            "[2] https://b.com",
+        ]
+    );
+}
@@ -0,0 +1,126 @@
+use conch_ansi::Modifier;
+use conch_split::{Alignment, BorderSet, Lines, Panel};
+
+#[cfg(test)]
+mod test_panel {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $panel:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let panel: Panel = $panel;
+                println!("Test '{}':\n{}", stringify!($name), panel);
+
+                assert_eq!(Lines::from(&panel).lines, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        fitted,
+        Panel::new(vec!["veni", "vidi", "vici"]),
+        vec!["┌──────┐", "│ veni │", "│ vidi │", "│ vici │", "└──────┘"]
+    );
+
+    test_factory!(
+        ascii_with_padding,
+        Panel::new(vec!["veni"])
+            .border(BorderSet::ASCII)
+            .padding(2)
+            .vertical_padding(1),
+        vec![
+            "+--------+",
+            "|        |",
+            "|  veni  |",
+            "|        |",
+            "+--------+",
+        ]
+    );
+
+    test_factory!(
+        title_alignments,
+        Panel::new(vec!["veni, vidi, vici"])
+            .title("Caesar")
+            .title_alignment(Alignment::Right)
+            .footer("47 BC")
+            .footer_alignment(Alignment::Center),
+        vec![
+            "┌───────── Caesar ─┐",
+            "│ veni, vidi, vici │",
+            "└───── 47 BC ──────┘",
+        ]
+    );
+
+    test_factory!(
+        title_wider_than_content,
+        Panel::new(vec!["veni"]).title("Julius Caesar"),
+        vec![
+            "┌─ Julius Caesar ─┐",
+            "│ veni            │",
+            "└─────────────────┘",
+        ]
+    );
+
+    test_factory!(
+        fixed_width_wraps_content,
+        Panel::new(vec!["veni, vidi, vici"])
+            .title("Julius Caesar")
+            .border(BorderSet::ROUNDED)
+            .width(14),
+        vec![
+            "╭─ Julius … ─╮",
+            "│ veni,      │",
+            "│ vidi, vici │",
+            "╰────────────╯",
+        ]
+    );
+
+    test_factory!(
+        styled_content,
+        Panel::new(vec!["\x1b[1mveni\x1b[22m", "vidi"]).border(BorderSet::ASCII),
+        vec![
+            "+------+",
+            "| \x1b[1mveni\x1b[22m |",
+            "| vidi |",
+            "+------+",
+        ]
+    );
+
+    test_factory!(
+        leading_blank_line,
+        Panel::new(vec!["", "veni"]).border(BorderSet::ASCII),
+        vec!["+------+", "|      |", "| veni |", "+------+"]
+    );
+
+    test_factory!(
+        border_modifier,
+        Panel::new(vec!["veni"])
+            .title("I")
+            .border(BorderSet::ASCII)
+            .border_modifier(Modifier::colour("BrightRed").unwrap()),
+        vec![
+            "\x1b[38;5;9m+-\x1b[39m I \x1b[38;5;9m--+\x1b[39m",
+            "\x1b[38;5;9m|\x1b[39m veni \x1b[38;5;9m|\x1b[39m",
+            "\x1b[38;5;9m+------+\x1b[39m",
+        ]
+    );
+
+    test_factory!(
+        from_lines,
+        Panel::new(Lines::from(vec!["veni", "vidi"]).title("Caesar")).border(BorderSet::ASCII),
+        vec![
+            "+--------+",
+            "| Caesar |",
+            "|        |",
+            "| veni   |",
+            "| vidi   |",
+            "+--------+",
+        ]
+    );
+}
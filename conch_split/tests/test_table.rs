@@ -0,0 +1,246 @@
+use conch_ansi::Modifier;
+use conch_split::{Alignment, BorderSet, Lines, Overflow, Table, TableColumn};
+
+fn fruits() -> Table {
+    Table::new().headers(vec!["Fruit", "Price"]).rows(vec![
+        vec!["Apple", "1.5"],
+        vec!["Durian", "12.25"],
+        vec!["Fig", "3"],
+    ])
+}
+
+#[cfg(test)]
+mod test_table {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $table:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let table: Table = $table;
+                println!("Test '{}':\n{}", stringify!($name), table);
+
+                assert_eq!(Lines::from(&table).lines, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        light_border,
+        fruits(),
+        vec![
+            "┌────────┬───────┐",
+            "│ Fruit  │ Price │",
+            "├────────┼───────┤",
+            "│ Apple  │ 1.5   │",
+            "│ Durian │ 12.25 │",
+            "│ Fig    │ 3     │",
+            "└────────┴───────┘",
+        ]
+    );
+
+    test_factory!(
+        heavy_border,
+        fruits().border(BorderSet::HEAVY),
+        vec![
+            "┏━━━━━━━━┳━━━━━━━┓",
+            "┃ Fruit  ┃ Price ┃",
+            "┣━━━━━━━━╋━━━━━━━┫",
+            "┃ Apple  ┃ 1.5   ┃",
+            "┃ Durian ┃ 12.25 ┃",
+            "┃ Fig    ┃ 3     ┃",
+            "┗━━━━━━━━┻━━━━━━━┛",
+        ]
+    );
+
+    test_factory!(
+        rounded_border,
+        fruits().border(BorderSet::ROUNDED).padding(0),
+        vec![
+            "╭──────┬─────╮",
+            "│Fruit │Price│",
+            "├──────┼─────┤",
+            "│Apple │1.5  │",
+            "│Durian│12.25│",
+            "│Fig   │3    │",
+            "╰──────┴─────╯",
+        ]
+    );
+
+    test_factory!(
+        no_border,
+        fruits().border(BorderSet::NONE).padding(0),
+        vec![
+            "Fruit  Price",
+            "Apple  1.5  ",
+            "Durian 12.25",
+            "Fig    3    ",
+        ]
+    );
+
+    test_factory!(
+        alignments,
+        fruits()
+            .border(BorderSet::ASCII)
+            .column(0, TableColumn::new().alignment(Alignment::Center))
+            .column(1, TableColumn::new().alignment(Alignment::Right)),
+        vec![
+            "+--------+-------+",
+            "| Fruit  | Price |",
+            "+--------+-------+",
+            "| Apple  |   1.5 |",
+            "| Durian | 12.25 |",
+            "|  Fig   |     3 |",
+            "+--------+-------+",
+        ]
+    );
+
+    test_factory!(
+        decimal_alignment,
+        fruits()
+            .border(BorderSet::ASCII)
+            .column(1, TableColumn::new().alignment(Alignment::Decimal)),
+        vec![
+            "+--------+-------+",
+            "| Fruit  | Price |",
+            "+--------+-------+",
+            "| Apple  |  1.5  |",
+            "| Durian | 12.25 |",
+            "| Fig    |  3    |",
+            "+--------+-------+",
+        ]
+    );
+
+    test_factory!(
+        decimal_alignment_max_width_wrap,
+        Table::new()
+            .row(vec!["12345.25"])
+            .row(vec!["1.5"])
+            .border(BorderSet::ASCII)
+            .column(
+                0,
+                TableColumn::new()
+                    .alignment(Alignment::Decimal)
+                    .max_width(4)
+            ),
+        vec!["+------+", "| 1234 |", "| 5.25 |", "|  1.5 |", "+------+"]
+    );
+
+    test_factory!(
+        decimal_alignment_max_width_truncate,
+        Table::new()
+            .row(vec!["12345.25"])
+            .row(vec!["1.5"])
+            .border(BorderSet::ASCII)
+            .column(
+                0,
+                TableColumn::new()
+                    .alignment(Alignment::Decimal)
+                    .max_width(4)
+                    .overflow(Overflow::Truncate)
+            ),
+        vec!["+------+", "| 123… |", "|  1.5 |", "+------+"]
+    );
+
+    test_factory!(
+        min_width,
+        fruits()
+            .border(BorderSet::ASCII)
+            .column(1, TableColumn::new().min_width(8)),
+        vec![
+            "+--------+----------+",
+            "| Fruit  | Price    |",
+            "+--------+----------+",
+            "| Apple  | 1.5      |",
+            "| Durian | 12.25    |",
+            "| Fig    | 3        |",
+            "+--------+----------+",
+        ]
+    );
+
+    test_factory!(
+        max_width_wrap,
+        Table::new()
+            .headers(vec!["Name", "Description"])
+            .row(vec!["conch", "Pretty shell output"])
+            .border(BorderSet::ASCII)
+            .column(1, TableColumn::new().max_width(11)),
+        vec![
+            "+-------+-------------+",
+            "| Name  | Description |",
+            "+-------+-------------+",
+            "| conch | Pretty      |",
+            "|       | shell       |",
+            "|       | output      |",
+            "+-------+-------------+",
+        ]
+    );
+
+    test_factory!(
+        max_width_truncate,
+        Table::new()
+            .headers(vec!["Name", "Description"])
+            .row(vec!["conch", "Pretty shell output"])
+            .border(BorderSet::ASCII)
+            .column(
+                1,
+                TableColumn::new()
+                    .max_width(11)
+                    .overflow(Overflow::Truncate)
+            ),
+        vec![
+            "+-------+-------------+",
+            "| Name  | Description |",
+            "+-------+-------------+",
+            "| conch | Pretty she… |",
+            "+-------+-------------+",
+        ]
+    );
+
+    test_factory!(
+        styled_cells,
+        Table::new()
+            .row(vec!["\x1b[1mveni\x1b[22m", "vidi"])
+            .row(vec!["vici", "\x1b[38;5;9mveni\x1b[39m"])
+            .border(BorderSet::ASCII),
+        vec![
+            "+------+------+",
+            "| \x1b[1mveni\x1b[22m | vidi |",
+            "| vici | \x1b[38;5;9mveni\x1b[39m |",
+            "+------+------+",
+        ]
+    );
+
+    test_factory!(
+        ragged_rows,
+        Table::new()
+            .row(vec!["veni", "vidi", "vici"])
+            .row(vec!["veni"])
+            .border(BorderSet::ASCII),
+        vec![
+            "+------+------+------+",
+            "| veni | vidi | vici |",
+            "| veni |      |      |",
+            "+------+------+------+",
+        ]
+    );
+
+    test_factory!(
+        header_and_stripe_modifiers,
+        Table::new()
+            .headers(vec!["N"])
+            .rows(vec![vec!["1"], vec!["2"], vec!["3"]])
+            .border(BorderSet::NONE)
+            .padding(0)
+            .header_modifier(Modifier::intensity("Bold").unwrap())
+            .stripe_modifier(Modifier::background("Grayscale02").unwrap()),
+        vec!["\x1b[1mN\x1b[22m", "1", "\x1b[48;5;234m2\x1b[49m", "3",]
+    );
+
+    test_factory!(empty, Table::new(), Vec::<String>::new());
+}
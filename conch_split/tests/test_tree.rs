@@ -0,0 +1,127 @@
+use conch_ansi::Modifier;
+use conch_split::{Lines, Tree, TreeGuides, TreeNode};
+
+fn directories() -> TreeNode {
+    TreeNode::new("/")
+        .child(
+            TreeNode::new("etc")
+                .child("hosts".into())
+                .child("passwd".into()),
+        )
+        .child(TreeNode::new("usr").child(TreeNode::new("bin").child("ls".into())))
+        .child("tmp".into())
+}
+
+#[cfg(test)]
+mod test_tree {
+    use super::*;
+
+    macro_rules! test_factory {
+        (
+            $name:ident,
+            $tree:expr,
+            $expected:expr
+            $(,)?
+        ) => {
+            #[test]
+            fn $name() {
+                let tree: Tree = $tree;
+                println!("Test '{}':\n{}", stringify!($name), tree);
+
+                assert_eq!(Lines::from(&tree).lines, $expected);
+            }
+        };
+    }
+
+    test_factory!(
+        unicode_guides,
+        Tree::new(directories()),
+        vec![
+            "/",
+            "├── etc",
+            "│   ├── hosts",
+            "│   └── passwd",
+            "├── usr",
+            "│   └── bin",
+            "│       └── ls",
+            "└── tmp",
+        ]
+    );
+
+    test_factory!(
+        ascii_guides,
+        Tree::new(directories()).guides(TreeGuides::ASCII),
+        vec![
+            "/",
+            "|-- etc",
+            "|   |-- hosts",
+            "|   `-- passwd",
+            "|-- usr",
+            "|   `-- bin",
+            "|       `-- ls",
+            "`-- tmp",
+        ]
+    );
+
+    test_factory!(
+        collapsed_nodes,
+        Tree::new(
+            TreeNode::new("/")
+                .child(TreeNode::new("etc").child("hosts".into()).collapsed(true))
+                .child(TreeNode::new("tmp").collapsed(true))
+        )
+        .collapsed_marker(" (+)"),
+        vec!["/", "├── etc (+)", "└── tmp"]
+    );
+
+    test_factory!(
+        multi_line_labels,
+        Tree::new(
+            TreeNode::new("root\nnode")
+                .child(TreeNode::new("first\nchild").child("grandchild\nof root".into()))
+                .child("last\nchild".into())
+        ),
+        vec![
+            "root",
+            "node",
+            "├── first",
+            "│   child",
+            "│   └── grandchild",
+            "│       of root",
+            "└── last",
+            "    child",
+        ]
+    );
+
+    test_factory!(
+        depth_modifiers,
+        Tree::new(directories()).depth_modifiers(vec![
+            Modifier::intensity("Bold").unwrap(),
+            Modifier::colour("BrightBlue").unwrap(),
+            Modifier::Nothing,
+        ]),
+        vec![
+            "\x1b[1m/\x1b[22m",
+            "├── \x1b[38;5;12metc\x1b[39m",
+            "│   ├── hosts",
+            "│   └── passwd",
+            "├── \x1b[38;5;12musr\x1b[39m",
+            "│   └── bin",
+            "│       └── ls",
+            "└── \x1b[38;5;12mtmp\x1b[39m",
+        ]
+    );
+
+    test_factory!(
+        guide_modifier,
+        Tree::new(TreeNode::new("/").child(TreeNode::new("etc").child("hosts".into())))
+            .guide_modifier(Modifier::colour("Grayscale13").unwrap()),
+        vec![
+            "/",
+            "\x1b[38;5;245m└── \x1b[39metc",
+            "    \x1b[38;5;245m└── \x1b[39mhosts",
+        ]
+    );
+
+    test_factory!(root_only, Tree::new("/".into()), vec!["/"]);
+}